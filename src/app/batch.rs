@@ -0,0 +1,143 @@
+use anyhow::Result;
+
+use crate::data::{Class, Student};
+use crate::site_check::Severity;
+
+/// Something done to every marked student at once
+#[derive(Debug, Clone)]
+pub enum BatchAction {
+    Clone,
+    Pull,
+    Clean,
+    Delete,
+    /// Move the students, and any clones, into another class
+    Move(Class),
+    /// Add the students to another class too, leaving them where they are
+    Copy(Class),
+    Tag(String),
+}
+
+impl BatchAction {
+    /// Short description for titles and reports, e.g. `Pull` or `Move to Period 3`
+    pub fn label(&self) -> String {
+        match self {
+            BatchAction::Clone => "Clone".to_string(),
+            BatchAction::Pull => "Pull".to_string(),
+            BatchAction::Clean => "Clean".to_string(),
+            BatchAction::Delete => "Delete".to_string(),
+            BatchAction::Move(class) => format!("Move to {}", class.name),
+            BatchAction::Copy(class) => format!("Copy to {}", class.name),
+            BatchAction::Tag(tag) => format!("Tag as '{}'", tag),
+        }
+    }
+
+    /// The one confirmation shown for the whole batch, naming everyone it affects
+    pub fn confirmation(&self, students: &[Student]) -> String {
+        let count = students.len();
+        let question = match self {
+            BatchAction::Clone => format!("Clone repositories for {} students?", count),
+            BatchAction::Pull => format!("Pull repositories for {} students?", count),
+            BatchAction::Clean => format!(
+                "Clean repositories for {} students?\nEvery local change and untracked file in their clones will be lost.",
+                count
+            ),
+            BatchAction::Delete => format!(
+                "Delete {} students from the class?\nThis cannot be undone. Their clones are left on disk.",
+                count
+            ),
+            BatchAction::Move(class) => format!("Move {} students and their clones to {}?", count, class.name),
+            BatchAction::Copy(class) => format!(
+                "Add {} students to {} as well?\nNotes and grades stay here, and repos need cloning again there.",
+                count, class.name
+            ),
+            BatchAction::Tag(tag) => format!("Tag {} students as '{}'?", count, tag),
+        };
+
+        let names: Vec<&str> = students.iter().map(|student| student.github_username.as_str()).collect();
+        format!("{}\n\n{}", question, names.join(", "))
+    }
+}
+
+/// What a batch action did to each student, in the order they were processed
+pub struct BatchReport {
+    label: String,
+    results: Vec<(String, Result<String>)>,
+}
+
+impl BatchReport {
+    pub fn new(action: &BatchAction) -> Self {
+        Self {
+            label: action.label(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Record one student's outcome; `Ok` carries a short note such as "pulled"
+    pub fn push(&mut self, github_username: &str, result: Result<String>) {
+        self.results.push((github_username.to_string(), result));
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.results.iter().filter(|(_, result)| result.is_ok()).count()
+    }
+
+    pub fn title(&self) -> String {
+        format!("{} report", self.label)
+    }
+
+    /// One line counting how many succeeded
+    pub fn headline(&self) -> String {
+        format!("{}: {} of {} succeeded", self.label, self.succeeded(), self.results.len())
+    }
+
+    /// Pass when everything succeeded, Fail when nothing did, Warn for anything in between
+    pub fn outcome(&self) -> Severity {
+        match self.succeeded() {
+            succeeded if succeeded == self.results.len() => Severity::Pass,
+            0 => Severity::Fail,
+            _ => Severity::Warn,
+        }
+    }
+
+    /// One line per student
+    pub fn lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(username, result)| match result {
+                Ok(note) => format!("✅ {}: {}", username, note),
+                Err(e) => format!("❌ {}: {:#}", username, e),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_confirmation_and_report() {
+        let students: Vec<Student> = (1..=14)
+            .map(|id| Student {
+                id,
+                class_id: 1,
+                username: format!("student{}", id),
+                github_username: format!("gh{}", id),
+                created_at: Utc::now(),
+            })
+            .collect();
+
+        let confirmation = BatchAction::Pull.confirmation(&students[..2]);
+        assert_eq!(confirmation, "Pull repositories for 2 students?\n\ngh1, gh2");
+        assert!(BatchAction::Tag("late".to_string()).confirmation(&students).ends_with("gh13, gh14"));
+
+        let mut report = BatchReport::new(&BatchAction::Pull);
+        report.push("gh1", Ok("pulled".to_string()));
+        report.push("gh2", Err(anyhow::anyhow!("Repository not found")));
+        assert_eq!(report.succeeded(), 1);
+        assert_eq!(report.headline(), "Pull: 1 of 2 succeeded");
+        assert_eq!(report.outcome(), Severity::Warn);
+        assert_eq!(report.lines(), ["✅ gh1: pulled", "❌ gh2: Repository not found"]);
+    }
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use dirs::home_dir;
 
+use crate::utils::launcher::OpenAction;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub theme: String,
@@ -10,6 +12,102 @@ pub struct Config {
     pub animation_speed: f32,
     pub enable_particle_effects: bool,
     pub frame_rate: u64,
+    #[serde(default)]
+    pub open_commands: OpenCommands,
+    /// Port for the local site preview server
+    #[serde(default = "default_preview_port")]
+    pub preview_port: u16,
+    /// IANA timezone of the school, e.g. "America/New_York", used for day boundaries and displayed times.
+    /// Unset uses this computer's timezone.
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+fn default_preview_port() -> u16 {
+    4000
+}
+
+/// A user-configurable external command.
+///
+/// `{path}` is replaced with the student's repository path and `{url}` with their site URL.
+/// Environment variables such as `$EDITOR` are expanded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandTemplate {
+    pub command: String,
+    /// Suspend the TUI and run the command in this terminal instead of launching it in the background
+    #[serde(default)]
+    pub suspend: bool,
+}
+
+impl CommandTemplate {
+    pub fn new(command: &str, suspend: bool) -> Self {
+        Self {
+            command: command.to_string(),
+            suspend,
+        }
+    }
+}
+
+/// Command templates for the "open" actions on a student's repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpenCommands {
+    pub terminal: CommandTemplate,
+    pub editor: CommandTemplate,
+    pub file_manager: CommandTemplate,
+    pub browser: CommandTemplate,
+}
+
+impl OpenCommands {
+    pub fn get(&self, action: OpenAction) -> &CommandTemplate {
+        match action {
+            OpenAction::Terminal => &self.terminal,
+            OpenAction::Editor => &self.editor,
+            OpenAction::FileManager => &self.file_manager,
+            OpenAction::Browser => &self.browser,
+        }
+    }
+}
+
+impl Default for OpenCommands {
+    fn default() -> Self {
+        let editor = if std::env::var("EDITOR").is_ok() {
+            CommandTemplate::new("$EDITOR {path}", true)
+        } else {
+            CommandTemplate::new("code {path}", false)
+        };
+
+        if cfg!(target_os = "macos") {
+            Self {
+                terminal: CommandTemplate::new("open -a Terminal {path}", false),
+                editor,
+                file_manager: CommandTemplate::new("open {path}", false),
+                browser: CommandTemplate::new("open {url}", false),
+            }
+        } else if cfg!(target_os = "windows") {
+            Self {
+                terminal: CommandTemplate::new("cmd /C start cmd /K cd /d {path}", false),
+                editor,
+                file_manager: CommandTemplate::new("explorer {path}", false),
+                browser: CommandTemplate::new("explorer {url}", false),
+            }
+        } else {
+            // Without a graphical session (e.g. over SSH) fall back to a shell in this terminal
+            let has_display = std::env::var("DISPLAY").is_ok() || std::env::var("WAYLAND_DISPLAY").is_ok();
+            let terminal = if has_display {
+                CommandTemplate::new("x-terminal-emulator", false)
+            } else {
+                CommandTemplate::new("$SHELL", true)
+            };
+
+            Self {
+                terminal,
+                editor,
+                file_manager: CommandTemplate::new("xdg-open {path}", false),
+                browser: CommandTemplate::new("xdg-open {url}", false),
+            }
+        }
+    }
 }
 
 impl Default for Config {
@@ -20,6 +118,9 @@ impl Default for Config {
             animation_speed: 1.0,
             enable_particle_effects: true,
             frame_rate: 60,
+            open_commands: OpenCommands::default(),
+            preview_port: default_preview_port(),
+            timezone: None,
         }
     }
 }
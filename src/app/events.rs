@@ -1,4 +1,7 @@
+use crate::app::BatchAction;
 use crate::data::{Class, Student};
+use crate::runner::TestResult;
+use crate::utils::launcher::OpenAction;
 use crate::ui::screens::ScreenType; // Fixed import - removed unused ScreenTypeVariant and ScreenContext
 
 #[derive(Debug, Clone)]
@@ -6,6 +9,10 @@ pub enum AppEvent {
     NavigateToScreen(ScreenType),
     GoBack,
     Quit,
+    /// Make this the current class, then handle the event; used by the command palette
+    InClass(Class, Box<AppEvent>),
+    /// Open the help overlay for the current screen
+    ShowHelp,
     
     // Loading states
     ShowLoading(String),
@@ -22,6 +29,9 @@ pub enum AppEvent {
     SelectClass(Class),
     ClassCreated(Class),
     ClassDeleted(i64),
+    RenameClass(Class, String), // class, new name
+    /// Create a class named like this with a copy of the class's roster
+    DuplicateClass(Class, String),
     
     // Student management
     StudentAdded(Student),
@@ -36,15 +46,33 @@ pub enum AppEvent {
     CloneRepo(String), // github_username
     PullRepo(String), // github_username
     CleanRepo(String), // github_username
-    OpenInTerminal(String), // github_username
+    OpenWith(OpenAction, String), // action, github_username
+    SuspendToShell(String), // github_username
+    OpenUrl(String), // url, opened with the browser command
+    CheckSite(String), // github_username
+    CheckAllSites,
+    RunTests,
+    /// A background test run has finished this many of its repositories
+    TestProgress(usize, usize), // finished, total
+    TestsFinished(Vec<TestResult>),
+    ExportTestResults,
+    CheckAssignment(i64), // assignment id
+    SnapshotAssignment(i64), // assignment id
+    RestoreLatest,
+    ExportGradebook,
     
     // Batch repo actions
     CloneAllRepos,
+    /// Run an action on each of these students and report how each went
+    RunBatch(BatchAction, Vec<Student>),
     
     // GitHub operations
     FetchGitHubActivity,
     ShowWeekView,
     ShowLatestActivity,
+    ShowStudentDetail(Student),
+    /// Re-fetch commit times for these students into the commit cache
+    UpdateCommitCache(Vec<Student>),
     RefreshLatestActivity,
     RefreshData,
 }
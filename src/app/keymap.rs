@@ -0,0 +1,672 @@
+use anyhow::{anyhow, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{LazyLock, RwLock},
+};
+
+/// A named thing a key can do; the snake_case names are what `keymap.json` uses.
+///
+/// Text inputs always get raw keys so typing is never remapped, and single-letter menu
+/// hotkeys stay with their menu items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    CommandPalette,
+    Help,
+    Back,
+    Select,
+    Up,
+    Down,
+    Left,
+    Right,
+    Refresh,
+    Sort,
+    ReverseSort,
+    Filter,
+    OnlyInactive,
+    OnlyErrors,
+    ThisWeek,
+    ToggleWeekends,
+    CustomRange,
+    EarlierDays,
+    LaterDays,
+    ToggleSelection,
+    SelectAll,
+    SelectNone,
+    InvertSelection,
+    BatchActions,
+    // Keys for one screen, or a few; they only need to be unique within a `Scope`
+    UpdateHistory,
+    Details,
+    Shell,
+    CheckSite,
+    OpenNotes,
+    OpenFiles,
+    OpenPreview,
+    OpenHeatmap,
+    OpenInBrowser,
+    OpenWeekView,
+    OpenClassMenu,
+    EditThresholds,
+    New,
+    Edit,
+    Delete,
+    Export,
+    AddSchoolDay,
+    Import,
+    CheckSubmissions,
+    Snapshot,
+    RestoreLatest,
+    EditRubric,
+    Grade,
+    RunTests,
+    LiveReload,
+    StopServer,
+    Next,
+    Previous,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    NextStudent,
+    PreviousStudent,
+}
+
+impl Action {
+    pub const ALL: &'static [Action] = &[
+        Action::Quit,
+        Action::CommandPalette,
+        Action::Help,
+        Action::Back,
+        Action::Select,
+        Action::Up,
+        Action::Down,
+        Action::Left,
+        Action::Right,
+        Action::Refresh,
+        Action::Sort,
+        Action::ReverseSort,
+        Action::Filter,
+        Action::OnlyInactive,
+        Action::OnlyErrors,
+        Action::ThisWeek,
+        Action::ToggleWeekends,
+        Action::CustomRange,
+        Action::EarlierDays,
+        Action::LaterDays,
+        Action::ToggleSelection,
+        Action::SelectAll,
+        Action::SelectNone,
+        Action::InvertSelection,
+        Action::BatchActions,
+        Action::UpdateHistory,
+        Action::Details,
+        Action::Shell,
+        Action::CheckSite,
+        Action::OpenNotes,
+        Action::OpenFiles,
+        Action::OpenPreview,
+        Action::OpenHeatmap,
+        Action::OpenInBrowser,
+        Action::OpenWeekView,
+        Action::OpenClassMenu,
+        Action::EditThresholds,
+        Action::New,
+        Action::Edit,
+        Action::Delete,
+        Action::Export,
+        Action::AddSchoolDay,
+        Action::Import,
+        Action::CheckSubmissions,
+        Action::Snapshot,
+        Action::RestoreLatest,
+        Action::EditRubric,
+        Action::Grade,
+        Action::RunTests,
+        Action::LiveReload,
+        Action::StopServer,
+        Action::Next,
+        Action::Previous,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Top,
+        Action::Bottom,
+        Action::NextStudent,
+        Action::PreviousStudent,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::CommandPalette => "command_palette",
+            Action::Help => "help",
+            Action::Back => "back",
+            Action::Select => "select",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Refresh => "refresh",
+            Action::Sort => "sort",
+            Action::ReverseSort => "reverse_sort",
+            Action::Filter => "filter",
+            Action::OnlyInactive => "only_inactive",
+            Action::OnlyErrors => "only_errors",
+            Action::ThisWeek => "this_week",
+            Action::ToggleWeekends => "toggle_weekends",
+            Action::CustomRange => "custom_range",
+            Action::EarlierDays => "earlier_days",
+            Action::LaterDays => "later_days",
+            Action::ToggleSelection => "toggle_selection",
+            Action::SelectAll => "select_all",
+            Action::SelectNone => "select_none",
+            Action::InvertSelection => "invert_selection",
+            Action::BatchActions => "batch_actions",
+            Action::UpdateHistory => "update_history",
+            Action::Details => "details",
+            Action::Shell => "shell",
+            Action::CheckSite => "check_site",
+            Action::OpenNotes => "open_notes",
+            Action::OpenFiles => "open_files",
+            Action::OpenPreview => "open_preview",
+            Action::OpenHeatmap => "open_heatmap",
+            Action::OpenInBrowser => "open_in_browser",
+            Action::OpenWeekView => "open_week_view",
+            Action::OpenClassMenu => "open_class_menu",
+            Action::EditThresholds => "edit_thresholds",
+            Action::New => "new",
+            Action::Edit => "edit",
+            Action::Delete => "delete",
+            Action::Export => "export",
+            Action::AddSchoolDay => "add_school_day",
+            Action::Import => "import",
+            Action::CheckSubmissions => "check_submissions",
+            Action::Snapshot => "snapshot",
+            Action::RestoreLatest => "restore_latest",
+            Action::EditRubric => "edit_rubric",
+            Action::Grade => "grade",
+            Action::RunTests => "run_tests",
+            Action::LiveReload => "live_reload",
+            Action::StopServer => "stop_server",
+            Action::Next => "next",
+            Action::Previous => "previous",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::NextStudent => "next_student",
+            Action::PreviousStudent => "previous_student",
+        }
+    }
+
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::CommandPalette => &["ctrl+p"],
+            Action::Help => &["?"],
+            Action::Back => &["esc"],
+            Action::Select => &["enter"],
+            Action::Up => &["up", "k"],
+            Action::Down => &["down", "j"],
+            Action::Left => &["left", "h"],
+            Action::Right => &["right", "l"],
+            Action::Refresh => &["r"],
+            Action::Sort => &["s"],
+            Action::ReverseSort => &["S"],
+            Action::Filter => &["/"],
+            Action::OnlyInactive => &["z"],
+            Action::OnlyErrors => &["e"],
+            Action::ThisWeek => &["t"],
+            Action::ToggleWeekends => &["w"],
+            Action::CustomRange => &["c"],
+            Action::EarlierDays => &["["],
+            Action::LaterDays => &["]"],
+            Action::ToggleSelection => &["space"],
+            Action::SelectAll => &["a"],
+            Action::SelectNone => &["u"],
+            Action::InvertSelection => &["v"],
+            Action::BatchActions => &["b"],
+            Action::UpdateHistory => &["u"],
+            Action::Details => &["i"],
+            Action::Shell => &["s"],
+            Action::CheckSite => &["c"],
+            Action::OpenNotes => &["n"],
+            Action::OpenFiles => &["f"],
+            Action::OpenPreview => &["p"],
+            Action::OpenHeatmap => &["h"],
+            Action::OpenInBrowser => &["o"],
+            Action::OpenWeekView => &["w"],
+            Action::OpenClassMenu => &["m"],
+            Action::EditThresholds => &["t"],
+            Action::New => &["n"],
+            Action::Edit => &["e"],
+            Action::Delete => &["d"],
+            Action::Export => &["x"],
+            Action::AddSchoolDay => &["s"],
+            Action::Import => &["i"],
+            Action::CheckSubmissions => &["c"],
+            Action::Snapshot => &["s"],
+            Action::RestoreLatest => &["l"],
+            Action::EditRubric => &["r"],
+            Action::Grade => &["G"],
+            Action::RunTests => &["r"],
+            Action::LiveReload => &["l"],
+            Action::StopServer => &["s"],
+            Action::Next => &["tab"],
+            Action::Previous => &["shift+tab"],
+            Action::PageUp => &["pageup"],
+            Action::PageDown => &["pagedown", "space"],
+            Action::Top => &["home", "g"],
+            Action::Bottom => &["end", "G"],
+            Action::NextStudent => &["pagedown"],
+            Action::PreviousStudent => &["pageup"],
+        }
+    }
+}
+
+/// Always available, except while typing
+const APP_WIDE: &[Action] = &[Action::Quit, Action::CommandPalette, Action::Help];
+
+/// The actions a screen, or a family of similar screens, responds to. A key may do different
+/// things in different scopes, e.g. `s` sorts a table but opens a shell on the repository screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Menus,
+    LatestActivity,
+    WeekView,
+    Heatmap,
+    Repositories,
+    DeleteStudents,
+    StudentDetail,
+    Dashboard,
+    Calendar,
+    Assignments,
+    Notes,
+    TestRunner,
+    SitePreview,
+    FileBrowser,
+    Grading,
+}
+
+impl Scope {
+    pub const ALL: &'static [Scope] = &[
+        Scope::Menus,
+        Scope::LatestActivity,
+        Scope::WeekView,
+        Scope::Heatmap,
+        Scope::Repositories,
+        Scope::DeleteStudents,
+        Scope::StudentDetail,
+        Scope::Dashboard,
+        Scope::Calendar,
+        Scope::Assignments,
+        Scope::Notes,
+        Scope::TestRunner,
+        Scope::SitePreview,
+        Scope::FileBrowser,
+        Scope::Grading,
+    ];
+
+    /// Every action the scope's screens check, besides the app-wide ones
+    pub fn actions(&self) -> &'static [Action] {
+        use Action::*;
+        match self {
+            Scope::Menus => &[Up, Down, Select, Back, Refresh],
+            Scope::LatestActivity => &[Up, Down, Select, Back, Refresh, Sort, ReverseSort, Filter, OnlyInactive, OnlyErrors],
+            Scope::WeekView => &[
+                Up, Down, Left, Right, Select, Back, Refresh, Sort, ReverseSort, Filter, OnlyInactive, OnlyErrors,
+                ThisWeek, ToggleWeekends, CustomRange, EarlierDays, LaterDays,
+            ],
+            Scope::Heatmap => &[Up, Down, Left, Right, Select, Back, ThisWeek, UpdateHistory, Top, Next, Previous],
+            Scope::Repositories => &[
+                Up, Down, Select, Back, ToggleSelection, SelectAll, SelectNone, InvertSelection, BatchActions,
+                Details, Shell, CheckSite,
+            ],
+            Scope::DeleteStudents => &[Up, Down, Select, Back, ToggleSelection, SelectAll, SelectNone, InvertSelection],
+            Scope::StudentDetail => &[Back, Refresh, OpenNotes, OpenFiles, OpenPreview, OpenHeatmap, OpenInBrowser],
+            Scope::Dashboard => &[
+                Up, Down, Select, Back, Refresh, OpenWeekView, OpenHeatmap, OpenClassMenu, EditThresholds,
+            ],
+            Scope::Calendar => &[
+                Up, Down, Select, Back, ToggleSelection, SelectAll, SelectNone, New, AddSchoolDay, Import, Delete,
+            ],
+            Scope::Assignments => &[
+                Up, Down, Select, Back, CheckSubmissions, Snapshot, RestoreLatest, EditRubric, Grade, New, Delete, Export,
+            ],
+            Scope::Notes => &[Up, Down, Left, Right, Select, Back, Filter, New, Edit, Delete, Next],
+            Scope::TestRunner => &[Up, Down, Select, Back, RunTests, Edit, Export, PageUp, PageDown],
+            Scope::SitePreview => &[Up, Down, Select, Back, Refresh, OpenInBrowser, LiveReload, StopServer],
+            Scope::FileBrowser => &[Up, Down, Left, Right, Select, Back, Refresh, Next, PageUp, PageDown, Top, Bottom],
+            Scope::Grading => &[Up, Down, Select, Back, Next, Previous, NextStudent, PreviousStudent],
+        }
+    }
+}
+
+/// A key plus the Ctrl/Alt modifiers it needs; Shift is carried by the character itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parse `k`, `S`, `?`, `esc`, `shift+tab`, `ctrl+p`, `alt+enter`, `f5`...
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let mut modifiers = KeyModifiers::NONE;
+        let mut shift = false;
+        let mut key = text;
+        // A trailing "+" is the plus key itself, so only split on earlier ones
+        while let Some((modifier, rest)) = key.split_once('+').filter(|(_, rest)| !rest.is_empty()) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => shift = true,
+                _ => return Err(anyhow!("Unknown modifier '{}' in '{}'", modifier, text)),
+            }
+            key = rest;
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if shift => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            name if name.len() > 1 && name.starts_with('f') => match name[1..].parse::<u8>() {
+                Ok(number) if (1..=12).contains(&number) => KeyCode::F(number),
+                _ => return Err(anyhow!("Unknown key '{}'", text)),
+            },
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if shift => KeyCode::Char(c.to_ascii_uppercase()),
+                    (Some(c), None) if modifiers.is_empty() => KeyCode::Char(c),
+                    // Terminals report Ctrl/Alt letters in lower case
+                    (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+                    _ => return Err(anyhow!("Unknown key '{}'", text)),
+                }
+            }
+        };
+
+        Ok(Self { code, modifiers })
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let relevant = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        self.code == key.code && self.modifiers == key.modifiers & relevant
+    }
+
+    /// Short label for help text, e.g. `↑`, `Esc`, `Ctrl+P`
+    pub fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::F(number) => format!("F{}", number),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if self.modifiers.is_empty() => c.to_string(),
+            KeyCode::Char(c) => c.to_ascii_uppercase().to_string(),
+            _ => "?".to_string(),
+        };
+
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("Alt+");
+        }
+        label.push_str(&key);
+        label
+    }
+}
+
+/// Which keys trigger each action
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .map(|key| KeyBinding::parse(key).expect("default key bindings parse"))
+                    .collect();
+                (*action, keys)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+/// `keymap.json` maps action names to one key or a list of keys
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Keymap {
+    /// Defaults with the user's overrides applied. Problems are returned alongside rather
+    /// than failing, so one typo doesn't lose every other binding.
+    pub fn from_overrides(json: &str) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut problems = Vec::new();
+
+        let overrides: HashMap<String, KeyList> = match serde_json::from_str(json) {
+            Ok(overrides) => overrides,
+            Err(e) => return (keymap, vec![format!("keymap.json isn't valid: {}", e)]),
+        };
+
+        for (name, keys) in overrides {
+            let Some(action) = Action::ALL.iter().find(|action| action.name() == name) else {
+                problems.push(format!("Unknown action '{}'", name));
+                continue;
+            };
+            let keys = match keys {
+                KeyList::One(key) => vec![key],
+                KeyList::Many(keys) => keys,
+            };
+            let mut bindings = Vec::new();
+            for key in keys {
+                match KeyBinding::parse(&key) {
+                    Ok(binding) => bindings.push(binding),
+                    Err(e) => problems.push(format!("{}: {}", name, e)),
+                }
+            }
+            if !bindings.is_empty() {
+                keymap.bindings.insert(*action, bindings);
+            }
+        }
+
+        problems.extend(keymap.conflicts());
+        (keymap, problems)
+    }
+
+    /// Keys bound to two actions in the same scope; whichever the screen checks first wins
+    pub fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for scope in Scope::ALL {
+            let mut owners: Vec<(KeyBinding, Action)> = Vec::new();
+            for action in APP_WIDE.iter().chain(scope.actions()) {
+                for binding in self.keys(*action) {
+                    match owners.iter().find(|(owned, _)| owned == binding) {
+                        Some((_, owner)) => {
+                            let conflict = format!(
+                                "'{}' is bound to both {} and {}",
+                                binding.label(),
+                                owner.name(),
+                                action.name()
+                            );
+                            if !conflicts.contains(&conflict) {
+                                conflicts.push(conflict);
+                            }
+                        }
+                        None => owners.push((*binding, *action)),
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The first of `actions` that a key triggers, if any
+    pub fn action_for(&self, actions: &[Action], key: &KeyEvent) -> Option<Action> {
+        actions
+            .iter()
+            .copied()
+            .find(|action| self.keys(*action).iter().any(|binding| binding.matches(key)))
+    }
+
+    /// First key of each action joined with `/`, e.g. `↑/↓`
+    pub fn label(&self, actions: &[Action]) -> String {
+        actions
+            .iter()
+            .filter_map(|action| self.keys(*action).first())
+            .map(KeyBinding::label)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Every key of each action, lined up so alternates read together, e.g. `↑/↓  k/j`
+    pub fn all_labels(&self, actions: &[Action]) -> String {
+        let most = actions.iter().map(|action| self.keys(*action).len()).max().unwrap_or(0);
+        (0..most)
+            .map(|index| {
+                actions
+                    .iter()
+                    .filter_map(|action| self.keys(*action).get(index))
+                    .map(KeyBinding::label)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+}
+
+static KEYMAP: LazyLock<RwLock<Keymap>> = LazyLock::new(|| RwLock::new(Keymap::default()));
+
+/// `~/.scv-rust/keymap.json`
+pub fn keymap_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
+    Ok(home.join(".scv-rust").join("keymap.json"))
+}
+
+/// Load the user's overrides, if any, and return anything wrong with them
+pub async fn load() -> Vec<String> {
+    let path = match keymap_path() {
+        Ok(path) => path,
+        Err(e) => return vec![e.to_string()],
+    };
+    let Ok(contents) = tokio::fs::read_to_string(&path).await else {
+        return Vec::new();
+    };
+    let (keymap, problems) = Keymap::from_overrides(&contents);
+    install(keymap);
+    problems
+}
+
+pub fn install(keymap: Keymap) {
+    *KEYMAP.write().unwrap_or_else(|e| e.into_inner()) = keymap;
+}
+
+fn with_keymap<T>(f: impl FnOnce(&Keymap) -> T) -> T {
+    f(&KEYMAP.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Whether `key` triggers `action` in the current keymap
+pub fn is(action: Action, key: &KeyEvent) -> bool {
+    with_keymap(|keymap| keymap.keys(action).iter().any(|binding| binding.matches(key)))
+}
+
+/// Which of `actions` a key triggers; pass a screen's `Scope::actions` or a component's own set
+pub fn action_for(actions: &[Action], key: &KeyEvent) -> Option<Action> {
+    with_keymap(|keymap| keymap.action_for(actions, key))
+}
+
+/// A key press that triggers `action`, for turning other input like the mouse wheel into keys
+pub fn key_event(action: Action) -> Option<KeyEvent> {
+    with_keymap(|keymap| keymap.keys(action).first().map(|binding| KeyEvent::new(binding.code, binding.modifiers)))
+}
+
+/// Help text for a group of actions from their first keys, e.g. `↑/↓`
+pub fn label(actions: &[Action]) -> String {
+    with_keymap(|keymap| keymap.label(actions))
+}
+
+/// Every key bound to a group of actions, for the help overlay
+pub fn all_labels(actions: &[Action]) -> String {
+    with_keymap(|keymap| keymap.all_labels(actions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_parse_and_report_conflicts() {
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        assert!(Keymap::default().conflicts().is_empty());
+        assert!(Keymap::default().keys(Action::Up).iter().any(|b| b.matches(&key(KeyCode::Char('k'), KeyModifiers::NONE))));
+
+        let (keymap, problems) = Keymap::from_overrides(
+            r#"{"quit": "ctrl+q", "back": ["esc", "b"], "sort": "x", "refresh": "x", "select_all": "s", "jump": "g", "up": "hyper+k"}"#,
+        );
+        assert!(keymap.keys(Action::Quit)[0].matches(&key(KeyCode::Char('q'), KeyModifiers::CONTROL)));
+        assert!(!keymap.keys(Action::Quit)[0].matches(&key(KeyCode::Char('q'), KeyModifiers::NONE)));
+        assert_eq!(keymap.action_for(Scope::Menus.actions(), &key(KeyCode::Char('b'), KeyModifiers::NONE)), Some(Action::Back));
+        assert_eq!(keymap.label(&[Action::Up, Action::Down]), "↑/↓");
+        assert_eq!(keymap.all_labels(&[Action::Up, Action::Down]), "↑/↓  k/j");
+        assert_eq!(keymap.all_labels(&[Action::Back]), "Esc  b");
+        assert!(problems.contains(&"Unknown action 'jump'".to_string()));
+        assert!(problems.iter().any(|p| p.starts_with("up: Unknown modifier")));
+        assert!(problems.contains(&"'x' is bound to both refresh and sort".to_string()));
+        // Screen keys clash only with actions on the same screen: `s` still sorts elsewhere
+        assert!(problems.contains(&"'s' is bound to both select_all and shell".to_string()));
+        assert!(problems.contains(&"'b' is bound to both back and batch_actions".to_string()));
+        assert!(!problems.iter().any(|p| p.starts_with("'s'") && p.contains("sort")));
+
+        assert_eq!(KeyBinding::parse("shift+tab").unwrap().label(), "Shift+Tab");
+        assert!(KeyBinding::parse("S").unwrap().matches(&key(KeyCode::Char('S'), KeyModifiers::SHIFT)));
+        assert_eq!(KeyBinding::parse("ctrl++").unwrap().label(), "Ctrl++");
+        assert!(KeyBinding::parse("f13").is_err());
+    }
+}
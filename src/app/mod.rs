@@ -1,5 +1,6 @@
-use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
@@ -9,27 +10,46 @@ use ratatui::{
 };
 use std::{
     io,
+    path::Path,
+    process::{Command, ExitStatus},
     time::{Duration, Instant},
 };
-use tokio::time::interval;
+use tokio::{
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+    time::interval,
+};
 
 use crate::{
-    data::github::GitHubClient,
+    data::{
+        assignments::{format_points, CommitSource, StudentSubmission, Submission},
+        github::GitHubClient,
+        Class, Snapshot, Student,
+    },
+    git::LocalCommit,
+    runner::{self, RunStatus},
+    site_check::{self, Severity, SiteCheck},
     ui::{
         animations::AnimationState,
-        components::loading::LoadingWidget,
+        components::{command_palette::{self, CommandPalette, PaletteEntry}, help_overlay::HelpOverlay, loading::LoadingWidget, report_overlay::ReportOverlay},
         layout::ResponsiveLayout,
-        screens::{Screen, ScreenType, ScreenTypeVariant, ScreenContext}, // Fixed imports
+        mouse::{ClickTracker, MouseAction},
+        screens::{student_detail, Screen, ScreenType, ScreenTypeVariant, ScreenContext}, // Fixed imports
         themes::{Theme, THEMES},
     },
+    utils::{csv, launcher, time},
 };
 
+pub mod batch;
 pub mod config;
 pub mod events;
+pub mod keymap;
 pub mod state;
 
-pub use config::Config;
+pub use batch::{BatchAction, BatchReport};
+pub use config::{CommandTemplate, Config};
 pub use events::{AppEvent, EventHandler};
+pub use keymap::{Action, Scope};
 pub use state::{AppState, NavigationStack}; // Removed MenuState as it's unused
 
 const FRAME_RATE: u64 = 60; // Target 60 FPS
@@ -48,6 +68,15 @@ pub struct App {
     should_quit: bool,
     navigation_stack: NavigationStack,
     current_screen: Box<dyn Screen>,
+    palette: CommandPalette,
+    help: HelpOverlay,
+    report: ReportOverlay,
+    clicks: ClickTracker,
+    /// Events sent back by work running in the background, handled between frames
+    background_tx: UnboundedSender<AppEvent>,
+    background_rx: UnboundedReceiver<AppEvent>,
+    /// The test run in progress; while it runs the back key cancels it and other input is ignored
+    test_run: Option<JoinHandle<()>>,
 }
 
 impl App {
@@ -62,6 +91,17 @@ impl App {
         // Initialize components
         let mut state = AppState::new().await?;
         state.set_github_token(github_token.clone());
+        state.preview_port = config.preview_port;
+        if let Some(name) = &config.timezone {
+            match crate::utils::time::parse_timezone(name) {
+                Ok(timezone) => crate::utils::time::set_school_timezone(Some(timezone)),
+                Err(e) => state.set_error(Some(format!("{}; showing times in this computer's timezone", e))),
+            }
+        }
+        let keymap_problems = keymap::load().await;
+        if !keymap_problems.is_empty() {
+            state.set_error(Some(format!("Problems in keymap.json:\n{}", keymap_problems.join("\n"))));
+        }
         let event_handler = EventHandler::new();
         let animation_state = AnimationState::new();
         let layout = ResponsiveLayout::new();
@@ -70,6 +110,7 @@ impl App {
         
         // Create initial screen
         let current_screen = Box::new(crate::ui::screens::main_menu::MainMenuScreen::new());
+        let (background_tx, background_rx) = mpsc::unbounded_channel();
 
         Ok(Self {
             terminal,
@@ -84,6 +125,13 @@ impl App {
             should_quit: false,
             navigation_stack,
             current_screen,
+            palette: CommandPalette::new(),
+            help: HelpOverlay::new(),
+            report: ReportOverlay::new(),
+            clicks: ClickTracker::default(),
+            background_tx,
+            background_rx,
+            test_run: None,
         })
     }
 
@@ -105,6 +153,9 @@ impl App {
                 let event = event::read()?;
                 self.handle_terminal_event(event).await?;
             }
+            while let Ok(event) = self.background_rx.try_recv() {
+                self.handle_app_event(event).await?;
+            }
 
             // Update animations and state
             self.update().await?;
@@ -128,14 +179,54 @@ impl App {
                 self.layout.update_size(width, height);
                 self.terminal.resize(Rect::new(0, 0, width, height))?;
             },
-            Event::Mouse(_) => {
-                // Handle mouse events if needed
+            Event::Mouse(mouse_event) => {
+                self.handle_mouse_event(mouse_event).await?;
             },
             _ => {}
         }
         Ok(())
     }
 
+    async fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<()> {
+        let Some(mouse) = self.clicks.interpret(mouse_event) else {
+            return Ok(());
+        };
+
+        // Clicking dismisses an error/success message the same way a key does
+        if self.state.error().is_some() {
+            if matches!(mouse, MouseAction::Click { .. } | MouseAction::DoubleClick { .. }) {
+                self.state.set_error(None);
+            }
+            return Ok(());
+        }
+        if self.test_run.is_some() {
+            return Ok(());
+        }
+
+        // Overlays are centered in the whole terminal, so hit-test them against it
+        let area = self.layout.area();
+        if self.palette.is_open() {
+            if let Some(event) = self.palette.handle_mouse_event(mouse, area) {
+                self.handle_app_event(event).await?;
+            }
+            return Ok(());
+        }
+        if self.help.is_visible() {
+            self.help.handle_mouse_event(mouse, area);
+            return Ok(());
+        }
+        if self.report.is_visible() {
+            self.report.handle_mouse_event(mouse, area);
+            return Ok(());
+        }
+
+        let app_event = self.current_screen.handle_mouse_event(mouse, &self.state).await?;
+        if let Some(event) = app_event {
+            self.handle_app_event(event).await?;
+        }
+        Ok(())
+    }
+
     async fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
         // Check if there's an error/success message to dismiss
         if self.state.error().is_some() {
@@ -144,13 +235,51 @@ impl App {
             return Ok(());
         }
 
-        // Global key bindings
-        match (key_event.code, key_event.modifiers) {
-            (KeyCode::Char('c'), KeyModifiers::CONTROL) | (KeyCode::Char('q'), KeyModifiers::NONE) => {
+        if self.test_run.is_some() {
+            if key_event.code == KeyCode::Char('c') && key_event.modifiers == KeyModifiers::CONTROL {
                 self.should_quit = true;
-                return Ok(());
-            },
-            _ => {}
+            } else if keymap::is(Action::Back, &key_event) {
+                self.cancel_test_run();
+            }
+            return Ok(());
+        }
+
+        if self.palette.is_open() {
+            if let Some(event) = self.palette.handle_key_event(key_event) {
+                self.handle_app_event(event).await?;
+            }
+            return Ok(());
+        }
+
+        if self.help.is_visible() {
+            self.help.handle_key_event(key_event);
+            return Ok(());
+        }
+
+        if self.report.is_visible() {
+            self.report.handle_key_event(key_event);
+            return Ok(());
+        }
+
+        // Global key bindings; Ctrl+C always quits, whatever the keymap says
+        if key_event.code == KeyCode::Char('c') && key_event.modifiers == KeyModifiers::CONTROL {
+            self.should_quit = true;
+            return Ok(());
+        }
+        if keymap::is(Action::CommandPalette, &key_event) {
+            match self.palette_entries().await {
+                Ok(entries) => self.palette.open(entries),
+                Err(e) => self.state.set_error(Some(format!("Failed to load the command palette: {}", e))),
+            }
+            return Ok(());
+        }
+        if keymap::is(Action::Quit, &key_event) && !self.current_screen.captures_text_input() {
+            self.should_quit = true;
+            return Ok(());
+        }
+        if keymap::is(Action::Help, &key_event) && !self.current_screen.captures_text_input() {
+            self.help.show(self.current_screen.screen_type().variant());
+            return Ok(());
         }
 
         // Let current screen handle the event
@@ -185,6 +314,19 @@ impl App {
             AppEvent::Quit => {
                 self.should_quit = true;
             },
+            AppEvent::InClass(class, event) => {
+                // Go back to the class on screen afterwards, unless the event opened one of this class's screens
+                let previous = self.state.current_class.replace(class.clone());
+                Box::pin(self.handle_app_event(*event)).await?;
+                let screen_type = self.current_screen.screen_type();
+                let showing = screen_type.context().and_then(ScreenContext::class).map(|shown| shown.id);
+                if showing != Some(class.id) {
+                    self.state.current_class = previous;
+                }
+            },
+            AppEvent::ShowHelp => {
+                self.help.show(self.current_screen.screen_type().variant());
+            },
             AppEvent::ShowLoading(message) => {
                 self.state.set_loading(true, message.clone());
                 
@@ -251,9 +393,9 @@ impl App {
                 // Store the selected class in the app state
                 self.state.current_class = Some(class.clone());
                 
-                // Navigate to the class management screen with the selected class
+                // Land on the class dashboard; its class menu is one key away
                 self.navigate_to_screen(
-                    ScreenType::new(ScreenTypeVariant::ClassManagement)
+                    ScreenType::new(ScreenTypeVariant::ClassDashboard)
                         .with_context(ScreenContext::Class(class))
                 ).await?;
             },
@@ -284,6 +426,37 @@ impl App {
             AppEvent::ClassDeleted(_id) => {
                 // TODO: Handle class deletion
             },
+            AppEvent::RenameClass(class, name) => {
+                match self.rename_class(&class, &name).await {
+                    Ok(moved) => {
+                        let renamed = Class { name, ..class.clone() };
+                        if self.state.current_class.as_ref().is_some_and(|current| current.id == renamed.id) {
+                            self.state.current_class = Some(renamed.clone());
+                        }
+                        self.navigation_stack.update_class(&renamed);
+                        let mut screen_type = self.current_screen.screen_type();
+                        screen_type.update_class(&renamed);
+                        self.current_screen = crate::ui::screens::create_screen(screen_type).await?;
+                        
+                        let clones = if moved {
+                            format!("\nClones moved to {}", self.state.git_manager.repos_dir.join(&renamed.name).display())
+                        } else {
+                            String::new()
+                        };
+                        self.state.set_error(Some(format!("✅ Renamed '{}' to '{}'{}", class.name, renamed.name, clones)));
+                    }
+                    Err(e) => self.state.set_error(Some(format!("Failed to rename class: {:#}", e))),
+                }
+            },
+            AppEvent::DuplicateClass(class, name) => {
+                match self.state.database.duplicate_class(class.id, &name).await {
+                    Ok((duplicate, copied)) => self.state.set_error(Some(format!(
+                        "✅ Created '{}' with {} students from '{}'",
+                        duplicate.name, copied, class.name
+                    ))),
+                    Err(e) => self.state.set_error(Some(format!("Failed to duplicate class: {:#}", e))),
+                }
+            },
             AppEvent::StudentAdded(_student) => {
                 // TODO: Handle student addition
             },
@@ -294,7 +467,44 @@ impl App {
                 // TODO: Implement repository cloning
             },
             AppEvent::PullRepositories => {
-                // TODO: Implement repository pulling
+                if let Some(class) = self.state.current_class.clone() {
+                    self.state.set_loading(true, format!("Pulling all repositories for {}...", class.name));
+                    
+                    match self.state.database.get_students_for_class(class.id).await {
+                        Ok(students) => {
+                            let git_manager = &self.state.git_manager;
+                            let mut pulled = 0;
+                            let mut not_cloned = 0;
+                            let mut failures = Vec::new();
+                            
+                            for student in &students {
+                                if !git_manager.repo_exists(&student.github_username, &class.name) {
+                                    not_cloned += 1;
+                                    continue;
+                                }
+                                match git_manager.pull_repo(&student.github_username, &class.name).await {
+                                    Ok(()) => pulled += 1,
+                                    Err(e) => failures.push(format!("{}: {}", student.github_username, e)),
+                                }
+                            }
+                            
+                            self.state.set_loading(false, String::new());
+                            let skipped = if not_cloned > 0 { format!(" ({} not cloned yet)", not_cloned) } else { String::new() };
+                            if failures.is_empty() {
+                                self.state.set_error(Some(format!("✅ Pulled {} repositories{}", pulled, skipped)));
+                            } else {
+                                self.state.set_error(Some(format!(
+                                    "✅ Pulled {} repositories{}\n❌ Failed to pull {} repositories:\n{}",
+                                    pulled, skipped, failures.len(), failures.join("\n")
+                                )));
+                            }
+                        }
+                        Err(e) => {
+                            self.state.set_loading(false, String::new());
+                            self.state.set_error(Some(format!("Failed to get students: {}", e)));
+                        }
+                    }
+                }
             },
             AppEvent::CleanRepositories => {
                 // TODO: Implement repository cleaning
@@ -359,19 +569,449 @@ impl App {
                     }
                 }
             },
-            AppEvent::OpenInTerminal(github_username) => {
+            AppEvent::OpenWith(action, github_username) => {
                 if let Some(class) = &self.state.current_class {
-                    let class_name = class.name.clone();
-                    let repos_dir = self.state.git_manager.repos_dir.clone();
+                    let repo_path = self.state.git_manager.get_repo_path(&github_username, &class.name);
                     
-                    let git_manager = crate::git::GitManager::new(repos_dir);
-                    match git_manager.open_terminal(&github_username, &class_name) {
+                    if action.needs_repo() && !repo_path.exists() {
+                        self.state.set_error(Some(format!("Repository not found at {}", repo_path.display())));
+                        return Ok(());
+                    }
+                    
+                    let template = self.config.open_commands.get(action).clone();
+                    let url = crate::data::github::pages_url(&github_username);
+                    match self.launch(&template, &repo_path, &url) {
                         Ok(()) => {
-                            self.state.set_error(Some(format!("✅ Opened terminal for {}'s repository", github_username)));
+                            if !template.suspend {
+                                self.state.set_error(Some(format!("✅ Opened {} for {}", action.label(), github_username)));
+                            }
+                        }
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to open {} for {}: {}", action.label(), github_username, e)));
+                        }
+                    }
+                }
+            },
+            AppEvent::OpenUrl(url) => {
+                let template = self.config.open_commands.browser.clone();
+                let working_dir = std::env::current_dir().unwrap_or_else(|_| ".".into());
+                if let Err(e) = self.launch(&template, &working_dir, &url) {
+                    self.state.set_error(Some(format!("Failed to open {}: {}", url, e)));
+                }
+            },
+            AppEvent::SuspendToShell(github_username) => {
+                if let Some(class) = &self.state.current_class {
+                    let repo_path = self.state.git_manager.get_repo_path(&github_username, &class.name);
+                    
+                    if !repo_path.exists() {
+                        self.state.set_error(Some(format!("Repository not found at {}", repo_path.display())));
+                        return Ok(());
+                    }
+                    
+                    let mut command = Command::new(launcher::user_shell());
+                    command.current_dir(&repo_path);
+                    
+                    let banner = format!("📂 {} — type 'exit' to return to scv", repo_path.display());
+                    // The shell's exit status only reflects the last command run, so it is not an error
+                    if let Err(e) = self.run_suspended(command, Some(&banner)) {
+                        self.state.set_error(Some(format!("Failed to start shell for {}: {}", github_username, e)));
+                    }
+                }
+            },
+            AppEvent::CheckSite(github_username) => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let students = match self.state.database.get_students_for_class(class.id).await {
+                        Ok(students) => students,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load students: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    let Some(student) = students.into_iter().find(|s| s.github_username == github_username) else {
+                        return Ok(());
+                    };
+                    
+                    self.state.set_loading(true, format!("Checking site for {}...", github_username));
+                    let result = self.check_student_site(&class, &student).await;
+                    self.state.set_loading(false, String::new());
+                    
+                    match result {
+                        Ok(check) if check.report.status() == Severity::Pass => {
+                            self.state.set_error(Some(format!("✅ {}: all site checks passed", github_username)));
+                        }
+                        Ok(check) => {
+                            self.state.set_error(Some(format!("{}: {}", github_username, check.report.summary())));
+                        }
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to check site for {}: {}", github_username, e)));
+                        }
+                    }
+                }
+            },
+            AppEvent::CheckAllSites => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let students = match self.state.database.get_students_for_class(class.id).await {
+                        Ok(students) => students,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load students: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    self.state.set_loading(true, format!("Checking all sites for {}...", class.name));
+                    
+                    let (mut passed, mut warned, mut failed) = (0, 0, 0);
+                    let mut skipped = Vec::new();
+                    let mut failures = Vec::new();
+                    for student in &students {
+                        if !self.state.git_manager.repo_exists(&student.github_username, &class.name) {
+                            skipped.push(student.github_username.clone());
+                            continue;
+                        }
+                        match self.check_student_site(&class, student).await.map(|check| check.report.status()) {
+                            Ok(Severity::Pass) => passed += 1,
+                            Ok(Severity::Warn) => warned += 1,
+                            Ok(Severity::Fail) => failed += 1,
+                            Err(e) => failures.push(format!("{}: {}", student.github_username, e)),
                         }
+                    }
+                    self.state.set_loading(false, String::new());
+                    
+                    let mut summary = format!(
+                        "✅ Checked {} sites: {} pass, {} warn, {} fail",
+                        passed + warned + failed, passed, warned, failed
+                    );
+                    if !skipped.is_empty() {
+                        summary.push_str(&format!("\nSkipped (not cloned): {}", skipped.join(", ")));
+                    }
+                    if !failures.is_empty() {
+                        summary.push_str(&format!("\n❌ Could not check:\n{}", failures.join("\n")));
+                    }
+                    self.state.set_error(Some(summary));
+                }
+            },
+            AppEvent::RunTests => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let loaded: Result<_> = async {
+                        let config = self.state.database.get_runner_config(class.id).await?;
+                        let students = self.state.database.get_students_for_class(class.id).await?;
+                        Ok((config, students))
+                    }.await;
+                    let (config, students) = match loaded {
+                        Ok(loaded) => loaded,
                         Err(e) => {
-                            self.state.set_error(Some(format!("Failed to open terminal for {}: {}", github_username, e)));
+                            self.state.set_error(Some(format!("Failed to load the test runner: {}", e)));
+                            return Ok(());
                         }
+                    };
+                    let repos = students
+                        .into_iter()
+                        .map(|student| {
+                            let repo_path = self.state.git_manager.get_repo_path(&student.github_username, &class.name);
+                            (student, repo_path)
+                        })
+                        .collect();
+                    
+                    self.state.set_loading(true, format!("Running '{}' in every repository... ({} to cancel)", config.command, keymap::label(&[Action::Back])));
+                    let progress = self.background_tx.clone();
+                    let finished = self.background_tx.clone();
+                    self.test_run = Some(tokio::spawn(async move {
+                        let results = runner::run_class(&config, repos, move |done, total| {
+                            let _ = progress.send(AppEvent::TestProgress(done, total));
+                        }).await;
+                        let _ = finished.send(AppEvent::TestsFinished(results));
+                    }));
+                }
+            },
+            AppEvent::TestProgress(done, total) => {
+                if self.test_run.is_some() {
+                    self.state.set_loading(true, format!(
+                        "Running tests: {} of {} repositories finished... ({} to cancel)",
+                        done, total, keymap::label(&[Action::Back])
+                    ));
+                }
+            },
+            AppEvent::TestsFinished(results) => {
+                // A run that was cancelled may still have sent its results
+                if self.test_run.take().is_none() {
+                    return Ok(());
+                }
+                let saved = self.state.database.save_test_results(&results).await;
+                self.state.set_loading(false, String::new());
+                
+                let passed = results.iter().filter(|r| r.status == RunStatus::Passed).count();
+                let ran = results.iter().filter(|r| r.status != RunStatus::NotCloned).count();
+                let mut summary = format!("✅ Tests finished: {} of {} repositories passed", passed, ran);
+                if let Err(e) = saved {
+                    summary.push_str(&format!("\n❌ Failed to save the results: {}", e));
+                }
+                self.state.set_error(Some(summary));
+                
+                if let Some(runner_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::test_runner::TestRunnerScreen>() {
+                    runner_screen.set_results(results);
+                }
+            },
+            AppEvent::ExportTestResults => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let results = match self.state.database.get_latest_test_results(class.id).await {
+                        Ok(results) => results,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to export test results: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    let rows: Vec<Vec<String>> = results
+                        .iter()
+                        .map(|result| vec![
+                            result.github_username.clone(),
+                            result.status.label().to_string(),
+                            result.exit_code.map(|code| code.to_string()).unwrap_or_default(),
+                            format!("{:.1}", result.duration_ms as f64 / 1000.0),
+                            time::format(result.ran_at, "%Y-%m-%dT%H:%M:%S%:z"),
+                            result.summary().to_string(),
+                        ])
+                        .collect();
+                    
+                    let export = csv::export_path(&class.name, "test-results").and_then(|path| {
+                        csv::write_csv(&path, &["github_username", "status", "exit_code", "duration_seconds", "ran_at", "summary"], &rows)?;
+                        Ok(path)
+                    });
+                    match export {
+                        Ok(path) => self.state.set_error(Some(format!("✅ Exported {} results to {}", rows.len(), path.display()))),
+                        Err(e) => self.state.set_error(Some(format!("Failed to export test results: {}", e))),
+                    }
+                }
+            },
+            AppEvent::CheckAssignment(assignment_id) => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let loaded: Result<_> = async {
+                        let assignments = self.state.database.get_assignments_for_class(class.id).await?;
+                        let students = self.state.database.get_students_for_class(class.id).await?;
+                        Ok((assignments, students))
+                    }.await;
+                    let (assignments, students) = match loaded {
+                        Ok(loaded) => loaded,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load assignment: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    let Some(assignment) = assignments.into_iter().find(|a| a.id == assignment_id) else {
+                        return Ok(());
+                    };
+                    let github_client = GitHubClient::new(self.state.get_github_token());
+                    
+                    self.state.set_loading(true, format!("Checking submissions for {}...", assignment.name));
+                    
+                    let mut submissions = Vec::new();
+                    for student in &students {
+                        let github = &student.github_username;
+                        // The local clone is the Pages repo, so only use it when the assignment lives there,
+                        // and only once it has fetched whatever was pushed since it was cloned
+                        let use_clone = assignment.uses_pages_repo(github)
+                            && self.state.git_manager.repo_exists(github, &class.name)
+                            && self.state.git_manager.fetch(github, &class.name).await.is_ok();
+                        let (source, commit_times) = if use_clone {
+                            (CommitSource::LocalClone, self.state.git_manager.commit_times(github, &class.name).await.map(Some))
+                        } else {
+                            (CommitSource::GitHub, github_client.get_submission_times(github, &assignment.repo_name(github), assignment.due_at).await)
+                        };
+                        
+                        let outcome = match commit_times {
+                            Ok(Some(times)) => Ok(Submission::evaluate(&times, assignment.due_at)),
+                            Ok(None) => Ok(Submission::Missing),
+                            Err(e) => Err(e.to_string()),
+                        };
+                        submissions.push(StudentSubmission { student_id: student.id, outcome, source });
+                    }
+                    
+                    self.state.set_loading(false, String::new());
+                    
+                    let count = |f: fn(&Submission) -> bool| submissions.iter().filter(|s| s.outcome.as_ref().is_ok_and(f)).count();
+                    let on_time = count(|s| matches!(s, Submission::OnTime { .. }));
+                    let late = count(|s| matches!(s, Submission::Late { .. }));
+                    let missing = count(|s| matches!(s, Submission::Missing));
+                    let errors = submissions.iter().filter(|s| s.outcome.is_err()).count();
+                    
+                    let mut summary = format!("✅ {}: {} on time, {} late, {} missing", assignment.name, on_time, late, missing);
+                    if errors > 0 {
+                        summary.push_str(&format!(", {} could not be checked", errors));
+                    }
+                    self.state.set_error(Some(summary));
+                    
+                    if let Some(assignments_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::assignments::AssignmentsScreen>() {
+                        assignments_screen.set_submissions(assignment.id, submissions);
+                    }
+                }
+            },
+            AppEvent::SnapshotAssignment(assignment_id) => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let loaded: Result<_> = async {
+                        let assignments = self.state.database.get_assignments_for_class(class.id).await?;
+                        let students = self.state.database.get_students_for_class(class.id).await?;
+                        Ok((assignments, students))
+                    }.await;
+                    let (assignments, students) = match loaded {
+                        Ok(loaded) => loaded,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load assignment: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    let Some(assignment) = assignments.into_iter().find(|a| a.id == assignment_id) else {
+                        return Ok(());
+                    };
+                    let tag = assignment.snapshot_tag();
+                    
+                    self.state.set_loading(true, format!("Checking out {} in every clone...", tag));
+                    
+                    let mut snapshots = Vec::new();
+                    let mut skipped = Vec::new();
+                    let mut failures = Vec::new();
+                    for student in &students {
+                        let github = &student.github_username;
+                        if !assignment.uses_pages_repo(github) || !self.state.git_manager.repo_exists(github, &class.name) {
+                            skipped.push(github.clone());
+                            continue;
+                        }
+                        
+                        let sha = match self.state.git_manager.commit_before(github, &class.name, assignment.due_at).await {
+                            Ok(Some(sha)) => sha,
+                            Ok(None) => {
+                                failures.push(format!("{}: no commits before the deadline", github));
+                                continue;
+                            }
+                            Err(e) => {
+                                failures.push(format!("{}: {}", github, e));
+                                continue;
+                            }
+                        };
+                        
+                        if let Err(e) = self.state.git_manager.checkout_snapshot(github, &class.name, &tag, &sha).await {
+                            failures.push(format!("{}: {}", github, e));
+                            continue;
+                        }
+                        
+                        let snapshot = Snapshot { assignment_id, student_id: student.id, sha, taken_at: chrono::Utc::now() };
+                        match self.state.database.save_snapshot(&snapshot).await {
+                            Ok(()) => snapshots.push(snapshot),
+                            Err(e) => failures.push(format!("{}: checked out, but not recorded: {}", github, e)),
+                        }
+                    }
+                    
+                    self.state.set_loading(false, String::new());
+                    
+                    let mut summary = format!("✅ Checked out {} in {} repositories", tag, snapshots.len());
+                    if !skipped.is_empty() {
+                        summary.push_str(&format!("\nSkipped (not cloned locally): {}", skipped.join(", ")));
+                    }
+                    if !failures.is_empty() {
+                        summary.push_str(&format!("\n❌ Failed:\n{}", failures.join("\n")));
+                    }
+                    self.state.set_error(Some(summary));
+                    
+                    if let Some(assignments_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::assignments::AssignmentsScreen>() {
+                        assignments_screen.add_snapshots(snapshots);
+                    }
+                }
+            },
+            AppEvent::RestoreLatest => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let students = match self.state.database.get_students_for_class(class.id).await {
+                        Ok(students) => students,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load students: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    
+                    self.state.set_loading(true, "Returning every clone to its latest commit...".to_string());
+                    
+                    let mut restored = 0;
+                    let mut failures = Vec::new();
+                    for student in &students {
+                        let github = &student.github_username;
+                        if !self.state.git_manager.repo_exists(github, &class.name) {
+                            continue;
+                        }
+                        match self.state.git_manager.checkout_latest(github, &class.name).await {
+                            Ok(_) => restored += 1,
+                            Err(e) => failures.push(format!("{}: {}", github, e)),
+                        }
+                    }
+                    
+                    self.state.set_loading(false, String::new());
+                    
+                    if failures.is_empty() {
+                        self.state.set_error(Some(format!("✅ Restored {} repositories to their latest commit", restored)));
+                    } else {
+                        self.state.set_error(Some(format!(
+                            "✅ Restored {} repositories\n❌ Failed to restore {}:\n{}",
+                            restored, failures.len(), failures.join("\n")
+                        )));
+                    }
+                }
+            },
+            AppEvent::ExportGradebook => {
+                if let Some(class) = self.state.current_class.clone() {
+                    let loaded: Result<_> = async {
+                        let students = self.state.database.get_students_for_class(class.id).await?;
+                        let assignments = self.state.database.get_assignments_for_class(class.id).await?;
+                        let mut gradebook = Vec::new();
+                        for assignment in assignments {
+                            let rubric = self.state.database.get_rubric(assignment.id).await?;
+                            let grades = self.state.database.get_grades_for_assignment(assignment.id).await?;
+                            gradebook.push((assignment, rubric, grades));
+                        }
+                        Ok((students, gradebook))
+                    }.await;
+                    let (students, gradebook) = match loaded {
+                        Ok(loaded) => loaded,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to export gradebook: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    
+                    // One column per rubric criterion, then a total and comment column per assignment
+                    let mut headers = vec!["github_username".to_string()];
+                    let mut columns = Vec::new();
+                    for (assignment, rubric, grades) in gradebook {
+                        headers.extend(rubric.iter().map(|criterion| {
+                            format!("{}: {} ({})", assignment.name, criterion.name, format_points(criterion.max_points))
+                        }));
+                        let max: f64 = rubric.iter().map(|criterion| criterion.max_points).sum();
+                        headers.push(format!("{}: total ({})", assignment.name, format_points(max)));
+                        headers.push(format!("{}: comment", assignment.name));
+                        columns.push((rubric, grades));
+                    }
+                    
+                    let rows: Vec<Vec<String>> = students
+                        .iter()
+                        .map(|student| {
+                            let mut row = vec![student.github_username.clone()];
+                            for (rubric, grades) in &columns {
+                                let grade = grades.get(&student.id);
+                                row.extend(rubric.iter().map(|criterion| {
+                                    grade
+                                        .and_then(|grade| grade.scores.get(&criterion.id))
+                                        .map(|points| format_points(*points))
+                                        .unwrap_or_default()
+                                }));
+                                row.push(grade.map(|grade| format_points(grade.total())).unwrap_or_default());
+                                row.push(grade.map(|grade| grade.comment.clone()).unwrap_or_default());
+                            }
+                            row
+                        })
+                        .collect();
+                    
+                    let header_refs: Vec<&str> = headers.iter().map(String::as_str).collect();
+                    let export = csv::export_path(&class.name, "gradebook").and_then(|path| {
+                        csv::write_csv(&path, &header_refs, &rows)?;
+                        Ok(path)
+                    });
+                    match export {
+                        Ok(path) => self.state.set_error(Some(format!("✅ Exported gradebook for {} students to {}", rows.len(), path.display()))),
+                        Err(e) => self.state.set_error(Some(format!("Failed to export gradebook: {}", e))),
                     }
                 }
             },
@@ -432,6 +1072,35 @@ impl App {
                     }
                 }
             },
+            AppEvent::RunBatch(action, students) => {
+                if let Some(class) = self.state.current_class.clone() {
+                    self.state.set_loading(true, format!("{} for {} students...", action.label(), students.len()));
+                    let report = self.run_batch(&class, &action, &students).await;
+                    self.state.set_loading(false, String::new());
+                    
+                    // Deletes, moves and tags change what the student lists show
+                    let database = &self.state.database;
+                    let reloaded: Result<_> = async {
+                        let students = database.get_students_for_class(class.id).await?;
+                        let tags = database.get_tags_for_class(class.id).await?;
+                        Ok((students, tags))
+                    }.await;
+                    // The batch already ran, so its report is shown even if the reload fails
+                    self.report.show(report.title(), report.headline(), report.outcome(), report.lines());
+                    let (students, tags) = match reloaded {
+                        Ok(reloaded) => reloaded,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to reload students: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    if let Some(repo_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::repo_management::RepoManagementScreen>() {
+                        repo_screen.set_students(students, tags);
+                    } else if let Some(delete_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::delete_student::DeleteStudentScreen>() {
+                        delete_screen.set_students(students);
+                    }
+                }
+            },
             AppEvent::FetchGitHubActivity => {
                 // TODO: Implement GitHub activity fetching
             },
@@ -454,6 +1123,53 @@ impl App {
                     self.state.set_loading(false, String::new());
                 }
             },
+            AppEvent::UpdateCommitCache(students) => {
+                if let Some(class) = self.state.get_current_class().cloned() {
+                    let github_client = GitHubClient::new(self.state.get_github_token());
+                    let mut failures = Vec::new();
+                    
+                    for (i, student) in students.iter().enumerate() {
+                        self.state.set_loading(true, format!("Fetching commit history ({}/{})...", i + 1, students.len()));
+                        let stored = match self.fetch_commit_times(&github_client, &class, student).await {
+                            Ok(times) => self.state.database.replace_cached_commits(student.id, &times).await
+                                .context("Fetched, but the history could not be saved"),
+                            Err(e) => Err(e),
+                        };
+                        if let Err(e) = stored {
+                            failures.push(format!("{}: {:#}", student.github_username, e));
+                        }
+                    }
+                    self.state.set_loading(false, String::new());
+                    
+                    let cache = match self.state.database.get_commit_cache(class.id).await {
+                        Ok(cache) => cache,
+                        Err(e) => {
+                            self.state.set_error(Some(format!("Failed to load stored commit history: {}", e)));
+                            return Ok(());
+                        }
+                    };
+                    if let Some(heatmap_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::activity_heatmap::ActivityHeatmapScreen>() {
+                        heatmap_screen.set_cache(cache);
+                    } else if let Some(dashboard) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::class_dashboard::ClassDashboardScreen>() {
+                        dashboard.set_cache(cache);
+                    }
+                    
+                    if failures.is_empty() {
+                        self.state.set_error(Some(format!("✅ Updated commit history for {} students", students.len())));
+                    } else {
+                        self.state.set_error(Some(format!("Failed to fetch {} of {}: {}", failures.len(), students.len(), failures.join("; "))));
+                    }
+                }
+            },
+            AppEvent::ShowStudentDetail(student) => {
+                if let Some(class) = self.state.get_current_class().cloned() {
+                    self.navigate_to_screen(
+                        ScreenType::new(ScreenTypeVariant::StudentDetail)
+                            .with_context(ScreenContext::ClassAndStudent(class, student))
+                    ).await?;
+                    self.load_student_activity().await;
+                }
+            },
             AppEvent::ShowLatestActivity => {
                 if let Some(class) = self.state.get_current_class().cloned() {
                     self.state.set_loading(true, "Loading latest activity...".to_string());
@@ -506,6 +1222,11 @@ impl App {
                         
                         self.state.set_loading(false, String::new());
                     }
+                    ScreenTypeVariant::StudentDetail => {
+                        // Reload notes, grades and checks from the database as well as the commit history
+                        self.current_screen = crate::ui::screens::create_screen(self.current_screen.screen_type()).await?;
+                        self.load_student_activity().await;
+                    }
                     _ => {
                         // For other screens, just ignore refresh for now
                     }
@@ -530,9 +1251,207 @@ impl App {
         Ok(())
     }
 
+    /// Stop the test run in progress, killing its commands; nothing from it is saved
+    fn cancel_test_run(&mut self) {
+        if let Some(test_run) = self.test_run.take() {
+            test_run.abort();
+            self.state.set_loading(false, String::new());
+            self.state.set_error(Some("Test run cancelled; no results were saved".to_string()));
+        }
+    }
+
+    /// Palette entries for every class, the current class first, then app-wide ones
+    async fn palette_entries(&self) -> Result<Vec<PaletteEntry>> {
+        let mut classes = self.state.database.get_classes().await?;
+        let current = self.state.current_class.as_ref().map(|class| class.id);
+        classes.sort_by_key(|class| Some(class.id) != current);
+        
+        let mut entries = Vec::new();
+        for class in &classes {
+            let students = self.state.database.get_students_for_class(class.id).await?;
+            entries.extend(command_palette::class_entries(class, &students));
+        }
+        entries.extend(command_palette::global_entries());
+        Ok(entries)
+    }
+
+    /// Commit times for a student's Pages repo, from the local clone when there is one
+    async fn fetch_commit_times(&self, github_client: &GitHubClient, class: &Class, student: &Student) -> Result<Vec<DateTime<Utc>>> {
+        let github = &student.github_username;
+        if self.state.git_manager.repo_exists(github, &class.name) {
+            self.state.git_manager.commit_times(github, &class.name).await
+        } else {
+            Ok(github_client.get_commit_times(github, &format!("{}.github.io", github)).await?.unwrap_or_default())
+        }
+    }
+
+    /// Read the commit history shown on the student detail screen, from the local clone when there is one
+    async fn load_student_activity(&mut self) {
+        let Some(ScreenContext::ClassAndStudent(class, student)) = self.current_screen.screen_type().context().cloned() else {
+            return;
+        };
+        let github = &student.github_username;
+        self.state.set_loading(true, format!("Loading activity for {}...", github));
+        
+        let git_manager = &self.state.git_manager;
+        let activity: Result<_> = if git_manager.repo_exists(github, &class.name) {
+            async {
+                let times = git_manager.commit_times(github, &class.name).await?;
+                let recent = git_manager.recent_commits(github, &class.name, student_detail::RECENT_COMMITS).await?;
+                Ok((times, recent, CommitSource::LocalClone))
+            }.await
+        } else {
+            let github_client = GitHubClient::new(self.state.get_github_token());
+            let repo = format!("{}.github.io", github);
+            async {
+                let times = github_client.get_commit_times(github, &repo).await?.unwrap_or_default();
+                let recent = github_client
+                    .get_recent_commits(github, &repo, student_detail::RECENT_COMMITS)
+                    .await?
+                    .into_iter()
+                    .map(|commit| LocalCommit {
+                        sha: commit.sha,
+                        author: commit.commit.author.name,
+                        date: commit.commit.author.date,
+                        summary: commit.commit.message.lines().next().unwrap_or_default().to_string(),
+                    })
+                    .collect();
+                Ok((times, recent, CommitSource::GitHub))
+            }.await
+        };
+        
+        self.state.set_loading(false, String::new());
+        
+        // Keep the heatmap cache in step with what was just fetched
+        if let Ok((times, _, _)) = &activity {
+            if let Err(e) = self.state.database.replace_cached_commits(student.id, times).await {
+                self.state.set_error(Some(format!("Failed to cache commits: {}", e)));
+            }
+        }
+        
+        if let Some(detail_screen) = self.current_screen.as_any_mut().downcast_mut::<student_detail::StudentDetailScreen>() {
+            match activity {
+                Ok((times, recent, source)) => detail_screen.set_activity(&times, recent, source),
+                Err(e) => detail_screen.set_activity_error(e.to_string()),
+            }
+        }
+    }
+
+    /// Rename a class and its folder of clones together, putting the folder back if the
+    /// database refuses the name. Returns whether there was a folder to move.
+    async fn rename_class(&self, class: &Class, name: &str) -> Result<bool> {
+        let git_manager = &self.state.git_manager;
+        let moved = git_manager.rename_class_dir(&class.name, name)?;
+        if let Err(e) = self.state.database.rename_class(class.id, name).await {
+            if moved {
+                return Err(rolled_back(e, git_manager.rename_class_dir(name, &class.name)));
+            }
+            return Err(e);
+        }
+        Ok(moved)
+    }
+    
+    /// Move a student and their clone to another class together, putting the clone back if the
+    /// database refuses the move. Returns whether there was a clone to move.
+    async fn move_student(&self, class: &Class, student: &Student, target: &Class) -> Result<bool> {
+        let git_manager = &self.state.git_manager;
+        let github_username = &student.github_username;
+        let moved = git_manager.move_repo(github_username, &class.name, &target.name)?;
+        if let Err(e) = self.state.database.move_student(student.id, target.id).await {
+            if moved {
+                return Err(rolled_back(e, git_manager.move_repo(github_username, &target.name, &class.name)));
+            }
+            return Err(e);
+        }
+        Ok(moved)
+    }
+    
+    /// Run a batch action on each student in turn, carrying on past failures
+    async fn run_batch(&self, class: &Class, action: &BatchAction, students: &[Student]) -> BatchReport {
+        let git_manager = &self.state.git_manager;
+        let database = &self.state.database;
+        let mut report = BatchReport::new(action);
+        
+        for student in students {
+            let github_username = &student.github_username;
+            let result = match action {
+                BatchAction::Clone => git_manager.clone_repo(github_username, &class.name).await.map(|()| "cloned".to_string()),
+                BatchAction::Pull => git_manager.pull_repo(github_username, &class.name).await.map(|()| "pulled".to_string()),
+                BatchAction::Clean => git_manager.clean_repo(github_username, &class.name).await.map(|()| "cleaned".to_string()),
+                BatchAction::Delete => database
+                    .delete_student(student.id)
+                    .await
+                    .map(|deleted| if deleted { "deleted" } else { "already gone" }.to_string()),
+                BatchAction::Move(target) => self
+                    .move_student(class, student, target)
+                    .await
+                    .map(|moved| if moved { "moved with their clone" } else { "moved (no clone)" }.to_string()),
+                BatchAction::Copy(target) => database.copy_student(student.id, target.id).await.map(|_| "copied".to_string()),
+                BatchAction::Tag(tag) => database
+                    .add_student_tag(student.id, tag)
+                    .await
+                    .map(|added| if added { "tagged" } else { "already tagged" }.to_string()),
+            };
+            report.push(github_username, result);
+        }
+        
+        report
+    }
+    
+    /// Run the site health checks on a student's clone, store the result and show it on screen
+    async fn check_student_site(&mut self, class: &Class, student: &Student) -> Result<SiteCheck> {
+        let repo_path = self.state.git_manager.get_repo_path(&student.github_username, &class.name);
+        let report = tokio::task::spawn_blocking(move || site_check::check_site(&repo_path)).await??;
+        let check = self.state.database
+            .save_site_check(student.id, &report)
+            .await
+            .context("The site was checked but the result could not be saved")?;
+        
+        if let Some(repo_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::repo_management::RepoManagementScreen>() {
+            repo_screen.set_site_check(check.clone());
+        }
+        
+        Ok(check)
+    }
+    
+    /// Run a configured command template, either in the background or in this terminal
+    fn launch(&mut self, template: &CommandTemplate, path: &Path, url: &str) -> Result<()> {
+        let command = launcher::build_command(&template.command, path, url)?;
+        
+        if template.suspend {
+            let status = self.run_suspended(command, None)?;
+            if !status.success() {
+                return Err(anyhow::anyhow!("Command exited with {}", status));
+            }
+            Ok(())
+        } else {
+            launcher::spawn_detached(command)
+        }
+    }
+
+    /// Leave the TUI, run a command attached to this terminal, then restore the TUI
+    fn run_suspended(&mut self, mut command: Command, banner: Option<&str>) -> Result<ExitStatus> {
+        crate::utils::terminal::restore_terminal()?;
+        if let Some(banner) = banner {
+            println!("{}", banner);
+        }
+        let status = command.status();
+        crate::utils::terminal::setup_terminal()?;
+        
+        // Force a full redraw since the command has drawn over the screen
+        self.terminal.clear()?;
+        
+        status.with_context(|| format!("Failed to run {:?}", command.get_program()))
+    }
+
     async fn navigate_to_screen(&mut self, screen_type: ScreenType) -> Result<()> {
-        self.navigation_stack.push(self.current_screen.screen_type());
-        self.current_screen = crate::ui::screens::create_screen(screen_type.clone()).await?;
+        let next_screen = crate::ui::screens::create_screen(screen_type.clone()).await?;
+        let previous_screen = std::mem::replace(&mut self.current_screen, next_screen);
+        if previous_screen.keep_when_covered() {
+            self.navigation_stack.push_screen(previous_screen);
+        } else {
+            self.navigation_stack.push(previous_screen.screen_type());
+        }
         self.animation_state.trigger_transition();
         Ok(())
     }
@@ -540,9 +1459,13 @@ impl App {
     // Also update the go_back method to refresh data when going back
     
     async fn go_back(&mut self) -> Result<()> {
-        if let Some(previous_screen_type) = self.navigation_stack.pop() {
-            self.current_screen = crate::ui::screens::create_screen(previous_screen_type.clone()).await?;
+        if let Some((previous_screen_type, kept_screen)) = self.navigation_stack.pop() {
             self.animation_state.trigger_transition();
+            if let Some(screen) = kept_screen {
+                self.current_screen = screen;
+                return Ok(());
+            }
+            self.current_screen = crate::ui::screens::create_screen(previous_screen_type.clone()).await?;
             
             // Auto-refresh data when going back to certain screens
             match previous_screen_type.variant() {
@@ -558,6 +1481,22 @@ impl App {
                         }
                     }
                 },
+                ScreenTypeVariant::WeekView => {
+                    if let Some(week_view_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::week_view::WeekViewScreen>() {
+                        week_view_screen.load_activity_data(self.state.github_token.clone()).await;
+                    }
+                },
+                ScreenTypeVariant::LatestActivity => {
+                    let github_client = GitHubClient::new(self.state.get_github_token());
+                    if let Some(latest_activity_screen) = self.current_screen.as_any_mut().downcast_mut::<crate::ui::screens::latest_activity::LatestActivityScreen>() {
+                        if let Err(e) = latest_activity_screen.load_activity_data(&github_client).await {
+                            latest_activity_screen.set_error(format!("Failed to load activity data: {}", e));
+                        }
+                    }
+                },
+                ScreenTypeVariant::StudentDetail => {
+                    self.load_student_activity().await;
+                },
                 _ => {}
             }
         } else {
@@ -577,6 +1516,7 @@ impl App {
         
         // Update current screen
         self.current_screen.update(delta_time, &mut self.state).await?;
+        self.palette.update(delta_time);
 
         Ok(())
     }
@@ -594,6 +1534,9 @@ impl App {
             
             // Render current screen
             self.current_screen.render(frame, area, state, animation_state, theme);
+            self.help.render(frame, area, theme);
+            self.report.render(frame, area, theme);
+            self.palette.render(frame, area, theme);
             
             // Render global overlays (loading, errors, etc.)
             Self::render_overlays_static(frame, area, state, animation_state, theme);
@@ -666,6 +1609,14 @@ impl App {
     }
 }
 
+/// The database error that stopped a move, saying whether the clone folder made it back
+fn rolled_back(error: anyhow::Error, rollback: Result<bool>) -> anyhow::Error {
+    match rollback {
+        Ok(_) => anyhow::anyhow!("{:#} (the clone folder was put back)", error),
+        Err(rollback) => anyhow::anyhow!("{:#}, and the clone folder could not be put back: {:#}", error, rollback),
+    }
+}
+
 // Helper function to center a rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
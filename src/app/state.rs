@@ -1,5 +1,5 @@
 use crate::data::{Database, Class}; // Removed unused Student import
-use crate::ui::screens::ScreenType;
+use crate::ui::screens::{Screen, ScreenType};
 use crate::git::GitManager;
 use std::path::PathBuf;
 
@@ -11,6 +11,7 @@ pub struct AppState {
     pub loading_message: String,
     pub error: Option<String>,
     pub github_token: Option<String>,
+    pub preview_port: u16,
 }
 
 impl AppState {
@@ -32,6 +33,7 @@ impl AppState {
             loading_message: String::new(),
             error: None,
             github_token: None,
+            preview_port: 4000,
         })
     }
     
@@ -79,7 +81,8 @@ impl AppState {
 }
 
 pub struct NavigationStack {
-    stack: Vec<ScreenType>,
+    /// Screens to go back to, rebuilt from their type unless the whole screen was kept
+    stack: Vec<(ScreenType, Option<Box<dyn Screen>>)>,
 }
 
 impl NavigationStack {
@@ -90,10 +93,15 @@ impl NavigationStack {
     }
     
     pub fn push(&mut self, screen_type: ScreenType) {
-        self.stack.push(screen_type);
+        self.stack.push((screen_type, None));
     }
     
-    pub fn pop(&mut self) -> Option<ScreenType> {
+    /// Keep a screen as it is, so going back shows it again without rebuilding it
+    pub fn push_screen(&mut self, screen: Box<dyn Screen>) {
+        self.stack.push((screen.screen_type(), Some(screen)));
+    }
+    
+    pub fn pop(&mut self) -> Option<(ScreenType, Option<Box<dyn Screen>>)> {
         self.stack.pop()
     }
     
@@ -104,4 +112,13 @@ impl NavigationStack {
     pub fn clear(&mut self) {
         self.stack.clear();
     }
+    
+    /// Point screens further back at the updated copy of a class, so going back doesn't revive an old name.
+    /// Kept screens still hold the old copy, so they are rebuilt instead.
+    pub fn update_class(&mut self, class: &Class) {
+        for (screen_type, kept) in &mut self.stack {
+            screen_type.update_class(class);
+            *kept = None;
+        }
+    }
 }
\ No newline at end of file
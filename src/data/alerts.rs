@@ -0,0 +1,188 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::{calendar::SchoolCalendar, AlertThresholds, CachedCommits};
+use crate::utils::time;
+
+/// School days on each side of the trend comparison, a school week
+pub const TREND_SCHOOL_DAYS: usize = 5;
+
+/// How far back to look for school days, so a calendar with none still ends
+const MAX_LOOKBACK_DAYS: usize = 366;
+
+/// Commits needed in the earlier week before a drop counts as a trend rather than noise
+const MIN_TREND_COMMITS: usize = 3;
+
+/// A reason a student may need a check-in
+#[derive(Debug, Clone, PartialEq)]
+pub enum Alert {
+    /// No Pages repository, or one without any commits
+    RepoMissing,
+    NotCloned,
+    Inactive { school_days: u32 },
+    Declining { previous: usize, recent: usize },
+    /// Commit history has never been fetched into the cache
+    NotFetched,
+}
+
+impl Alert {
+    pub fn label(&self) -> String {
+        match self {
+            Alert::RepoMissing => "Repo missing or empty".to_string(),
+            Alert::NotCloned => "Never cloned".to_string(),
+            Alert::Inactive { school_days } => format!("Inactive {} school days", school_days),
+            Alert::Declining { previous, recent } => format!("Declining ({} → {})", previous, recent),
+            Alert::NotFetched => "No stored history yet".to_string(),
+        }
+    }
+
+    /// Higher is more urgent; the dashboard lists the most urgent students first
+    pub fn severity(&self) -> u8 {
+        match self {
+            Alert::RepoMissing => 4,
+            Alert::Inactive { .. } => 3,
+            Alert::Declining { .. } => 2,
+            Alert::NotCloned => 1,
+            Alert::NotFetched => 0,
+        }
+    }
+}
+
+/// What the dashboard knows about one student
+#[derive(Debug, Clone)]
+pub struct StudentStatus {
+    pub latest_commit: Option<DateTime<Utc>>,
+    /// Commits on the last `TREND_SCHOOL_DAYS` school days
+    pub recent_commits: usize,
+    /// Commits on the `TREND_SCHOOL_DAYS` school days before that
+    pub previous_commits: usize,
+    pub alerts: Vec<Alert>,
+}
+
+impl StudentStatus {
+    pub fn severity(&self) -> Option<u8> {
+        self.alerts.iter().map(Alert::severity).max()
+    }
+}
+
+/// The school days of both trend windows up to and including today, oldest first.
+/// Weekends, holidays and breaks are skipped, so a week off doesn't read as a decline.
+pub fn trend_days(calendar: &SchoolCalendar, today: NaiveDate) -> Vec<NaiveDate> {
+    let mut days: Vec<NaiveDate> = std::iter::successors(Some(today), |day| day.pred_opt())
+        .take(MAX_LOOKBACK_DAYS)
+        .filter(|day| calendar.is_school_day(*day))
+        .take(2 * TREND_SCHOOL_DAYS)
+        .collect();
+    days.reverse();
+    days
+}
+
+/// School days strictly between the last commit and today
+pub fn school_days_since(calendar: &SchoolCalendar, last: NaiveDate, today: NaiveDate) -> u32 {
+    last.iter_days()
+        .skip(1)
+        .take_while(|day| *day < today)
+        .filter(|day| calendar.is_school_day(*day))
+        .count() as u32
+}
+
+/// Flag a student from their cached commit history and whether their repo is cloned
+pub fn assess(
+    cached: Option<&CachedCommits>,
+    cloned: bool,
+    calendar: &SchoolCalendar,
+    thresholds: &AlertThresholds,
+    today: NaiveDate,
+) -> StudentStatus {
+    let Some(cached) = cached else {
+        return StudentStatus {
+            latest_commit: None,
+            recent_commits: 0,
+            previous_commits: 0,
+            alerts: vec![Alert::NotFetched],
+        };
+    };
+
+    let days = trend_days(calendar, today);
+    let (previous_days, recent_days) = days.split_at(days.len().saturating_sub(TREND_SCHOOL_DAYS));
+    let commits_on = |window: &[NaiveDate]| {
+        cached.times.iter().filter(|at| window.contains(&time::school_date(**at))).count()
+    };
+    let recent_commits = commits_on(recent_days);
+    let previous_commits = commits_on(previous_days);
+
+    let mut alerts = Vec::new();
+    let latest_commit = cached.times.iter().max().copied();
+    match latest_commit {
+        Some(latest) => {
+            let idle = school_days_since(calendar, time::school_date(latest), today);
+            if idle >= thresholds.inactive_school_days {
+                alerts.push(Alert::Inactive { school_days: idle });
+            }
+        }
+        None => alerts.push(Alert::RepoMissing),
+    }
+
+    if previous_commits >= MIN_TREND_COMMITS
+        && recent_commits * 100 <= previous_commits * (100 - thresholds.decline_percent.min(100) as usize)
+    {
+        alerts.push(Alert::Declining { previous: previous_commits, recent: recent_commits });
+    }
+    if !cloned && latest_commit.is_some() {
+        alerts.push(Alert::NotCloned);
+    }
+    alerts.sort_by_key(|alert| std::cmp::Reverse(alert.severity()));
+
+    StudentStatus {
+        latest_commit,
+        recent_commits,
+        previous_commits,
+        alerts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CalendarEntry, DayKind};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_assess_flags_inactive_and_declining_students() {
+        let date = |value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap();
+        let today = date("2024-03-15"); // Friday
+        let calendar = SchoolCalendar::default();
+        assert_eq!(school_days_since(&calendar, date("2024-03-08"), today), 4);
+        assert_eq!(school_days_since(&calendar, date("2024-03-14"), today), 0);
+
+        // One commit a day through Friday the 8th, then nothing
+        let cached = CachedCommits {
+            student_id: 1,
+            times: (4..=8).map(|day| Utc.with_ymd_and_hms(2024, 3, day, 12, 0, 0).unwrap()).collect(),
+            updated_at: Utc::now(),
+        };
+        let status = assess(Some(&cached), true, &calendar, &AlertThresholds::default(), today);
+        assert_eq!((status.previous_commits, status.recent_commits), (5, 0));
+        assert_eq!(
+            status.alerts,
+            vec![Alert::Inactive { school_days: 4 }, Alert::Declining { previous: 5, recent: 0 }]
+        );
+
+        // A week off is neither inactivity nor a decline
+        let spring_break = SchoolCalendar::new(vec![CalendarEntry {
+            id: 1,
+            class_id: 1,
+            name: "Spring Break".to_string(),
+            start: date("2024-03-11"),
+            end: date("2024-03-14"),
+            kind: DayKind::Break,
+        }]);
+        let status = assess(Some(&cached), true, &spring_break, &AlertThresholds::default(), today);
+        assert_eq!((status.previous_commits, status.recent_commits), (1, 4));
+        assert!(status.alerts.is_empty());
+
+        let empty = CachedCommits { times: Vec::new(), ..cached };
+        let missing = assess(Some(&empty), false, &calendar, &AlertThresholds::default(), today);
+        assert_eq!(missing.alerts, vec![Alert::RepoMissing]);
+        assert_eq!(assess(None, true, &calendar, &AlertThresholds::default(), today).alerts, vec![Alert::NotFetched]);
+    }
+}
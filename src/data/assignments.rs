@@ -0,0 +1,184 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use super::RubricCriterion;
+use crate::utils::time;
+
+/// Where commit times for a submission were read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitSource {
+    LocalClone,
+    GitHub,
+}
+
+impl CommitSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommitSource::LocalClone => "local clone",
+            CommitSource::GitHub => "GitHub",
+        }
+    }
+}
+
+/// How a student's commits line up with an assignment deadline
+#[derive(Debug, Clone, PartialEq)]
+pub enum Submission {
+    OnTime {
+        last_commit: DateTime<Utc>,
+    },
+    Late {
+        /// Last commit made before the deadline, if any
+        last_on_time: Option<DateTime<Utc>>,
+        last_commit: DateTime<Utc>,
+        late_by: Duration,
+        late_commits: usize,
+    },
+    Missing,
+}
+
+impl Submission {
+    /// Classify commit times against a deadline; any commit after the deadline makes the submission late
+    pub fn evaluate(commit_times: &[DateTime<Utc>], due_at: DateTime<Utc>) -> Self {
+        let last_on_time = commit_times.iter().filter(|time| **time <= due_at).max().copied();
+        let late: Vec<_> = commit_times.iter().filter(|time| **time > due_at).collect();
+
+        match (late.iter().max(), last_on_time) {
+            (Some(last_commit), _) => Submission::Late {
+                last_on_time,
+                last_commit: **last_commit,
+                late_by: **last_commit - due_at,
+                late_commits: late.len(),
+            },
+            (None, Some(last_commit)) => Submission::OnTime { last_commit },
+            (None, None) => Submission::Missing,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Submission::OnTime { .. } => "On time".to_string(),
+            Submission::Late { late_by, .. } => format!("Late by {}", format_duration(*late_by)),
+            Submission::Missing => "Missing".to_string(),
+        }
+    }
+}
+
+/// Result of checking one student's repo against an assignment
+#[derive(Debug, Clone)]
+pub struct StudentSubmission {
+    pub student_id: i64,
+    pub outcome: Result<Submission, String>,
+    pub source: CommitSource,
+}
+
+/// Parse a due date typed as `YYYY-MM-DD HH:MM` or `YYYY-MM-DD` (end of day) in the school timezone
+pub fn parse_due(input: &str) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M").ok().or_else(|| {
+        NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_time(NaiveTime::from_hms_opt(23, 59, 0).unwrap_or_default()))
+    })?;
+    time::from_school_time(naive)
+}
+
+/// Parse a rubric typed as `Layout: 10, Content: 5`, keeping the criteria in order
+pub fn parse_rubric(input: &str) -> Result<Vec<(String, f64)>, String> {
+    let mut criteria: Vec<(String, f64)> = Vec::new();
+
+    for entry in input.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (name, points) = entry
+            .rsplit_once(':')
+            .ok_or_else(|| format!("'{}' needs points, e.g. '{}: 10'", entry, entry))?;
+        let name = name.trim().to_string();
+        let points: f64 = points
+            .trim()
+            .parse()
+            .ok()
+            .filter(|points: &f64| points.is_finite() && *points > 0.0)
+            .ok_or_else(|| format!("'{}' must be worth a positive number of points", name))?;
+
+        if name.is_empty() {
+            return Err("Every criterion needs a name".to_string());
+        }
+        if criteria.iter().any(|(existing, _)| existing.eq_ignore_ascii_case(&name)) {
+            return Err(format!("'{}' is listed twice", name));
+        }
+        criteria.push((name, points));
+    }
+
+    Ok(criteria)
+}
+
+/// The inverse of `parse_rubric`, for editing an existing rubric
+pub fn format_rubric(rubric: &[RubricCriterion]) -> String {
+    rubric
+        .iter()
+        .map(|criterion| format!("{}: {}", criterion.name, format_points(criterion.max_points)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Points without a trailing `.0`, e.g. "10" or "7.5"
+pub fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{}", (points * 100.0).round() / 100.0)
+    }
+}
+
+/// Compact duration such as "2d 3h", "5h 12m" or "40m"
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / (24 * 60), (minutes / 60) % 24, minutes % 60);
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_evaluate_submission_against_deadline() {
+        let due = Utc.with_ymd_and_hms(2024, 3, 1, 23, 59, 0).unwrap();
+        let before = due - Duration::hours(5);
+        let after = due + Duration::hours(26) + Duration::minutes(3);
+
+        assert_eq!(Submission::evaluate(&[before], due), Submission::OnTime { last_commit: before });
+        assert_eq!(Submission::evaluate(&[], due), Submission::Missing);
+
+        let late = Submission::evaluate(&[before, after, due + Duration::minutes(1)], due);
+        assert_eq!(
+            late,
+            Submission::Late {
+                last_on_time: Some(before),
+                last_commit: after,
+                late_by: after - due,
+                late_commits: 2,
+            }
+        );
+        assert_eq!(late.label(), "Late by 1d 2h");
+    }
+
+    #[test]
+    fn test_parse_rubric() {
+        assert_eq!(
+            parse_rubric("Layout: 10, Alt text: 2.5,").unwrap(),
+            vec![("Layout".to_string(), 10.0), ("Alt text".to_string(), 2.5)]
+        );
+        assert_eq!(parse_rubric("  ").unwrap(), vec![]);
+        assert!(parse_rubric("Layout").is_err());
+        assert!(parse_rubric("Layout: -1").is_err());
+        assert!(parse_rubric("Layout: 5, layout: 5").is_err());
+        assert_eq!(format_points(2.5), "2.5");
+        assert_eq!(format_points(10.0), "10");
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::{anyhow, Context, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use super::{CalendarEntry, DayKind};
+
+/// A class's school calendar: weekdays are school days unless a holiday or break covers them
+#[derive(Debug, Clone, Default)]
+pub struct SchoolCalendar {
+    entries: Vec<CalendarEntry>,
+}
+
+impl SchoolCalendar {
+    pub fn new(mut entries: Vec<CalendarEntry>) -> Self {
+        entries.sort_by_key(|entry| (entry.start, entry.end));
+        Self { entries }
+    }
+
+    /// Entries ordered by start date
+    pub fn entries(&self) -> &[CalendarEntry] {
+        &self.entries
+    }
+
+    /// The holiday or break covering a date, if any
+    pub fn closure(&self, date: NaiveDate) -> Option<&CalendarEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.kind != DayKind::SchoolDay && entry.contains(date))
+    }
+
+    /// Whether students are expected to work on this date; make-up days win over closures
+    pub fn is_school_day(&self, date: NaiveDate) -> bool {
+        if self.entries.iter().any(|entry| entry.kind == DayKind::SchoolDay && entry.contains(date)) {
+            return true;
+        }
+        self.closure(date).is_none() && !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+}
+
+/// Parse `YYYY-MM-DD` or an inclusive `YYYY-MM-DD..YYYY-MM-DD`
+pub fn parse_span(input: &str) -> Result<(NaiveDate, NaiveDate)> {
+    let parse_date = |value: &str| {
+        NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+            .with_context(|| format!("Invalid date '{}', expected YYYY-MM-DD", value.trim()))
+    };
+    let (start, end) = match input.split_once("..") {
+        Some((start, end)) => (parse_date(start)?, parse_date(end)?),
+        None => (parse_date(input)?, parse_date(input)?),
+    };
+    if end < start {
+        return Err(anyhow!("Range ends before it starts"));
+    }
+    Ok((start, end))
+}
+
+/// A day-off event read from an iCalendar file
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    pub name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Whether it was a whole-day event; timed ones are usually meetings rather than days off
+    pub all_day: bool,
+}
+
+impl IcsEvent {
+    /// Single days are holidays, anything longer is a break
+    pub fn kind(&self) -> DayKind {
+        if self.start == self.end { DayKind::Holiday } else { DayKind::Break }
+    }
+}
+
+/// A VEVENT being read; dates carry whether they were bare DATE values
+#[derive(Default)]
+struct PendingEvent {
+    summary: Option<String>,
+    start: Option<(NaiveDate, bool)>,
+    end: Option<(NaiveDate, bool)>,
+}
+
+/// Read the VEVENTs of an `.ics` export as inclusive date ranges
+pub fn parse_ics(contents: &str) -> Result<Vec<IcsEvent>> {
+    // Long lines are folded onto continuation lines that start with a space or tab
+    let mut lines: Vec<String> = Vec::new();
+    for line in contents.lines() {
+        match (line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<PendingEvent> = None;
+
+    for line in &lines {
+        let Some((property, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = property.split(';').next().unwrap_or_default().to_ascii_uppercase();
+
+        match (name.as_str(), value.trim()) {
+            ("BEGIN", "VEVENT") => current = Some(PendingEvent::default()),
+            ("END", "VEVENT") => {
+                let Some(PendingEvent { summary, start: Some((start, all_day)), end }) = current.take() else {
+                    continue;
+                };
+                // All-day events end on the following day (DTEND is exclusive)
+                let end = match end {
+                    Some((end, true)) => end - Duration::days(1),
+                    Some((end, false)) => end,
+                    None => start,
+                };
+                events.push(IcsEvent {
+                    name: summary.unwrap_or_else(|| "Day off".to_string()),
+                    start,
+                    end: end.max(start),
+                    all_day,
+                });
+            }
+            ("SUMMARY", summary) => {
+                if let Some(event) = current.as_mut() {
+                    event.summary = Some(unescape_text(summary));
+                }
+            }
+            ("DTSTART", date) => {
+                if let Some(event) = current.as_mut() {
+                    event.start = Some(parse_ics_date(date)?);
+                }
+            }
+            ("DTEND", date) => {
+                if let Some(event) = current.as_mut() {
+                    event.end = Some(parse_ics_date(date)?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if events.is_empty() && !contents.contains("BEGIN:VCALENDAR") {
+        return Err(anyhow!("Not an iCalendar file"));
+    }
+    Ok(events)
+}
+
+/// The date of a DATE or DATE-TIME value, and whether it was a bare date
+fn parse_ics_date(value: &str) -> Result<(NaiveDate, bool)> {
+    let date = value.get(..8).unwrap_or(value);
+    let parsed = NaiveDate::parse_from_str(date, "%Y%m%d")
+        .with_context(|| format!("Invalid calendar date '{}'", value))?;
+    Ok((parsed, value.len() == 8))
+}
+
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ics_and_school_days() {
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:Thanksgiving\\, no school\r\nDTSTART;VALUE=DATE:20241128\r\nDTEND;VALUE=DATE:20241130\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nSUMMARY:Winter\r\n  Break\r\nDTSTART;VALUE=DATE:20241223\r\nDTEND;VALUE=DATE:20250104\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nSUMMARY:PD Day\r\nDTSTART:20241011T080000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let events = parse_ics(ics).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].name, "Thanksgiving, no school");
+        assert_eq!(events[0].end, NaiveDate::from_ymd_opt(2024, 11, 29).unwrap());
+        assert_eq!(events[1].name, "Winter Break");
+        assert_eq!(events[1].kind(), DayKind::Break);
+        assert_eq!(events[2].kind(), DayKind::Holiday);
+        assert!(events[0].all_day && !events[2].all_day);
+        assert!(parse_ics("hello").is_err());
+
+        let entry = |name: &str, start: &str, kind| {
+            let (start, end) = parse_span(start).unwrap();
+            CalendarEntry { id: 0, class_id: 1, name: name.to_string(), start, end, kind }
+        };
+        let calendar = SchoolCalendar::new(vec![
+            entry("Winter Break", "2024-12-23..2025-01-03", DayKind::Break),
+            entry("Make-up day", "2024-12-28", DayKind::SchoolDay),
+        ]);
+        let date = |value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap();
+        assert!(calendar.is_school_day(date("2024-12-20")));
+        assert!(!calendar.is_school_day(date("2024-12-21")));
+        assert!(!calendar.is_school_day(date("2024-12-23")));
+        assert!(calendar.is_school_day(date("2024-12-28")));
+        assert_eq!(calendar.closure(date("2025-01-02")).map(|e| e.name.as_str()), Some("Winter Break"));
+    }
+}
@@ -2,10 +2,14 @@ use anyhow::Result;
 use rusqlite::{Connection, params};
 use std::path::PathBuf;
 use dirs::home_dir;
-use chrono::Utc; // Removed unused DateTime import
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use super::models::{Class, Student};
+use super::calendar::{IcsEvent, SchoolCalendar};
+use super::models::{AlertThresholds, Assignment, CachedCommits, CalendarEntry, Class, DayKind, Grade, MetricSettings, Note, RubricCriterion, Snapshot, Student};
+use crate::runner::{RunStatus, RunnerConfig, TestResult};
+use crate::site_check::{SiteCheck, SiteReport};
 
 use tokio::sync::Mutex;
 
@@ -20,6 +24,10 @@ impl Database {
         
         let conn = Connection::open(&db_path)?;
         
+        // Only some SQLite builds turn foreign keys on by default; without them ON DELETE CASCADE
+        // does nothing and deleting a student or class leaves their notes, grades, tags and history behind
+        conn.pragma_update(None, "foreign_keys", true)?;
+        
         // Create tables if they don't exist
         Self::create_tables(&conn)?;
         
@@ -55,9 +63,231 @@ impl Database {
             [],
         )?;
         
+        // Create site checks table (latest health check per student)
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS site_checks (
+                student_id INTEGER PRIMARY KEY,
+                checked_at TEXT NOT NULL,
+                status TEXT NOT NULL,
+                findings TEXT NOT NULL,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create grading runner settings table (one row per class)
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS runner_configs (
+                class_id INTEGER PRIMARY KEY,
+                command TEXT NOT NULL,
+                timeout_secs INTEGER NOT NULL,
+                concurrency INTEGER NOT NULL,
+                FOREIGN KEY (class_id) REFERENCES classes (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create test results table (every run is kept)
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS test_results (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                student_id INTEGER NOT NULL,
+                ran_at TEXT NOT NULL,
+                status TEXT NOT NULL,
+                exit_code INTEGER,
+                duration_ms INTEGER NOT NULL,
+                stdout TEXT NOT NULL,
+                stderr TEXT NOT NULL,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create assignments table
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS assignments (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                class_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                due_at TEXT NOT NULL,
+                repo_pattern TEXT NOT NULL DEFAULT '{github}.github.io',
+                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (class_id) REFERENCES classes (id) ON DELETE CASCADE,
+                UNIQUE(class_id, name)
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create snapshots table
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS snapshots (
+                assignment_id INTEGER NOT NULL,
+                student_id INTEGER NOT NULL,
+                sha TEXT NOT NULL,
+                taken_at TEXT NOT NULL,
+                PRIMARY KEY (assignment_id, student_id),
+                FOREIGN KEY (assignment_id) REFERENCES assignments (id) ON DELETE CASCADE,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create rubric tables
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS rubric_criteria (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                assignment_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                max_points REAL NOT NULL,
+                position INTEGER NOT NULL,
+                FOREIGN KEY (assignment_id) REFERENCES assignments (id) ON DELETE CASCADE,
+                UNIQUE(assignment_id, name)
+            )
+            "#,
+            [],
+        )?;
+        
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS grades (
+                assignment_id INTEGER NOT NULL,
+                student_id INTEGER NOT NULL,
+                comment TEXT NOT NULL DEFAULT '',
+                graded_at TEXT NOT NULL,
+                PRIMARY KEY (assignment_id, student_id),
+                FOREIGN KEY (assignment_id) REFERENCES assignments (id) ON DELETE CASCADE,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS scores (
+                assignment_id INTEGER NOT NULL,
+                student_id INTEGER NOT NULL,
+                criterion_id INTEGER NOT NULL,
+                points REAL NOT NULL,
+                PRIMARY KEY (assignment_id, student_id, criterion_id),
+                FOREIGN KEY (criterion_id) REFERENCES rubric_criteria (id) ON DELETE CASCADE,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create notes table
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS notes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                student_id INTEGER NOT NULL,
+                body TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create school calendar table; dates are inclusive YYYY-MM-DD
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS calendar_entries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                class_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                start_date TEXT NOT NULL,
+                end_date TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                FOREIGN KEY (class_id) REFERENCES classes (id) ON DELETE CASCADE,
+                UNIQUE(class_id, name, start_date, end_date)
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create per-class alert thresholds table
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_thresholds (
+                class_id INTEGER PRIMARY KEY,
+                inactive_school_days INTEGER NOT NULL,
+                decline_percent INTEGER NOT NULL,
+                FOREIGN KEY (class_id) REFERENCES classes (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create per-class metric definitions table
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS metric_settings (
+                class_id INTEGER PRIMARY KEY,
+                min_daily_commits INTEGER NOT NULL,
+                weekly_target_days INTEGER NOT NULL,
+                consistency_weeks INTEGER NOT NULL,
+                FOREIGN KEY (class_id) REFERENCES classes (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create commit cache tables, refreshed per student from their clone or GitHub
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS commit_cache (
+                student_id INTEGER NOT NULL,
+                committed_at TEXT NOT NULL,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS commit_cache_updates (
+                student_id INTEGER PRIMARY KEY,
+                updated_at TEXT NOT NULL,
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
+        // Create student tags table, free-form labels applied in batches
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS student_tags (
+                student_id INTEGER NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (student_id, tag),
+                FOREIGN KEY (student_id) REFERENCES students (id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        
         // Create indexes
         conn.execute("CREATE INDEX IF NOT EXISTS idx_students_class_id ON students(class_id)", [])?;
         conn.execute("CREATE INDEX IF NOT EXISTS idx_students_username ON students(username)", [])?;
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_test_results_student_id ON test_results(student_id)", [])?;
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_notes_student_id ON notes(student_id)", [])?;
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_commit_cache_student_id ON commit_cache(student_id)", [])?;
         
         Ok(())
     }
@@ -66,6 +296,10 @@ impl Database {
     
     pub async fn create_class(&self, name: &str) -> Result<Class> {
         let conn = self.conn.lock().await;
+        Self::insert_class(&conn, name)
+    }
+    
+    fn insert_class(conn: &Connection, name: &str) -> Result<Class> {
         let mut stmt = conn.prepare(
             "INSERT INTO classes (name, created_at) VALUES (?, datetime('now')) RETURNING id, name, created_at"
         )?;
@@ -74,7 +308,7 @@ impl Database {
             Ok(Class {
                 id: row.get(0)?,
                 name: row.get(1)?,
-                created_at: Utc::now(), // For now, use current time
+                created_at: parse_timestamp(row.get(2)?),
             })
         })?;
         
@@ -88,7 +322,7 @@ impl Database {
             Ok(Class {
                 id: row.get(0)?,
                 name: row.get(1)?,
-                created_at: Utc::now(), // For now, use current time
+                created_at: parse_timestamp(row.get(2)?),
             })
         })?;
         
@@ -107,7 +341,7 @@ impl Database {
             Ok(Class {
                 id: row.get(0)?,
                 name: row.get(1)?,
-                created_at: Utc::now(), // For now, use current time
+                created_at: parse_timestamp(row.get(2)?),
             })
         })?;
         
@@ -117,6 +351,42 @@ impl Database {
         }
     }
     
+    /// Rename a class; fails if another class already has the name
+    pub async fn rename_class(&self, id: i64, name: &str) -> Result<()> {
+        let conn = self.conn.lock().await;
+        if Self::class_name_taken(&conn, name)? {
+            return Err(anyhow::anyhow!("A class named '{}' already exists", name));
+        }
+        
+        conn.execute("UPDATE classes SET name = ? WHERE id = ?", params![name, id])?;
+        Ok(())
+    }
+    
+    /// Create a class with a copy of another class's roster, e.g. for a new semester.
+    /// Returns the new class and how many students were copied.
+    pub async fn duplicate_class(&self, id: i64, name: &str) -> Result<(Class, usize)> {
+        let mut conn = self.conn.lock().await;
+        // One transaction, so a failed roster copy doesn't leave an empty class behind
+        let tx = conn.transaction()?;
+        if Self::class_name_taken(&tx, name)? {
+            return Err(anyhow::anyhow!("A class named '{}' already exists", name));
+        }
+        
+        let class = Self::insert_class(&tx, name)?;
+        let copied = tx.execute(
+            "INSERT INTO students (class_id, username, github_username, created_at) 
+             SELECT ?, username, github_username, datetime('now') FROM students WHERE class_id = ?",
+            params![class.id, id],
+        )?;
+        tx.commit()?;
+        
+        Ok((class, copied))
+    }
+    
+    fn class_name_taken(conn: &Connection, name: &str) -> Result<bool> {
+        Ok(conn.query_row("SELECT EXISTS(SELECT 1 FROM classes WHERE name = ?)", params![name], |row| row.get(0))?)
+    }
+    
     pub async fn delete_class(&self, id: i64) -> Result<bool> {
         let conn = self.conn.lock().await;
         let affected = conn.execute("DELETE FROM classes WHERE id = ?", params![id])?;
@@ -139,7 +409,7 @@ impl Database {
                 class_id: row.get(1)?,
                 username: row.get(2)?,
                 github_username: row.get(3)?,
-                created_at: Utc::now(), // For now, use current time
+                created_at: parse_timestamp(row.get(4)?),
             })
         })?;
         
@@ -158,7 +428,7 @@ impl Database {
                 class_id: row.get(1)?,
                 username: row.get(2)?,
                 github_username: row.get(3)?,
-                created_at: Utc::now(), // For now, use current time
+                created_at: parse_timestamp(row.get(4)?),
             })
         })?;
         
@@ -176,16 +446,700 @@ impl Database {
         Ok(affected > 0)
     }
     
+    /// Move a student to another class, keeping their notes, grades and history
+    pub async fn move_student(&self, id: i64, class_id: i64) -> Result<()> {
+        let conn = self.conn.lock().await;
+        Self::ensure_username_free(&conn, id, class_id)?;
+        
+        conn.execute("UPDATE students SET class_id = ? WHERE id = ?", params![class_id, id])?;
+        Ok(())
+    }
+    
+    /// Add a student to another class as well, e.g. when they take two sections.
+    /// Only the roster entry is copied; notes, grades and history stay with the original.
+    pub async fn copy_student(&self, id: i64, class_id: i64) -> Result<Student> {
+        let conn = self.conn.lock().await;
+        Self::ensure_username_free(&conn, id, class_id)?;
+        
+        let mut stmt = conn.prepare(
+            "INSERT INTO students (class_id, username, github_username, created_at) 
+             SELECT ?, username, github_username, datetime('now') FROM students WHERE id = ? 
+             RETURNING id, class_id, username, github_username, created_at"
+        )?;
+        let student = stmt.query_row(params![class_id, id], |row| {
+            Ok(Student {
+                id: row.get(0)?,
+                class_id: row.get(1)?,
+                username: row.get(2)?,
+                github_username: row.get(3)?,
+                created_at: parse_timestamp(row.get(4)?),
+            })
+        })?;
+        
+        Ok(student)
+    }
+    
+    /// Usernames are unique within a class, so check before moving or copying a student into one
+    fn ensure_username_free(conn: &Connection, id: i64, class_id: i64) -> Result<()> {
+        let taken: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM students WHERE class_id = ? AND username = (SELECT username FROM students WHERE id = ?))",
+            params![class_id, id],
+            |row| row.get(0),
+        )?;
+        if taken {
+            return Err(anyhow::anyhow!("A student with the same username is already in that class"));
+        }
+        Ok(())
+    }
+    
+    /// Tag a student; returns false if they already had the tag
+    pub async fn add_student_tag(&self, student_id: i64, tag: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let affected = conn.execute(
+            "INSERT OR IGNORE INTO student_tags (student_id, tag) VALUES (?, ?)",
+            params![student_id, tag],
+        )?;
+        Ok(affected > 0)
+    }
+    
+    /// Tags for every tagged student in a class, keyed by student id
+    pub async fn get_tags_for_class(&self, class_id: i64) -> Result<HashMap<i64, Vec<String>>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT t.student_id, t.tag 
+             FROM student_tags t JOIN students s ON s.id = t.student_id 
+             WHERE s.class_id = ? ORDER BY t.tag"
+        )?;
+        let tag_iter = stmt.query_map(params![class_id], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+        
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        for tag in tag_iter {
+            let (student_id, tag) = tag?;
+            tags.entry(student_id).or_default().push(tag);
+        }
+        
+        Ok(tags)
+    }
+    
     pub async fn get_student_count_for_class(&self, class_id: i64) -> Result<i64> {
         let conn = self.conn.lock().await;
         let mut stmt = conn.prepare("SELECT COUNT(*) FROM students WHERE class_id = ?")?;
         let count: i64 = stmt.query_row(params![class_id], |row| row.get(0))?;
         Ok(count)
     }
+    
+    // ===== NOTE OPERATIONS =====
+    
+    pub async fn add_note(&self, student_id: i64, body: &str) -> Result<Note> {
+        let conn = self.conn.lock().await;
+        let now = Utc::now();
+        conn.execute(
+            "INSERT INTO notes (student_id, body, created_at, updated_at) VALUES (?, ?, ?, ?)",
+            params![student_id, body, now.to_rfc3339(), now.to_rfc3339()],
+        )?;
+        
+        Ok(Note {
+            id: conn.last_insert_rowid(),
+            student_id,
+            body: body.to_string(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+    
+    pub async fn update_note(&self, id: i64, body: &str) -> Result<DateTime<Utc>> {
+        let conn = self.conn.lock().await;
+        let now = Utc::now();
+        conn.execute(
+            "UPDATE notes SET body = ?, updated_at = ? WHERE id = ?",
+            params![body, now.to_rfc3339(), id],
+        )?;
+        Ok(now)
+    }
+    
+    pub async fn delete_note(&self, id: i64) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let affected = conn.execute("DELETE FROM notes WHERE id = ?", params![id])?;
+        Ok(affected > 0)
+    }
+    
+    /// Notes for every student in a class, newest first
+    pub async fn get_notes_for_class(&self, class_id: i64) -> Result<Vec<Note>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT n.id, n.student_id, n.body, n.created_at, n.updated_at 
+             FROM notes n JOIN students s ON s.id = n.student_id 
+             WHERE s.class_id = ? ORDER BY n.created_at DESC"
+        )?;
+        let note_iter = stmt.query_map(params![class_id], |row| {
+            let created_at: String = row.get(3)?;
+            let updated_at: String = row.get(4)?;
+            Ok(Note {
+                id: row.get(0)?,
+                student_id: row.get(1)?,
+                body: row.get(2)?,
+                created_at: DateTime::parse_from_rfc3339(&created_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                updated_at: DateTime::parse_from_rfc3339(&updated_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            })
+        })?;
+        
+        let mut notes = Vec::new();
+        for note in note_iter {
+            notes.push(note?);
+        }
+        
+        Ok(notes)
+    }
+    
+    // ===== ASSIGNMENT OPERATIONS =====
+    
+    pub async fn create_assignment(&self, class_id: i64, name: &str, due_at: DateTime<Utc>, repo_pattern: &str) -> Result<Assignment> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "INSERT INTO assignments (class_id, name, due_at, repo_pattern, created_at) 
+             VALUES (?, ?, ?, ?, datetime('now')) 
+             RETURNING id"
+        )?;
+        
+        let id: i64 = stmt.query_row(params![class_id, name, due_at.to_rfc3339(), repo_pattern], |row| row.get(0))?;
+        
+        Ok(Assignment {
+            id,
+            class_id,
+            name: name.to_string(),
+            due_at,
+            repo_pattern: repo_pattern.to_string(),
+        })
+    }
+    
+    pub async fn get_assignments_for_class(&self, class_id: i64) -> Result<Vec<Assignment>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT id, class_id, name, due_at, repo_pattern 
+             FROM assignments WHERE class_id = ? ORDER BY due_at"
+        )?;
+        let assignment_iter = stmt.query_map(params![class_id], |row| {
+            let due_at: String = row.get(3)?;
+            Ok(Assignment {
+                id: row.get(0)?,
+                class_id: row.get(1)?,
+                name: row.get(2)?,
+                due_at: DateTime::parse_from_rfc3339(&due_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                repo_pattern: row.get(4)?,
+            })
+        })?;
+        
+        let mut assignments = Vec::new();
+        for assignment in assignment_iter {
+            assignments.push(assignment?);
+        }
+        
+        Ok(assignments)
+    }
+    
+    pub async fn delete_assignment(&self, id: i64) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let affected = conn.execute("DELETE FROM assignments WHERE id = ?", params![id])?;
+        Ok(affected > 0)
+    }
+    
+    pub async fn save_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT OR REPLACE INTO snapshots (assignment_id, student_id, sha, taken_at) VALUES (?, ?, ?, ?)",
+            params![snapshot.assignment_id, snapshot.student_id, snapshot.sha, snapshot.taken_at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    pub async fn get_snapshots_for_class(&self, class_id: i64) -> Result<Vec<Snapshot>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT s.assignment_id, s.student_id, s.sha, s.taken_at 
+             FROM snapshots s JOIN assignments a ON a.id = s.assignment_id 
+             WHERE a.class_id = ?"
+        )?;
+        let snapshot_iter = stmt.query_map(params![class_id], |row| {
+            let taken_at: String = row.get(3)?;
+            Ok(Snapshot {
+                assignment_id: row.get(0)?,
+                student_id: row.get(1)?,
+                sha: row.get(2)?,
+                taken_at: DateTime::parse_from_rfc3339(&taken_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            })
+        })?;
+        
+        let mut snapshots = Vec::new();
+        for snapshot in snapshot_iter {
+            snapshots.push(snapshot?);
+        }
+        
+        Ok(snapshots)
+    }
+    
+    // ===== CALENDAR OPERATIONS =====
+    
+    pub async fn add_calendar_entry(&self, class_id: i64, name: &str, start: NaiveDate, end: NaiveDate, kind: DayKind) -> Result<CalendarEntry> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT OR REPLACE INTO calendar_entries (class_id, name, start_date, end_date, kind) VALUES (?, ?, ?, ?, ?)",
+            params![class_id, name, start.to_string(), end.to_string(), kind.as_str()],
+        )?;
+        
+        Ok(CalendarEntry {
+            id: conn.last_insert_rowid(),
+            class_id,
+            name: name.to_string(),
+            start,
+            end,
+            kind,
+        })
+    }
+    
+    /// Add imported days off, skipping any already on the calendar. Returns how many were new
+    pub async fn import_calendar_events(&self, class_id: i64, events: &[IcsEvent]) -> Result<usize> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        let mut added = 0;
+        for event in events {
+            added += tx.execute(
+                "INSERT OR IGNORE INTO calendar_entries (class_id, name, start_date, end_date, kind) VALUES (?, ?, ?, ?, ?)",
+                params![class_id, event.name, event.start.to_string(), event.end.to_string(), event.kind().as_str()],
+            )?;
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+    
+    pub async fn delete_calendar_entry(&self, id: i64) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        let affected = conn.execute("DELETE FROM calendar_entries WHERE id = ?", params![id])?;
+        Ok(affected > 0)
+    }
+    
+    pub async fn get_calendar(&self, class_id: i64) -> Result<SchoolCalendar> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT id, class_id, name, start_date, end_date, kind FROM calendar_entries WHERE class_id = ?"
+        )?;
+        let entry_iter = stmt.query_map(params![class_id], |row| {
+            let start: String = row.get(3)?;
+            let end: String = row.get(4)?;
+            let kind: String = row.get(5)?;
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, start, end, kind))
+        })?;
+        
+        let mut entries = Vec::new();
+        for entry in entry_iter {
+            let (id, class_id, name, start, end, kind): (i64, i64, String, String, String, String) = entry?;
+            // Skip rows that no longer parse rather than failing the whole calendar
+            let (Ok(start), Ok(end), Some(kind)) = (start.parse(), end.parse(), DayKind::parse(&kind)) else {
+                continue;
+            };
+            entries.push(CalendarEntry { id, class_id, name, start, end, kind });
+        }
+        
+        Ok(SchoolCalendar::new(entries))
+    }
+    
+    // ===== METRIC OPERATIONS =====
+    
+    /// Metric definitions for a class, or the defaults if none were saved
+    pub async fn get_metric_settings(&self, class_id: i64) -> Result<MetricSettings> {
+        let conn = self.conn.lock().await;
+        let settings = conn.query_row(
+            "SELECT min_daily_commits, weekly_target_days, consistency_weeks FROM metric_settings WHERE class_id = ?",
+            params![class_id],
+            |row| Ok(MetricSettings {
+                min_daily_commits: row.get(0)?,
+                weekly_target_days: row.get(1)?,
+                consistency_weeks: row.get(2)?,
+            }),
+        );
+        
+        match settings {
+            Ok(settings) => Ok(settings),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(MetricSettings::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    pub async fn set_metric_settings(&self, class_id: i64, settings: &MetricSettings) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT OR REPLACE INTO metric_settings (class_id, min_daily_commits, weekly_target_days, consistency_weeks) VALUES (?, ?, ?, ?)",
+            params![class_id, settings.min_daily_commits, settings.weekly_target_days, settings.consistency_weeks],
+        )?;
+        Ok(())
+    }
+    
+    // ===== COMMIT CACHE OPERATIONS =====
+    
+    /// Replace a student's cached commit times with a fresh fetch
+    pub async fn replace_cached_commits(&self, student_id: i64, times: &[DateTime<Utc>]) -> Result<()> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM commit_cache WHERE student_id = ?", params![student_id])?;
+        {
+            let mut stmt = tx.prepare("INSERT INTO commit_cache (student_id, committed_at) VALUES (?, ?)")?;
+            for time in times {
+                stmt.execute(params![student_id, time.to_rfc3339()])?;
+            }
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO commit_cache_updates (student_id, updated_at) VALUES (?, ?)",
+            params![student_id, Utc::now().to_rfc3339()],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Cached commits for every student in a class that has been fetched at least once
+    pub async fn get_commit_cache(&self, class_id: i64) -> Result<HashMap<i64, CachedCommits>> {
+        let conn = self.conn.lock().await;
+        let mut cache = HashMap::new();
+        
+        let mut stmt = conn.prepare(
+            "SELECT u.student_id, u.updated_at FROM commit_cache_updates u 
+             JOIN students s ON s.id = u.student_id WHERE s.class_id = ?"
+        )?;
+        let updates = stmt.query_map(params![class_id], |row| {
+            let updated_at: String = row.get(1)?;
+            Ok((row.get::<_, i64>(0)?, updated_at))
+        })?;
+        for update in updates {
+            let (student_id, updated_at) = update?;
+            cache.insert(student_id, CachedCommits {
+                student_id,
+                times: Vec::new(),
+                updated_at: DateTime::parse_from_rfc3339(&updated_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            });
+        }
+        
+        let mut stmt = conn.prepare(
+            "SELECT c.student_id, c.committed_at FROM commit_cache c 
+             JOIN students s ON s.id = c.student_id WHERE s.class_id = ?"
+        )?;
+        let commits = stmt.query_map(params![class_id], |row| {
+            let committed_at: String = row.get(1)?;
+            Ok((row.get::<_, i64>(0)?, committed_at))
+        })?;
+        for commit in commits {
+            let (student_id, committed_at) = commit?;
+            if let (Some(entry), Ok(time)) = (cache.get_mut(&student_id), DateTime::parse_from_rfc3339(&committed_at)) {
+                entry.times.push(time.with_timezone(&Utc));
+            }
+        }
+        
+        Ok(cache)
+    }
+    
+    // ===== ALERT OPERATIONS =====
+    
+    /// Thresholds for a class, or the defaults if none were saved
+    pub async fn get_alert_thresholds(&self, class_id: i64) -> Result<AlertThresholds> {
+        let conn = self.conn.lock().await;
+        let thresholds = conn.query_row(
+            "SELECT inactive_school_days, decline_percent FROM alert_thresholds WHERE class_id = ?",
+            params![class_id],
+            |row| Ok(AlertThresholds {
+                inactive_school_days: row.get(0)?,
+                decline_percent: row.get(1)?,
+            }),
+        );
+        
+        match thresholds {
+            Ok(thresholds) => Ok(thresholds),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(AlertThresholds::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    pub async fn set_alert_thresholds(&self, class_id: i64, thresholds: &AlertThresholds) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT OR REPLACE INTO alert_thresholds (class_id, inactive_school_days, decline_percent) VALUES (?, ?, ?)",
+            params![class_id, thresholds.inactive_school_days, thresholds.decline_percent],
+        )?;
+        Ok(())
+    }
+    
+    // ===== SITE CHECK OPERATIONS =====
+    
+    pub async fn save_site_check(&self, student_id: i64, report: &SiteReport) -> Result<SiteCheck> {
+        let conn = self.conn.lock().await;
+        let checked_at = Utc::now();
+        conn.execute(
+            "INSERT OR REPLACE INTO site_checks (student_id, checked_at, status, findings) VALUES (?, ?, ?, ?)",
+            params![student_id, checked_at.to_rfc3339(), report.status().label(), serde_json::to_string(&report.findings)?],
+        )?;
+        
+        Ok(SiteCheck {
+            student_id,
+            checked_at,
+            report: report.clone(),
+        })
+    }
+    
+    pub async fn get_site_checks_for_class(&self, class_id: i64) -> Result<HashMap<i64, SiteCheck>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT sc.student_id, sc.checked_at, sc.findings 
+             FROM site_checks sc JOIN students s ON s.id = sc.student_id 
+             WHERE s.class_id = ?"
+        )?;
+        let rows = stmt.query_map(params![class_id], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?;
+        
+        let mut checks = HashMap::new();
+        for row in rows {
+            let (student_id, checked_at, findings) = row?;
+            let checked_at = DateTime::parse_from_rfc3339(&checked_at)
+                .map(|date| date.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now());
+            let report = SiteReport {
+                findings: serde_json::from_str(&findings).unwrap_or_default(),
+            };
+            checks.insert(student_id, SiteCheck { student_id, checked_at, report });
+        }
+        
+        Ok(checks)
+    }
+    
+    // ===== RUBRIC AND GRADE OPERATIONS =====
+    
+    /// Replace an assignment's rubric. Criteria are matched by name so existing scores survive
+    /// re-ordering or re-weighting; scores for removed criteria are deleted.
+    pub async fn set_rubric(&self, assignment_id: i64, criteria: &[(String, f64)]) -> Result<Vec<RubricCriterion>> {
+        {
+            let mut conn = self.conn.lock().await;
+            let tx = conn.transaction()?;
+            
+            let existing: HashMap<String, i64> = {
+                let mut stmt = tx.prepare("SELECT name, id FROM rubric_criteria WHERE assignment_id = ?")?;
+                let rows = stmt.query_map(params![assignment_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+                rows.collect::<rusqlite::Result<_>>()?
+            };
+            
+            for (name, id) in &existing {
+                if !criteria.iter().any(|(kept, _)| kept == name) {
+                    tx.execute("DELETE FROM scores WHERE criterion_id = ?", params![id])?;
+                    tx.execute("DELETE FROM rubric_criteria WHERE id = ?", params![id])?;
+                }
+            }
+            
+            for (position, (name, max_points)) in criteria.iter().enumerate() {
+                match existing.get(name) {
+                    Some(id) => tx.execute(
+                        "UPDATE rubric_criteria SET max_points = ?, position = ? WHERE id = ?",
+                        params![max_points, position as i64, id],
+                    )?,
+                    None => tx.execute(
+                        "INSERT INTO rubric_criteria (assignment_id, name, max_points, position) VALUES (?, ?, ?, ?)",
+                        params![assignment_id, name, max_points, position as i64],
+                    )?,
+                };
+            }
+            
+            tx.commit()?;
+        }
+        
+        self.get_rubric(assignment_id).await
+    }
+    
+    pub async fn get_rubric(&self, assignment_id: i64) -> Result<Vec<RubricCriterion>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT id, assignment_id, name, max_points 
+             FROM rubric_criteria WHERE assignment_id = ? ORDER BY position"
+        )?;
+        let criterion_iter = stmt.query_map(params![assignment_id], |row| {
+            Ok(RubricCriterion {
+                id: row.get(0)?,
+                assignment_id: row.get(1)?,
+                name: row.get(2)?,
+                max_points: row.get(3)?,
+            })
+        })?;
+        
+        let mut rubric = Vec::new();
+        for criterion in criterion_iter {
+            rubric.push(criterion?);
+        }
+        
+        Ok(rubric)
+    }
+    
+    pub async fn save_grade(&self, grade: &Grade) -> Result<()> {
+        let mut conn = self.conn.lock().await;
+        let tx = conn.transaction()?;
+        
+        tx.execute(
+            "INSERT OR REPLACE INTO grades (assignment_id, student_id, comment, graded_at) VALUES (?, ?, ?, ?)",
+            params![grade.assignment_id, grade.student_id, grade.comment, grade.graded_at.to_rfc3339()],
+        )?;
+        tx.execute(
+            "DELETE FROM scores WHERE assignment_id = ? AND student_id = ?",
+            params![grade.assignment_id, grade.student_id],
+        )?;
+        for (criterion_id, points) in &grade.scores {
+            tx.execute(
+                "INSERT INTO scores (assignment_id, student_id, criterion_id, points) VALUES (?, ?, ?, ?)",
+                params![grade.assignment_id, grade.student_id, criterion_id, points],
+            )?;
+        }
+        
+        tx.commit()?;
+        Ok(())
+    }
+    
+    /// Grades for an assignment keyed by student id
+    pub async fn get_grades_for_assignment(&self, assignment_id: i64) -> Result<HashMap<i64, Grade>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT student_id, comment, graded_at FROM grades WHERE assignment_id = ?"
+        )?;
+        let grade_iter = stmt.query_map(params![assignment_id], |row| {
+            let graded_at: String = row.get(2)?;
+            Ok(Grade {
+                assignment_id,
+                student_id: row.get(0)?,
+                scores: HashMap::new(),
+                comment: row.get(1)?,
+                graded_at: DateTime::parse_from_rfc3339(&graded_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            })
+        })?;
+        
+        let mut grades = HashMap::new();
+        for grade in grade_iter {
+            let grade = grade?;
+            grades.insert(grade.student_id, grade);
+        }
+        
+        let mut stmt = conn.prepare(
+            "SELECT student_id, criterion_id, points FROM scores WHERE assignment_id = ?"
+        )?;
+        let score_iter = stmt.query_map(params![assignment_id], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?, row.get::<_, f64>(2)?))
+        })?;
+        for score in score_iter {
+            let (student_id, criterion_id, points) = score?;
+            if let Some(grade) = grades.get_mut(&student_id) {
+                grade.scores.insert(criterion_id, points);
+            }
+        }
+        
+        Ok(grades)
+    }
+    
+    // ===== GRADING RUNNER OPERATIONS =====
+    
+    /// Get the grading command settings for a class, or the defaults if none are saved
+    pub async fn get_runner_config(&self, class_id: i64) -> Result<RunnerConfig> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare("SELECT command, timeout_secs, concurrency FROM runner_configs WHERE class_id = ?")?;
+        let mut rows = stmt.query_map(params![class_id], |row| {
+            Ok(RunnerConfig {
+                command: row.get(0)?,
+                timeout_secs: row.get::<_, i64>(1)? as u64,
+                concurrency: row.get::<_, i64>(2)? as usize,
+            })
+        })?;
+        
+        match rows.next() {
+            Some(config) => Ok(config?),
+            None => Ok(RunnerConfig::default()),
+        }
+    }
+    
+    pub async fn save_runner_config(&self, class_id: i64, config: &RunnerConfig) -> Result<()> {
+        let conn = self.conn.lock().await;
+        conn.execute(
+            "INSERT OR REPLACE INTO runner_configs (class_id, command, timeout_secs, concurrency) VALUES (?, ?, ?, ?)",
+            params![class_id, config.command, config.timeout_secs as i64, config.concurrency as i64],
+        )?;
+        Ok(())
+    }
+    
+    pub async fn save_test_results(&self, results: &[TestResult]) -> Result<()> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "INSERT INTO test_results (student_id, ran_at, status, exit_code, duration_ms, stdout, stderr) 
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )?;
+        
+        for result in results {
+            stmt.execute(params![
+                result.student_id,
+                result.ran_at.to_rfc3339(),
+                result.status.label(),
+                result.exit_code,
+                result.duration_ms as i64,
+                result.stdout,
+                result.stderr,
+            ])?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Most recent test result for each student in a class that has been tested
+    pub async fn get_latest_test_results(&self, class_id: i64) -> Result<Vec<TestResult>> {
+        let conn = self.conn.lock().await;
+        let mut stmt = conn.prepare(
+            "SELECT t.student_id, s.github_username, t.status, t.exit_code, t.duration_ms, t.stdout, t.stderr, t.ran_at 
+             FROM test_results t JOIN students s ON s.id = t.student_id 
+             WHERE s.class_id = ? AND t.id = (SELECT MAX(id) FROM test_results WHERE student_id = t.student_id) 
+             ORDER BY s.username"
+        )?;
+        let result_iter = stmt.query_map(params![class_id], |row| {
+            let ran_at: String = row.get(7)?;
+            Ok(TestResult {
+                student_id: row.get(0)?,
+                github_username: row.get(1)?,
+                status: RunStatus::from_label(&row.get::<_, String>(2)?),
+                exit_code: row.get(3)?,
+                duration_ms: row.get::<_, i64>(4)? as u64,
+                stdout: row.get(5)?,
+                stderr: row.get(6)?,
+                ran_at: DateTime::parse_from_rfc3339(&ran_at)
+                    .map(|date| date.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            })
+        })?;
+        
+        let mut results = Vec::new();
+        for result in result_iter {
+            results.push(result?);
+        }
+        
+        Ok(results)
+    }
 }
 
 
 
+/// Parse a `datetime('now')` value, which SQLite stores as UTC without an offset
+fn parse_timestamp(value: Option<String>) -> DateTime<Utc> {
+    value
+        .and_then(|value| NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S").ok())
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .unwrap_or_else(Utc::now)
+}
+
 fn get_database_path() -> Result<PathBuf> {
     let home = home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
     let scv_dir = home.join(".scv-rust"); // Different from Go version
@@ -222,8 +1176,47 @@ mod tests {
         let students = db.get_students_for_class(class.id).await?;
         assert_eq!(students.len(), 1);
         
-        // Test cleanup
+        // Renaming can't take another class's name
+        let other = db.create_class("Test Class Other").await?;
+        assert!(db.rename_class(class.id, "Test Class Other").await.is_err());
+        db.rename_class(class.id, "Test Class Renamed").await?;
+        assert_eq!(db.get_class_by_id(class.id).await?.map(|c| c.name), Some("Test Class Renamed".to_string()));
+        
+        // Duplicating copies the roster, and a taken name creates nothing
+        db.add_student(class.id, "seconduser").await?;
+        let (copy, copied) = db.duplicate_class(class.id, "Test Class Copy").await?;
+        assert_eq!(copied, 2);
+        assert_eq!(db.get_students_for_class(copy.id).await?.len(), 2);
+        let class_count = db.get_classes().await?.len();
+        assert!(db.duplicate_class(class.id, "Test Class Other").await.is_err());
+        assert_eq!(db.get_classes().await?.len(), class_count);
+        
+        // Copies and moves refuse a username the target class already has
+        assert!(db.copy_student(student.id, copy.id).await.is_err());
+        assert!(db.move_student(student.id, copy.id).await.is_err());
+        let copied_student = db.copy_student(student.id, other.id).await?;
+        assert_eq!((copied_student.class_id, copied_student.username.as_str()), (other.id, "testuser"));
+        let moved = db.add_student(class.id, "thirduser").await?;
+        db.move_student(moved.id, other.id).await?;
+        assert_eq!(db.get_students_for_class(other.id).await?.len(), 2);
+        assert_eq!(db.get_students_for_class(class.id).await?.len(), 2);
+        db.delete_class(other.id).await?;
+        db.delete_class(copy.id).await?;
+        
+        // Deleting a student takes their notes and tags with them
+        db.add_note(student.id, "Needs help with CSS").await?;
+        db.add_student_tag(student.id, "late").await?;
         db.delete_student(student.id).await?;
+        {
+            let conn = db.conn.lock().await;
+            let count = |table: &str| -> Result<i64> {
+                Ok(conn.query_row(&format!("SELECT COUNT(*) FROM {} WHERE student_id = ?", table), params![student.id], |row| row.get(0))?)
+            };
+            assert_eq!(count("notes")?, 0);
+            assert_eq!(count("student_tags")?, 0);
+        }
+        
+        // Test cleanup
         db.delete_class(class.id).await?;
         
         Ok(())
@@ -1,8 +1,10 @@
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc, Weekday, Duration, Datelike};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::utils::time;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubCommit {
     pub sha: String,
@@ -13,6 +15,8 @@ pub struct GitHubCommit {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitDetails {
     pub author: CommitAuthor,
+    #[serde(default)]
+    pub committer: Option<CommitAuthor>,
     pub message: String,
 }
 
@@ -34,12 +38,104 @@ pub struct GitHubUser {
 pub struct WeekActivity {
     pub student_username: String,
     pub student_github_username: String,
-    pub daily_commits: HashMap<Weekday, bool>, // true if committed on that day
+    pub daily_commits: HashMap<NaiveDate, u32>, // commits made on each school day of the range
     pub total_commits: usize,
     pub latest_commit: Option<DateTime<Utc>>,
     pub error: Option<String>,
 }
 
+/// Pages of 100 commits fetched at most when checking a repository's full history
+const MAX_COMMIT_PAGES: usize = 5;
+
+/// Longest custom range the week view will fetch, in days
+pub const MAX_RANGE_DAYS: i64 = 31;
+
+/// Inclusive span of school calendar days shown in the week view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub include_weekends: bool,
+}
+
+impl DateRange {
+    /// Monday to Sunday of the week containing `day`
+    pub fn week_of(day: NaiveDate, include_weekends: bool) -> Self {
+        let start = day - Duration::days(day.weekday().num_days_from_monday() as i64);
+        Self {
+            start,
+            end: start + Duration::days(6),
+            include_weekends,
+        }
+    }
+
+    pub fn this_week(include_weekends: bool) -> Self {
+        Self::week_of(time::today(), include_weekends)
+    }
+
+    /// The same span moved by whole weeks (negative for earlier)
+    pub fn shift_weeks(&self, weeks: i64) -> Self {
+        Self {
+            start: self.start + Duration::weeks(weeks),
+            end: self.end + Duration::weeks(weeks),
+            ..*self
+        }
+    }
+
+    /// Parse "YYYY-MM-DD..YYYY-MM-DD" (a space or "to" also separates); a single date covers just that day
+    pub fn parse(input: &str, include_weekends: bool) -> Result<Self> {
+        let parts: Vec<&str> = input
+            .split(|c: char| c.is_whitespace() || c == '.')
+            .filter(|part| !part.is_empty() && *part != "to")
+            .collect();
+        let parse_date = |value: &str| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .with_context(|| format!("Invalid date '{}', expected YYYY-MM-DD", value))
+        };
+        
+        let (start, end) = match parts.as_slice() {
+            [day] => (parse_date(day)?, parse_date(day)?),
+            [start, end] => (parse_date(start)?, parse_date(end)?),
+            _ => return Err(anyhow::anyhow!("Enter a range as YYYY-MM-DD..YYYY-MM-DD")),
+        };
+        if end < start {
+            return Err(anyhow::anyhow!("Range ends before it starts"));
+        }
+        if (end - start).num_days() >= MAX_RANGE_DAYS {
+            return Err(anyhow::anyhow!("Ranges are limited to {} days", MAX_RANGE_DAYS));
+        }
+        
+        Ok(Self { start, end, include_weekends })
+    }
+
+    /// Days shown as columns, oldest first
+    pub fn days(&self) -> Vec<NaiveDate> {
+        self.start
+            .iter_days()
+            .take_while(|day| *day <= self.end)
+            .filter(|day| self.include_weekends || !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+            .collect()
+    }
+
+    /// UTC instants from the start of the first school day to the end of the last
+    pub fn utc_bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let midnight = |day: NaiveDate| {
+            let naive = day.and_hms_opt(0, 0, 0).expect("midnight is a valid time");
+            time::from_school_time(naive).unwrap_or_else(|| Utc.from_utc_datetime(&naive))
+        };
+        (midnight(self.start), midnight(self.end + Duration::days(1)))
+    }
+
+    pub fn label(&self) -> String {
+        let days = self.days();
+        match (days.first(), days.last()) {
+            (Some(first), Some(last)) if first == last => first.format("%a %b %-d, %Y").to_string(),
+            (Some(first), Some(last)) => format!("{} – {}", first.format("%a %b %-d"), last.format("%a %b %-d, %Y")),
+            _ => format!("{} – {} (no weekdays)", self.start.format("%b %-d"), self.end.format("%b %-d, %Y")),
+        }
+    }
+}
+
 pub struct GitHubClient {
     client: reqwest::Client,
     token: Option<String>,
@@ -62,40 +158,25 @@ impl GitHubClient {
         }
     }
 
-    pub async fn get_week_activity(&self, github_username: &str) -> Result<WeekActivity> {
-        let weekdays = get_past_weekdays(5);
-        let mut daily_commits = HashMap::new();
+    /// Commits per school day of `range` on the student's GitHub Pages repository
+    pub async fn get_week_activity(&self, github_username: &str, range: &DateRange) -> Result<WeekActivity> {
+        let mut daily_commits: HashMap<NaiveDate, u32> = range.days().into_iter().map(|day| (day, 0)).collect();
         let mut total_commits = 0;
-        let mut latest_commit = None;
+        let mut latest_commit: Option<DateTime<Utc>> = None;
 
-        // Initialize all weekdays to false
-        for weekday in &weekdays {
-            daily_commits.insert(*weekday, false);
-        }
-
-        match self.get_commits_for_user(github_username, &weekdays).await {
+        match self.get_commits_for_user(github_username, range.utc_bounds()).await {
             Ok(commits) => {
-                // Filter commits to only include those in the target weekdays
-                let filtered_commits: Vec<_> = commits.into_iter()
-                    .filter(|commit| {
-                        let commit_weekday = commit.commit.author.date.weekday();
-                        weekdays.contains(&commit_weekday)
-                    })
-                    .collect();
-                
-                total_commits = filtered_commits.len();
-                
-                // Process filtered commits to determine daily activity
-                for commit in filtered_commits {
+                for commit in commits {
                     let commit_date = commit.commit.author.date;
-                    let weekday = commit_date.weekday();
-                    
-                    // Mark this weekday as having commits
-                    daily_commits.insert(weekday, true);
                     
-                    // Update latest commit
-                    if latest_commit.is_none() || commit_date > latest_commit.unwrap() {
-                        latest_commit = Some(commit_date);
+                    // Weekends are absent from the map unless the range includes them
+                    if let Some(count) = daily_commits.get_mut(&time::school_date(commit_date)) {
+                        *count += 1;
+                        total_commits += 1;
+                        
+                        if latest_commit.is_none_or(|latest| commit_date > latest) {
+                            latest_commit = Some(commit_date);
+                        }
                     }
                 }
 
@@ -122,35 +203,150 @@ impl GitHubClient {
         }
     }
 
-    async fn get_commits_for_user(&self, github_username: &str, weekdays: &[Weekday]) -> Result<Vec<GitHubCommit>> {
+    async fn get_commits_for_user(&self, github_username: &str, (since, until): (DateTime<Utc>, DateTime<Utc>)) -> Result<Vec<GitHubCommit>> {
         let repo_name = format!("{}.github.io", github_username);
         let url = format!("https://api.github.com/repos/{}/{}/commits", github_username, repo_name);
+        let mut commits = Vec::new();
         
-        // Calculate the date range for the past 5 weekdays
-        let start_date = get_earliest_weekday_date(weekdays);
-        let end_date = Utc::now();
+        // A month of commits can exceed one page; cap the pages like the full-history fetch
+        for page in 1..=MAX_COMMIT_PAGES {
+            let mut request = self.client.get(&url)
+                .query(&[
+                    ("since", since.to_rfc3339()),
+                    ("until", until.to_rfc3339()),
+                    ("per_page", "100".to_string()),
+                    ("page", page.to_string()),
+                ]);
+
+            // Add authorization header if token is available
+            if let Some(token) = &self.token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+
+            let response = request.send().await
+                .with_context(|| format!("Failed to fetch commits for {}", github_username))?;
+
+            if response.status() == 404 || response.status() == 409 {
+                // Repository not found or empty - this is expected for some students
+                break;
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(anyhow::anyhow!("GitHub API error {}: {}", status, error_text));
+            }
+            
+            let page_commits: Vec<GitHubCommit> = response.json().await
+                .with_context(|| "Failed to parse GitHub API response")?;
+            let count = page_commits.len();
+            commits.extend(page_commits);
+            
+            if count < 100 {
+                break;
+            }
+        }
+        
+        Ok(commits)
+    }
+
+    /// Commit times (committer date) on the default branch of any repository, or `None` if it doesn't exist
+    pub async fn get_commit_times(&self, owner: &str, repo: &str) -> Result<Option<Vec<DateTime<Utc>>>> {
+        // Student repos are small; cap the pages fetched to stay well within rate limits
+        let times = self.query_commit_times(owner, repo, &[], 100, MAX_COMMIT_PAGES).await?;
+        Ok(times.map(|(times, _)| times))
+    }
+
+    /// The commit times an assignment deadline depends on: every commit after `due_at` and the last one before it.
+    /// Unlike the capped full history, this never drops the commit that decides whether work was on time.
+    pub async fn get_submission_times(&self, owner: &str, repo: &str, due_at: DateTime<Utc>) -> Result<Option<Vec<DateTime<Utc>>>> {
+        let Some((mut times, complete)) = self
+            .query_commit_times(owner, repo, &[("since", due_at.to_rfc3339())], 100, MAX_COMMIT_PAGES)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if !complete {
+            return Err(anyhow::anyhow!(
+                "More than {} commits since the deadline; clone the repository to check it",
+                MAX_COMMIT_PAGES * 100
+            ));
+        }
+        
+        if let Some((before, _)) = self.query_commit_times(owner, repo, &[("until", due_at.to_rfc3339())], 1, 1).await? {
+            times.extend(before);
+        }
+        Ok(Some(times))
+    }
+
+    /// Page through commit times matching `filters`, newest first. `None` if the repository doesn't exist;
+    /// otherwise the times and whether the whole history was read within `max_pages`.
+    async fn query_commit_times(
+        &self,
+        owner: &str,
+        repo: &str,
+        filters: &[(&str, String)],
+        per_page: usize,
+        max_pages: usize,
+    ) -> Result<Option<(Vec<DateTime<Utc>>, bool)>> {
+        let url = format!("https://api.github.com/repos/{}/{}/commits", owner, repo);
+        let mut times = Vec::new();
+        
+        for page in 1..=max_pages {
+            let mut request = self.client.get(&url)
+                .query(filters)
+                .query(&[("per_page", per_page.to_string()), ("page", page.to_string())]);
+            
+            if let Some(token) = &self.token {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+            
+            let response = request.send().await
+                .with_context(|| format!("Failed to fetch commits for {}/{}", owner, repo))?;
+            
+            if response.status() == 404 || response.status() == 409 {
+                // 404: repository not found, 409: repository is empty
+                return Ok(if page == 1 { None } else { Some((times, true)) });
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(anyhow::anyhow!("GitHub API error {}: {}", status, error_text));
+            }
+            
+            let commits: Vec<GitHubCommit> = response.json().await
+                .with_context(|| "Failed to parse GitHub API response")?;
+            let count = commits.len();
+            times.extend(commits.into_iter().map(|commit| {
+                commit.commit.committer.map(|c| c.date).unwrap_or(commit.commit.author.date)
+            }));
+            
+            if count < per_page {
+                return Ok(Some((times, true)));
+            }
+        }
+        
+        Ok(Some((times, false)))
+    }
+
+    /// Most recent commits on the default branch of a repository, newest first; empty if it doesn't exist
+    pub async fn get_recent_commits(&self, owner: &str, repo: &str, limit: usize) -> Result<Vec<GitHubCommit>> {
+        let url = format!("https://api.github.com/repos/{}/{}/commits", owner, repo);
         
         let mut request = self.client.get(&url)
-            .query(&[
-                ("since", start_date.to_rfc3339()),
-                ("until", end_date.to_rfc3339()),
-                ("per_page", "100".to_string()),
-            ]);
+            .query(&[("per_page", limit.min(100).to_string())]);
 
-        // Add authorization header if token is available
         if let Some(token) = &self.token {
             request = request.header("Authorization", format!("Bearer {}", token));
         }
 
         let response = request.send().await
-            .with_context(|| format!("Failed to fetch commits for {}", github_username))?;
+            .with_context(|| format!("Failed to fetch commits for {}/{}", owner, repo))?;
 
         if response.status().is_success() {
             let commits: Vec<GitHubCommit> = response.json().await
                 .with_context(|| "Failed to parse GitHub API response")?;
             Ok(commits)
-        } else if response.status() == 404 {
-            // Repository not found - this is expected for some students
+        } else if response.status() == 404 || response.status() == 409 {
             Ok(Vec::new())
         } else {
             let status = response.status();
@@ -189,56 +385,29 @@ impl GitHubClient {
     }
 }
 
-// Helper function to get the past N weekdays (Monday-Friday)
-fn get_past_weekdays(count: usize) -> Vec<Weekday> {
-    let mut weekdays = Vec::new();
-    let mut current = Utc::now();
-    
-    while weekdays.len() < count {
-        let weekday = current.weekday();
-        if weekday != Weekday::Sat && weekday != Weekday::Sun {
-            weekdays.push(weekday);
-        }
-        current = current - Duration::days(1);
-    }
-    
-    weekdays.reverse(); // Return in chronological order
-    weekdays
+// Helper function to get the GitHub Pages URL for a student's site
+pub fn pages_url(github_username: &str) -> String {
+    format!("https://{}.github.io", github_username)
 }
 
-// Helper function to get the earliest date from weekdays
-fn get_earliest_weekday_date(weekdays: &[Weekday]) -> DateTime<Utc> {
-    let mut current = Utc::now();
-    let mut days_back = 0;
-    
-    // Go back up to 7 days to find the earliest weekday
-    while days_back < 7 {
-        let weekday = current.weekday();
-        if weekdays.contains(&weekday) {
-            break;
-        }
-        current = current - Duration::days(1);
-        days_back += 1;
-    }
-    
-    // Go back additional days to cover all weekdays
-    current - Duration::days(7)
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-// Helper function to format weekday for display
-pub fn format_weekday(weekday: Weekday) -> &'static str {
-    match weekday {
-        Weekday::Mon => "Mon",
-        Weekday::Tue => "Tue",
-        Weekday::Wed => "Wed",
-        Weekday::Thu => "Thu",
-        Weekday::Fri => "Fri",
-        Weekday::Sat => "Sat",
-        Weekday::Sun => "Sun",
+    #[test]
+    fn test_date_range_days_and_parsing() {
+        let thursday = NaiveDate::from_ymd_opt(2024, 3, 14).unwrap();
+        let week = DateRange::week_of(thursday, false);
+        assert_eq!(week.start, NaiveDate::from_ymd_opt(2024, 3, 11).unwrap());
+        assert_eq!(week.days().len(), 5);
+        assert_eq!(DateRange { include_weekends: true, ..week }.days().len(), 7);
+        assert_eq!(week.shift_weeks(-1).start, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+
+        let custom = DateRange::parse("2024-03-01..2024-03-10", true).unwrap();
+        assert_eq!(custom.days().len(), 10);
+        assert_eq!(DateRange::parse("2024-03-01 to 2024-03-04", false).unwrap().days().len(), 2);
+        assert!(DateRange::parse("2024-03-10..2024-03-01", false).is_err());
+        assert!(DateRange::parse("2024-01-01..2024-03-01", false).is_err());
+        assert!(DateRange::parse("last week", false).is_err());
     }
 }
-
-// Helper function to get current weekdays for display
-pub fn get_current_weekdays() -> Vec<Weekday> {
-    vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
-}
\ No newline at end of file
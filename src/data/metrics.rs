@@ -0,0 +1,155 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use std::collections::HashMap;
+
+use super::{calendar::SchoolCalendar, CachedCommits, MetricSettings};
+use crate::utils::time;
+
+/// Work-habit metrics for one student, computed from their stored commit history
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StudentMetrics {
+    /// Active school days in a row up to today; today only counts once it is active
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub avg_commits_per_active_day: f64,
+    /// Most school days in a row without activity since the first commit
+    pub longest_gap: u32,
+    /// Share of the recent weekly targets met, 0 to 100
+    pub consistency: u32,
+}
+
+/// Metrics over every commit in `times`, with holidays, breaks and weekends left neutral
+pub fn compute(
+    times: &[DateTime<Utc>],
+    calendar: &SchoolCalendar,
+    settings: &MetricSettings,
+    today: NaiveDate,
+) -> StudentMetrics {
+    let mut counts: HashMap<NaiveDate, u32> = HashMap::new();
+    for at in times {
+        *counts.entry(time::school_date(*at)).or_insert(0) += 1;
+    }
+    let min_commits = settings.min_daily_commits.max(1);
+    let is_active = |day: NaiveDate| counts.get(&day).copied().unwrap_or(0) >= min_commits;
+
+    let Some(first) = counts.keys().min().copied() else {
+        return StudentMetrics::default();
+    };
+
+    let mut metrics = StudentMetrics::default();
+    let mut gap = 0;
+    for day in first.iter_days().take_while(|day| *day <= today) {
+        if !calendar.is_school_day(day) {
+            continue;
+        }
+        if is_active(day) {
+            metrics.current_streak += 1;
+            metrics.longest_streak = metrics.longest_streak.max(metrics.current_streak);
+            gap = 0;
+        } else if day < today {
+            metrics.current_streak = 0;
+            gap += 1;
+            metrics.longest_gap = metrics.longest_gap.max(gap);
+        }
+    }
+
+    let (active_days, active_commits) = counts
+        .values()
+        .filter(|count| **count >= min_commits)
+        .fold((0, 0), |(days, commits), count| (days + 1, commits + count));
+    if active_days > 0 {
+        metrics.avg_commits_per_active_day = f64::from(active_commits) / f64::from(active_days);
+    }
+
+    metrics.consistency = consistency(calendar, settings, today, is_active);
+    metrics
+}
+
+/// Average share of each recent week's target met, counting school days before today, and today once it is active
+fn consistency(
+    calendar: &SchoolCalendar,
+    settings: &MetricSettings,
+    today: NaiveDate,
+    is_active: impl Fn(NaiveDate) -> bool,
+) -> u32 {
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let mut scores = Vec::new();
+    for week in 0..settings.consistency_weeks.max(1) as i64 {
+        let monday = this_monday - Duration::weeks(week);
+        let school_days: Vec<NaiveDate> = monday
+            .iter_days()
+            .take(7)
+            .filter(|day| (*day < today || *day == today && is_active(*day)) && calendar.is_school_day(*day))
+            .collect();
+        let target = settings.weekly_target_days.max(1).min(school_days.len() as u32);
+        if target == 0 {
+            continue;
+        }
+        let active = school_days.iter().filter(|day| is_active(**day)).count() as u32;
+        scores.push(f64::from(active.min(target)) / f64::from(target));
+    }
+
+    if scores.is_empty() {
+        return 100;
+    }
+    (scores.iter().sum::<f64>() / scores.len() as f64 * 100.0).round() as u32
+}
+
+/// Metrics for every student with cached commits
+pub fn for_class(
+    cache: &HashMap<i64, CachedCommits>,
+    calendar: &SchoolCalendar,
+    settings: &MetricSettings,
+    today: NaiveDate,
+) -> HashMap<i64, StudentMetrics> {
+    cache
+        .iter()
+        .map(|(student_id, cached)| (*student_id, compute(&cached.times, calendar, settings, today)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CalendarEntry, DayKind};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_streaks_gaps_and_consistency() {
+        let date = |value: &str| NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap();
+        let noon = |day: u32| Utc.with_ymd_and_hms(2024, 3, day, 12, 0, 0).unwrap();
+        // Friday; Monday the 11th is a holiday and must not break the streak
+        let today = date("2024-03-15");
+        let calendar = SchoolCalendar::new(vec![CalendarEntry {
+            id: 1,
+            class_id: 1,
+            name: "Teacher day".to_string(),
+            start: date("2024-03-11"),
+            end: date("2024-03-11"),
+            kind: DayKind::Holiday,
+        }]);
+        let settings = MetricSettings { min_daily_commits: 1, weekly_target_days: 3, consistency_weeks: 2 };
+
+        // Active Mon 4th, idle Tue 5th to Thu 7th, then every school day from Fri 8th to Thu 14th
+        let times = vec![noon(4), noon(4), noon(8), noon(12), noon(13), noon(14), noon(14)];
+        let metrics = compute(&times, &calendar, &settings, today);
+        assert_eq!(metrics.current_streak, 4);
+        assert_eq!(metrics.longest_streak, 4);
+        assert_eq!(metrics.longest_gap, 3);
+        assert!((metrics.avg_commits_per_active_day - 7.0 / 5.0).abs() < 1e-9);
+        // Last week 2 of 3 days, this week 3 of 3
+        assert_eq!(metrics.consistency, 83);
+
+        let strict = MetricSettings { min_daily_commits: 2, ..settings };
+        let metrics = compute(&times, &calendar, &strict, today);
+        assert_eq!((metrics.current_streak, metrics.longest_streak), (1, 1));
+        assert_eq!(metrics.longest_gap, 6);
+        assert_eq!(compute(&[], &calendar, &settings, today), StudentMetrics::default());
+
+        // Monday morning before any commits: the new week is not held against them yet
+        let monday = date("2024-03-18");
+        let metrics = compute(&times, &calendar, &settings, monday);
+        assert_eq!(metrics.consistency, 100);
+        let metrics = compute(&[times.clone(), vec![noon(18)]].concat(), &calendar, &settings, monday);
+        assert_eq!(metrics.consistency, 100);
+    }
+}
@@ -1,6 +1,10 @@
+pub mod alerts;
+pub mod assignments;
+pub mod calendar;
 pub mod database;
 pub mod models;
 pub mod github;
+pub mod metrics;
 
 pub use database::Database;
-pub use models::{Class, Student}; // Removed unused StudentWithClass
\ No newline at end of file
+pub use models::{AlertThresholds, Assignment, CachedCommits, CalendarEntry, Class, DayKind, Grade, MetricSettings, Note, RubricCriterion, Snapshot, Student}; // Removed unused StudentWithClass
\ No newline at end of file
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Class {
@@ -17,6 +18,169 @@ pub struct Student {
     pub created_at: DateTime<Utc>,
 }
 
+/// A deadline for work pushed to a student repository
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Assignment {
+    pub id: i64,
+    pub class_id: i64,
+    pub name: String,
+    pub due_at: DateTime<Utc>,
+    /// Repository name with `{github}` standing in for the student's username, e.g. `{github}.github.io`
+    pub repo_pattern: String,
+}
+
+/// The commit a student's clone was pinned to for an assignment deadline
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Snapshot {
+    pub assignment_id: i64,
+    pub student_id: i64,
+    pub sha: String,
+    pub taken_at: DateTime<Utc>,
+}
+
+/// A private teacher note about a student
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Note {
+    pub id: i64,
+    pub student_id: i64,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Note {
+    /// Case-insensitive substring match on the note text
+    pub fn matches(&self, query: &str) -> bool {
+        self.body.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// What a calendar entry does to the days it covers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DayKind {
+    Holiday,
+    Break,
+    /// An instructional day that would otherwise be off, such as a make-up Saturday
+    SchoolDay,
+}
+
+impl DayKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DayKind::Holiday => "holiday",
+            DayKind::Break => "break",
+            DayKind::SchoolDay => "school_day",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "holiday" => Some(DayKind::Holiday),
+            "break" => Some(DayKind::Break),
+            "school_day" => Some(DayKind::SchoolDay),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DayKind::Holiday => "Holiday",
+            DayKind::Break => "Break",
+            DayKind::SchoolDay => "School day",
+        }
+    }
+}
+
+/// A day or inclusive range of days on a class's school calendar
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CalendarEntry {
+    pub id: i64,
+    pub class_id: i64,
+    pub name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub kind: DayKind,
+}
+
+impl CalendarEntry {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// Commit times last fetched for a student, kept so heatmaps render without network access
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedCommits {
+    pub student_id: i64,
+    pub times: Vec<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Per-class limits used to flag students on the class dashboard
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AlertThresholds {
+    /// School days without a commit before a student is flagged as inactive
+    pub inactive_school_days: u32,
+    /// Drop in commits, week over week, that counts as a declining trend
+    pub decline_percent: u32,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            inactive_school_days: 3,
+            decline_percent: 50,
+        }
+    }
+}
+
+/// Per-class definitions for the work-habit metrics
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetricSettings {
+    /// Commits needed on a day for it to count as active
+    pub min_daily_commits: u32,
+    /// Active school days expected per week for full consistency
+    pub weekly_target_days: u32,
+    /// Recent weeks averaged into the consistency score
+    pub consistency_weeks: u32,
+}
+
+impl Default for MetricSettings {
+    fn default() -> Self {
+        Self {
+            min_daily_commits: 1,
+            weekly_target_days: 3,
+            consistency_weeks: 4,
+        }
+    }
+}
+
+/// One line of an assignment's rubric
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RubricCriterion {
+    pub id: i64,
+    pub assignment_id: i64,
+    pub name: String,
+    pub max_points: f64,
+}
+
+/// A student's rubric scores and feedback for one assignment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Grade {
+    pub assignment_id: i64,
+    pub student_id: i64,
+    /// Points per rubric criterion id; criteria that haven't been scored are absent
+    pub scores: HashMap<i64, f64>,
+    pub comment: String,
+    pub graded_at: DateTime<Utc>,
+}
+
+impl Grade {
+    pub fn total(&self) -> f64 {
+        self.scores.values().sum()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StudentWithClass {
     pub student: Student,
@@ -43,4 +207,35 @@ impl Student {
             created_at: Utc::now(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl Assignment {
+    pub const DEFAULT_REPO_PATTERN: &'static str = "{github}.github.io";
+
+    /// The repository a student submits this assignment to
+    pub fn repo_name(&self, github_username: &str) -> String {
+        self.repo_pattern.replace("{github}", github_username)
+    }
+
+    /// Whether the assignment lives in the student's Pages repo, which is the one we clone locally
+    pub fn uses_pages_repo(&self, github_username: &str) -> bool {
+        self.repo_name(github_username) == format!("{}.github.io", github_username)
+    }
+
+    /// Local tag marking a student's last commit before the deadline, e.g. `deadline/12-project-1`.
+    /// The id keeps assignments whose names slug the same apart.
+    pub fn snapshot_tag(&self) -> String {
+        let slug = self
+            .name
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            format!("deadline/{}", self.id)
+        } else {
+            format!("deadline/{}-{}", self.id, slug)
+        }
+    }
+}
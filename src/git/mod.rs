@@ -1,8 +1,19 @@
+pub mod tree;
+
 use anyhow::{Context, Result};
-use std::path::PathBuf;
-use std::process::Command;
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
 use tokio::process::Command as TokioCommand;
 
+/// A commit read from a local clone
+#[derive(Debug, Clone)]
+pub struct LocalCommit {
+    pub sha: String,
+    pub author: String,
+    pub date: DateTime<Utc>,
+    pub summary: String,
+}
+
 pub struct GitManager {
     pub repos_dir: PathBuf,
 }
@@ -99,47 +110,129 @@ impl GitManager {
         Ok(())
     }
 
-    pub fn open_terminal(&self, github_username: &str, class_name: &str) -> Result<()> {
-        let repo_path = self.repos_dir.join(class_name).join(github_username);
-        
+    /// Most recent commits on the checked-out branch of a local clone, newest first
+    pub async fn recent_commits(&self, github_username: &str, class_name: &str, limit: usize) -> Result<Vec<LocalCommit>> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+
         if !repo_path.exists() {
             return Err(anyhow::anyhow!("Repository not found at {}", repo_path.display()));
         }
 
-        #[cfg(target_os = "macos")]
-        {
-            Command::new("open")
-                .arg("-a")
-                .arg("Terminal")
-                .arg(&repo_path)
-                .spawn()
-                .context("Failed to open terminal")?;
-        }
+        let output = TokioCommand::new("git")
+            .args(["log", &format!("-n{}", limit), "--format=%H%x1f%an%x1f%aI%x1f%s"])
+            .current_dir(&repo_path)
+            .output()
+            .await
+            .context("Failed to execute git log command")?;
 
-        #[cfg(target_os = "linux")]
-        {
-            Command::new("gnome-terminal")
-                .arg("--working-directory")
-                .arg(&repo_path)
-                .spawn()
-                .context("Failed to open terminal")?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("Git log failed: {}", error));
         }
 
-        #[cfg(target_os = "windows")]
-        {
-            Command::new("cmd")
-                .arg("/C")
-                .arg("start")
-                .arg("cmd")
-                .arg("/K")
-                .arg(format!("cd /d {}", repo_path.display()))
-                .spawn()
-                .context("Failed to open terminal")?;
+        let commits = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\x1f');
+                let sha = fields.next()?.to_string();
+                let author = fields.next()?.to_string();
+                let date = DateTime::parse_from_rfc3339(fields.next()?).ok()?.with_timezone(&Utc);
+                let summary = fields.next().unwrap_or_default().to_string();
+                Some(LocalCommit { sha, author, date, summary })
+            })
+            .collect();
+
+        Ok(commits)
+    }
+
+    /// Update a clone's remote-tracking branches without touching what is checked out
+    pub async fn fetch(&self, github_username: &str, class_name: &str) -> Result<()> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+        run_git(&repo_path, &["fetch", "origin"]).await?;
+        Ok(())
+    }
+
+    /// Commit times (committer date) of every commit on the default branch of a local clone, as of the last fetch
+    pub async fn commit_times(&self, github_username: &str, class_name: &str) -> Result<Vec<DateTime<Utc>>> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+
+        let mut args = vec!["log".to_string(), "--format=%cI".to_string()];
+        args.extend(default_branch(&repo_path).await);
+        let output = TokioCommand::new("git")
+            .args(&args)
+            .current_dir(&repo_path)
+            .output()
+            .await
+            .context("Failed to execute git log command")?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            if error.contains("does not have any commits") {
+                return Ok(Vec::new());
+            }
+            return Err(anyhow::anyhow!("Git log failed: {}", error));
         }
 
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| DateTime::parse_from_rfc3339(line.trim()).ok())
+            .map(|date| date.with_timezone(&Utc))
+            .collect())
+    }
+
+    /// Last commit on the default branch committed at or before `deadline`, even while a snapshot is checked out
+    pub async fn commit_before(&self, github_username: &str, class_name: &str, deadline: DateTime<Utc>) -> Result<Option<String>> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+        let branch = default_branch(&repo_path).await.unwrap_or_else(|| "HEAD".to_string());
+        let sha = run_git(&repo_path, &["rev-list", "-1", &format!("--before={}", deadline.to_rfc3339()), &branch]).await?;
+        Ok(Some(sha).filter(|sha| !sha.is_empty()))
+    }
+
+    /// Tag `sha` locally, unless the tag already marks it, and check it out as a detached HEAD
+    pub async fn checkout_snapshot(&self, github_username: &str, class_name: &str, tag: &str, sha: &str) -> Result<()> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+        // An existing tag is only reused when it marks the same commit, never moved
+        let tag_ref = format!("refs/tags/{}^{{commit}}", tag);
+        match run_git(&repo_path, &["rev-parse", "--quiet", "--verify", &tag_ref]).await {
+            Ok(tagged) if tagged == sha => {}
+            Ok(tagged) => {
+                return Err(anyhow::anyhow!(
+                    "Tag {} already marks commit {}, not {}; delete the tag to take a new snapshot",
+                    tag,
+                    &tagged[..tagged.len().min(7)],
+                    &sha[..sha.len().min(7)]
+                ))
+            }
+            Err(_) => {
+                run_git(&repo_path, &["tag", tag, sha]).await?;
+            }
+        }
+        run_git(&repo_path, &["checkout", "--detach", tag]).await?;
         Ok(())
     }
 
+    /// Leave any snapshot and go back to the tip of the default branch, fast-forwarded to what
+    /// was last pushed. A local branch with commits that aren't on origin is left alone.
+    pub async fn checkout_latest(&self, github_username: &str, class_name: &str) -> Result<String> {
+        let repo_path = self.get_repo_path(github_username, class_name);
+        run_git(&repo_path, &["fetch", "origin"]).await?;
+        let branch = match default_branch(&repo_path).await {
+            Some(remote_head) => remote_head.trim_start_matches("origin/").to_string(),
+            None => "main".to_string(),
+        };
+        let upstream = format!("origin/{}", branch);
+        run_git(&repo_path, &["checkout", &branch]).await?;
+        if run_git(&repo_path, &["merge-base", "--is-ancestor", "HEAD", &upstream]).await.is_err() {
+            return Err(anyhow::anyhow!(
+                "{} has commits that aren't on {}, so it can't be fast-forwarded; resolve it in the clone",
+                branch,
+                upstream
+            ));
+        }
+        run_git(&repo_path, &["merge", "--ff-only", &upstream]).await?;
+        Ok(branch)
+    }
+
     pub fn get_repo_path(&self, github_username: &str, class_name: &str) -> PathBuf {
         self.repos_dir.join(class_name).join(github_username)
     }
@@ -148,6 +241,41 @@ impl GitManager {
         self.get_repo_path(github_username, class_name).exists()
     }
 
+    /// Rename a class's folder of clones; returns false if nothing has been cloned for it yet
+    pub fn rename_class_dir(&self, from_class: &str, to_class: &str) -> Result<bool> {
+        let from = self.repos_dir.join(from_class);
+        if !from.exists() {
+            return Ok(false);
+        }
+
+        let to = self.repos_dir.join(to_class);
+        if to.exists() {
+            return Err(anyhow::anyhow!("A folder already exists at {}", to.display()));
+        }
+
+        std::fs::rename(&from, &to)
+            .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
+        Ok(true)
+    }
+
+    /// Move a student's clone into another class's folder; returns false if there was no clone to move
+    pub fn move_repo(&self, github_username: &str, from_class: &str, to_class: &str) -> Result<bool> {
+        let from = self.get_repo_path(github_username, from_class);
+        if !from.exists() {
+            return Ok(false);
+        }
+
+        let to = self.get_repo_path(github_username, to_class);
+        if to.exists() {
+            return Err(anyhow::anyhow!("Repository already exists at {}", to.display()));
+        }
+
+        std::fs::create_dir_all(self.repos_dir.join(to_class))?;
+        std::fs::rename(&from, &to)
+            .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
+        Ok(true)
+    }
+
     pub async fn clone_all_repos(&self, students: &[crate::data::Student], class_name: &str) -> Result<Vec<(String, Result<()>)>> {
         let mut results = Vec::new();
         
@@ -158,4 +286,121 @@ impl GitManager {
         
         Ok(results)
     }
-}
\ No newline at end of file
+}
+
+/// The remote default branch, e.g. `origin/main`, which stays put while a snapshot is checked out.
+/// `None` for clones without a remote HEAD, where the checked-out branch is all there is.
+async fn default_branch(repo_path: &Path) -> Option<String> {
+    run_git(repo_path, &["rev-parse", "--abbrev-ref", "origin/HEAD"]).await.ok()
+}
+
+/// Run a git command in `repo_path`, returning trimmed stdout
+async fn run_git(repo_path: &Path, args: &[&str]) -> Result<String> {
+    if !repo_path.exists() {
+        return Err(anyhow::anyhow!("Repository not found at {}", repo_path.display()));
+    }
+
+    let output = TokioCommand::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()
+        .await
+        .with_context(|| format!("Failed to execute git {}", args[0]))?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!("Git {} failed: {}", args[0], error.trim()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_folders_follow_moves_and_renames() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let git_manager = GitManager::new(dir.path().to_path_buf());
+        std::fs::create_dir_all(git_manager.get_repo_path("alice", "Period 1"))?;
+        std::fs::create_dir_all(git_manager.get_repo_path("bob", "Period 2"))?;
+
+        assert!(git_manager.move_repo("alice", "Period 1", "Period 2")?);
+        assert!(git_manager.repo_exists("alice", "Period 2"));
+        assert!(!git_manager.repo_exists("alice", "Period 1"));
+        // Nothing cloned, nothing to move
+        assert!(!git_manager.move_repo("carol", "Period 1", "Period 2")?);
+
+        assert!(git_manager.rename_class_dir("Period 2", "Fall Period 2")?);
+        assert!(git_manager.repo_exists("bob", "Fall Period 2"));
+        assert!(!git_manager.rename_class_dir("Period 3", "Fall Period 3")?);
+
+        // Existing folders are never overwritten
+        std::fs::create_dir_all(git_manager.get_repo_path("alice", "Period 1"))?;
+        assert!(git_manager.move_repo("alice", "Period 1", "Fall Period 2").is_err());
+        assert!(git_manager.rename_class_dir("Period 1", "Fall Period 2").is_err());
+
+        Ok(())
+    }
+
+    fn git(repo_path: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=Teacher", "-c", "user.email=teacher@example.com"])
+            .args(args)
+            .current_dir(repo_path)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[tokio::test]
+    async fn test_history_follows_default_branch_after_snapshot() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let origin = dir.path().join("origin.git");
+        let work = dir.path().join("work");
+        git(dir.path(), &["init", "--bare", "origin.git"]);
+        git(dir.path(), &["clone", "origin.git", "work"]);
+        git(&work, &["commit", "--allow-empty", "-m", "before", "--date=2024-03-01T12:00:00Z"]);
+        git(&work, &["push", "origin", "HEAD"]);
+
+        let git_manager = GitManager::new(dir.path().join("repos"));
+        let repo_path = git_manager.get_repo_path("alice", "Period 1");
+        std::fs::create_dir_all(repo_path.parent().unwrap())?;
+        git(dir.path(), &["clone", origin.to_str().unwrap(), repo_path.to_str().unwrap()]);
+
+        let deadline = Utc::now();
+        let sha = git_manager.commit_before("alice", "Period 1", deadline).await?.unwrap();
+        git_manager.checkout_snapshot("alice", "Period 1", "deadline", &sha).await?;
+
+        // Work pushed after the snapshot is still seen, though the clone sits on a detached HEAD
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        git(&work, &["commit", "--allow-empty", "-m", "after"]);
+        git(&work, &["push", "origin", "HEAD"]);
+        git_manager.fetch("alice", "Period 1").await?;
+
+        assert_eq!(git_manager.commit_times("alice", "Period 1").await?.len(), 2);
+        let latest = git_manager.commit_before("alice", "Period 1", Utc::now()).await?.unwrap();
+        assert_ne!(latest, sha);
+        assert_eq!(git_manager.commit_before("alice", "Period 1", deadline).await?, Some(sha.clone()));
+
+        // A snapshot tag is reused for the same commit but never moved to another
+        git_manager.checkout_snapshot("alice", "Period 1", "deadline", &sha).await?;
+        assert!(git_manager.checkout_snapshot("alice", "Period 1", "deadline", &latest).await.is_err());
+
+        // Latest means what was pushed, not the local branch as it was cloned
+        git(&work, &["commit", "--allow-empty", "-m", "later still"]);
+        git(&work, &["push", "origin", "HEAD"]);
+        git_manager.checkout_latest("alice", "Period 1").await?;
+        let head = run_git(&repo_path, &["rev-parse", "HEAD"]).await?;
+        assert_eq!(head, run_git(&work, &["rev-parse", "HEAD"]).await?);
+
+        // A local commit that was never pushed is not thrown away
+        git(&repo_path, &["commit", "--allow-empty", "-m", "local only"]);
+        git(&work, &["commit", "--allow-empty", "-m", "pushed"]);
+        git(&work, &["push", "origin", "HEAD"]);
+        assert!(git_manager.checkout_latest("alice", "Period 1").await.is_err());
+
+        Ok(())
+    }
+}
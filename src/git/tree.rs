@@ -0,0 +1,166 @@
+use anyhow::{Context, Result};
+use ignore::WalkBuilder;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Files larger than this are not loaded into the viewer
+const MAX_VIEWABLE_SIZE: u64 = 1024 * 1024;
+
+/// A single file or directory inside a repository
+#[derive(Debug, Clone)]
+pub struct TreeEntry {
+    pub name: String,
+    pub relative_path: PathBuf,
+    pub depth: usize,
+    pub is_dir: bool,
+}
+
+/// Collapsible file tree of a cloned repository that respects `.gitignore`
+pub struct FileTree {
+    root: PathBuf,
+    entries: Vec<TreeEntry>,
+    expanded: HashSet<PathBuf>,
+}
+
+impl FileTree {
+    /// Walk the repository at `root`, skipping `.git` and anything ignored by git
+    pub fn load(root: &Path) -> Result<Self> {
+        if !root.exists() {
+            return Err(anyhow::anyhow!("Repository not found at {}", root.display()));
+        }
+
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .git_ignore(true)
+            .git_exclude(true)
+            .git_global(false)
+            .parents(false)
+            .require_git(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .sort_by_file_path(|a, b| {
+                // Directories first, then alphabetical (case-insensitive)
+                b.is_dir().cmp(&a.is_dir()).then_with(|| {
+                    a.to_string_lossy().to_lowercase().cmp(&b.to_string_lossy().to_lowercase())
+                })
+            })
+            .build();
+
+        let mut entries = Vec::new();
+        for entry in walker {
+            let entry = entry.context("Failed to read repository tree")?;
+            if entry.depth() == 0 {
+                continue;
+            }
+
+            let relative_path = entry.path().strip_prefix(root)?.to_path_buf();
+            entries.push(TreeEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                relative_path,
+                depth: entry.depth() - 1,
+                is_dir: entry.file_type().map(|t| t.is_dir()).unwrap_or(false),
+            });
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            entries,
+            expanded: HashSet::new(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn is_expanded(&self, entry: &TreeEntry) -> bool {
+        self.expanded.contains(&entry.relative_path)
+    }
+
+    /// Entries that are currently visible, i.e. not hidden inside a collapsed directory
+    pub fn visible_entries(&self) -> Vec<&TreeEntry> {
+        let mut visible = Vec::new();
+        let mut hidden_below: Option<usize> = None;
+
+        for entry in &self.entries {
+            if let Some(depth) = hidden_below {
+                if entry.depth > depth {
+                    continue;
+                }
+                hidden_below = None;
+            }
+
+            visible.push(entry);
+
+            if entry.is_dir && !self.expanded.contains(&entry.relative_path) {
+                hidden_below = Some(entry.depth);
+            }
+        }
+
+        visible
+    }
+
+    pub fn toggle(&mut self, relative_path: &Path) {
+        if !self.expanded.remove(relative_path) {
+            self.expanded.insert(relative_path.to_path_buf());
+        }
+    }
+
+    pub fn expand(&mut self, relative_path: &Path) {
+        self.expanded.insert(relative_path.to_path_buf());
+    }
+
+    pub fn collapse(&mut self, relative_path: &Path) {
+        self.expanded.remove(relative_path);
+    }
+
+    /// Read a file from the tree for display, refusing binary and oversized files
+    pub fn read_file(&self, relative_path: &Path) -> Result<String> {
+        let path = self.root.join(relative_path);
+        let metadata = std::fs::metadata(&path)
+            .with_context(|| format!("Failed to read {}", relative_path.display()))?;
+
+        if metadata.len() > MAX_VIEWABLE_SIZE {
+            return Err(anyhow::anyhow!(
+                "{} is too large to display ({} KB)",
+                relative_path.display(),
+                metadata.len() / 1024
+            ));
+        }
+
+        let bytes = std::fs::read(&path)
+            .with_context(|| format!("Failed to read {}", relative_path.display()))?;
+
+        if bytes.iter().take(8000).any(|b| *b == 0) {
+            return Err(anyhow::anyhow!("{} is a binary file", relative_path.display()));
+        }
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_respects_gitignore() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join(".gitignore"), "node_modules/\n*.log\n")?;
+        std::fs::write(dir.path().join("index.html"), "<html></html>")?;
+        std::fs::write(dir.path().join("debug.log"), "noise")?;
+        std::fs::create_dir_all(dir.path().join("node_modules/pkg"))?;
+        std::fs::write(dir.path().join("node_modules/pkg/index.js"), "")?;
+        std::fs::create_dir_all(dir.path().join("css"))?;
+        std::fs::write(dir.path().join("css/style.css"), "body {}")?;
+
+        let mut tree = FileTree::load(dir.path())?;
+        let names: Vec<_> = tree.visible_entries().iter().map(|e| e.name.clone()).collect();
+        assert_eq!(names, vec!["css", ".gitignore", "index.html"]);
+
+        tree.expand(Path::new("css"));
+        let names: Vec<_> = tree.visible_entries().iter().map(|e| e.name.clone()).collect();
+        assert_eq!(names, vec!["css", "style.css", ".gitignore", "index.html"]);
+
+        Ok(())
+    }
+}
@@ -5,6 +5,9 @@ use tracing_subscriber;
 mod app;
 mod data;
 mod git;
+mod preview;
+mod runner;
+mod site_check;
 mod ui;
 mod utils;
 
@@ -0,0 +1,420 @@
+use anyhow::{Context, Result};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::process::Command as TokioCommand;
+use tokio::task::JoinHandle;
+
+/// Endpoint polled by the injected live reload script
+const RELOAD_ENDPOINT: &str = "/__scv/reload";
+const MAX_REQUEST_HEAD: usize = 16 * 1024;
+
+const RELOAD_SCRIPT: &str = "<script>(function(){var last=null;setInterval(function(){\
+fetch('/__scv/reload',{cache:'no-store'}).then(function(r){return r.text()}).then(function(v){\
+if(last!==null&&v!==last){location.reload()}last=v}).catch(function(){})},1000)})();</script>";
+
+/// Where the preview server reads site files from
+#[derive(Debug, Clone)]
+pub enum SiteSource {
+    /// The files currently checked out in the clone
+    WorkingTree(PathBuf),
+    /// The files as they were at a commit, read straight from git without touching the checkout
+    Commit { repo: PathBuf, sha: String },
+}
+
+impl SiteSource {
+    pub fn label(&self) -> String {
+        match self {
+            SiteSource::WorkingTree(_) => "working tree".to_string(),
+            SiteSource::Commit { sha, .. } => format!("commit {}", short_sha(sha)),
+        }
+    }
+
+    async fn lookup(&self, relative_path: &str) -> Result<Lookup> {
+        match self {
+            SiteSource::WorkingTree(root) => {
+                let path = root.join(relative_path);
+                match tokio::fs::metadata(&path).await {
+                    Ok(metadata) if metadata.is_dir() => Ok(Lookup::Dir),
+                    Ok(_) => Ok(Lookup::File(tokio::fs::read(&path).await?)),
+                    Err(_) => Ok(Lookup::NotFound),
+                }
+            }
+            SiteSource::Commit { repo, sha } => {
+                let object = format!("{}:{}", sha, relative_path.trim_end_matches('/'));
+                let kind = git_output(repo, &["cat-file", "-t", &object]).await?;
+                match kind.as_deref().map(|k| String::from_utf8_lossy(k).trim().to_string()).as_deref() {
+                    Some("tree") => Ok(Lookup::Dir),
+                    Some("blob") => {
+                        let bytes = git_output(repo, &["cat-file", "blob", &object]).await?;
+                        Ok(bytes.map(Lookup::File).unwrap_or(Lookup::NotFound))
+                    }
+                    _ => Ok(Lookup::NotFound),
+                }
+            }
+        }
+    }
+
+    /// A value that changes whenever the served files change
+    async fn generation(&self) -> String {
+        match self {
+            SiteSource::WorkingTree(root) => {
+                let root = root.clone();
+                tokio::task::spawn_blocking(move || latest_modification(&root).to_string())
+                    .await
+                    .unwrap_or_default()
+            }
+            SiteSource::Commit { sha, .. } => sha.clone(),
+        }
+    }
+}
+
+enum Lookup {
+    File(Vec<u8>),
+    Dir,
+    NotFound,
+}
+
+/// A running static file server for previewing a student's site
+pub struct PreviewServer {
+    address: SocketAddr,
+    source: SiteSource,
+    handle: JoinHandle<()>,
+}
+
+impl PreviewServer {
+    /// Start serving on `127.0.0.1:<port>`, falling back to any free port if it is taken
+    pub async fn start(source: SiteSource, port: u16, live_reload: bool) -> Result<Self> {
+        let listener = match TcpListener::bind(("127.0.0.1", port)).await {
+            Ok(listener) => listener,
+            Err(_) => TcpListener::bind(("127.0.0.1", 0))
+                .await
+                .context("Failed to start preview server")?,
+        };
+        let address = listener.local_addr()?;
+
+        let shared = Arc::new(source.clone());
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let source = Arc::clone(&shared);
+                tokio::spawn(async move {
+                    let _ = handle_connection(stream, &source, live_reload).await;
+                });
+            }
+        });
+
+        Ok(Self {
+            address,
+            source,
+            handle,
+        })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.address)
+    }
+
+    pub fn source(&self) -> &SiteSource {
+        &self.source
+    }
+
+    /// Stop the server and wait until its port has been released
+    pub async fn stop(mut self) {
+        self.handle.abort();
+        let _ = (&mut self.handle).await;
+    }
+}
+
+impl Drop for PreviewServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, source: &SiteSource, live_reload: bool) -> Result<()> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        if buffer.len() > MAX_REQUEST_HEAD {
+            return write_response(&mut stream, 431, "text/plain; charset=utf-8", b"Request header too large", true).await;
+        }
+        let read = tokio::time::timeout(Duration::from_secs(10), stream.read(&mut chunk)).await??;
+        if read == 0 {
+            return Ok(());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    let head = String::from_utf8_lossy(&buffer);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or("/").to_string();
+
+    let include_body = match method.as_str() {
+        "GET" => true,
+        "HEAD" => false,
+        _ => return write_response(&mut stream, 405, "text/plain; charset=utf-8", b"Method not allowed", true).await,
+    };
+
+    let path = percent_decode(target.split(['?', '#']).next().unwrap_or("/"));
+
+    if path == RELOAD_ENDPOINT {
+        let generation = source.generation().await;
+        return write_response(&mut stream, 200, "text/plain; charset=utf-8", generation.as_bytes(), include_body).await;
+    }
+
+    let Some(relative) = sanitize_path(&path) else {
+        return write_response(&mut stream, 403, "text/plain; charset=utf-8", b"Forbidden", include_body).await;
+    };
+
+    let (status, file_path, bytes) = match resolve(source, &relative, path.ends_with('/')).await? {
+        Resolved::File(file_path, bytes) => (200, file_path, bytes),
+        Resolved::Redirect => {
+            let location = format!("{}/", path);
+            let response = format!(
+                "HTTP/1.1 301 Moved Permanently\r\nLocation: {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                location
+            );
+            stream.write_all(response.as_bytes()).await?;
+            return Ok(());
+        }
+        Resolved::NotFound => match source.lookup("404.html").await? {
+            // GitHub Pages serves a custom 404.html when the site has one
+            Lookup::File(bytes) => (404, "404.html".to_string(), bytes),
+            _ => (404, "404.txt".to_string(), b"404 Not Found".to_vec()),
+        },
+    };
+
+    let content_type = mime_type(Path::new(&file_path));
+    let body = if live_reload && content_type.starts_with("text/html") {
+        inject_reload_script(bytes)
+    } else {
+        bytes
+    };
+    write_response(&mut stream, status, content_type, &body, include_body).await
+}
+
+enum Resolved {
+    File(String, Vec<u8>),
+    Redirect,
+    NotFound,
+}
+
+/// Resolve a request path the way GitHub Pages does: directories serve their
+/// `index.html` and extensionless paths fall back to `<path>.html`
+async fn resolve(source: &SiteSource, relative: &str, trailing_slash: bool) -> Result<Resolved> {
+    match source.lookup(relative).await? {
+        Lookup::File(bytes) => return Ok(Resolved::File(relative.to_string(), bytes)),
+        Lookup::Dir if !trailing_slash && !relative.is_empty() => return Ok(Resolved::Redirect),
+        Lookup::Dir => {
+            let index = if relative.is_empty() {
+                "index.html".to_string()
+            } else {
+                format!("{}/index.html", relative)
+            };
+            if let Lookup::File(bytes) = source.lookup(&index).await? {
+                return Ok(Resolved::File(index, bytes));
+            }
+        }
+        Lookup::NotFound => {
+            let html = format!("{}.html", relative);
+            if !relative.is_empty() {
+                if let Lookup::File(bytes) = source.lookup(&html).await? {
+                    return Ok(Resolved::File(html, bytes));
+                }
+            }
+        }
+    }
+    Ok(Resolved::NotFound)
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+    include_body: bool,
+) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        content_type,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    if include_body {
+        stream.write_all(body).await?;
+    }
+    stream.flush().await?;
+    Ok(())
+}
+
+/// Turn a URL path into a repository-relative path, rejecting anything that escapes the root
+fn sanitize_path(path: &str) -> Option<String> {
+    let mut segments = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => return None,
+            s if s.contains('\\') || s.contains('\0') || s == ".git" => return None,
+            s => segments.push(s),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+pub fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = (char::from(bytes[i + 1]).to_digit(16), char::from(bytes[i + 2]).to_digit(16));
+            if let (Some(high), Some(low)) = hex {
+                decoded.push((high * 16 + low) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Insert the live reload script before `</body>`, or at the end of the page if there is none
+fn inject_reload_script(mut bytes: Vec<u8>) -> Vec<u8> {
+    let index = bytes
+        .windows(7)
+        .rposition(|window| window.eq_ignore_ascii_case(b"</body>"))
+        .unwrap_or(bytes.len());
+    bytes.splice(index..index, RELOAD_SCRIPT.bytes());
+    bytes
+}
+
+/// Content type for a file, based on its extension
+pub fn mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" | "map" => "application/json",
+        "txt" | "md" => "text/plain; charset=utf-8",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Most recent modification time (in ms) of any file in the tree, ignoring `.git`
+fn latest_modification(root: &Path) -> u128 {
+    let mut latest = 0;
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            if entry.file_name() == ".git" {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else { continue };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            }
+            if let Ok(modified) = metadata.modified() {
+                let millis = modified.duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+                latest = latest.max(millis);
+            }
+        }
+    }
+    latest
+}
+
+/// Run git in `repo`, returning stdout on success and `None` if git reported an error
+async fn git_output(repo: &Path, args: &[&str]) -> Result<Option<Vec<u8>>> {
+    let output = TokioCommand::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .await
+        .context("Failed to execute git command")?;
+
+    Ok(output.status.success().then_some(output.stdout))
+}
+
+pub fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn get(server: &PreviewServer, path: &str) -> Result<String> {
+        let mut stream = TcpStream::connect(server.address).await?;
+        let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path);
+        stream.write_all(request.as_bytes()).await?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+        Ok(response)
+    }
+
+    #[tokio::test]
+    async fn test_preview_server_resolves_pages_paths() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("index.html"), "<html><body>home</body></html>")?;
+        std::fs::write(dir.path().join("about.html"), "about page")?;
+        std::fs::create_dir_all(dir.path().join("blog"))?;
+        std::fs::write(dir.path().join("blog/index.html"), "blog")?;
+        std::fs::write(dir.path().join("style.css"), "body {}")?;
+
+        let server = PreviewServer::start(SiteSource::WorkingTree(dir.path().to_path_buf()), 0, true).await?;
+
+        let response = get(&server, "/").await?;
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("Content-Type: text/html; charset=utf-8"));
+        assert!(response.contains("__scv/reload"));
+
+        assert!(get(&server, "/style.css").await?.contains("Content-Type: text/css"));
+        assert!(get(&server, "/about").await?.contains("about page"));
+        assert!(get(&server, "/blog").await?.starts_with("HTTP/1.1 301"));
+        assert!(get(&server, "/blog/").await?.contains("blog"));
+        assert!(get(&server, "/missing.png").await?.starts_with("HTTP/1.1 404"));
+        assert!(get(&server, "/../secret").await?.starts_with("HTTP/1.1 403"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,370 @@
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command as TokioCommand;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+
+use crate::data::Student;
+
+/// Only the tail of each output stream is kept, which is where test summaries end up
+const MAX_CAPTURED_OUTPUT: usize = 64 * 1024;
+
+/// How long to wait for a timed-out command's pipes to close once its processes are killed
+const OUTPUT_GRACE: Duration = Duration::from_secs(1);
+
+/// How a class's grading command is run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunnerConfig {
+    pub command: String,
+    pub timeout_secs: u64,
+    pub concurrency: usize,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            command: "npm test".to_string(),
+            timeout_secs: 120,
+            concurrency: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunStatus {
+    Passed,
+    Failed,
+    TimedOut,
+    Error,
+    NotCloned,
+}
+
+impl RunStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunStatus::Passed => "passed",
+            RunStatus::Failed => "failed",
+            RunStatus::TimedOut => "timed out",
+            RunStatus::Error => "error",
+            RunStatus::NotCloned => "not cloned",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Self {
+        match label {
+            "passed" => RunStatus::Passed,
+            "failed" => RunStatus::Failed,
+            "timed out" => RunStatus::TimedOut,
+            "not cloned" => RunStatus::NotCloned,
+            _ => RunStatus::Error,
+        }
+    }
+}
+
+/// Outcome of running the grading command in one student's repo
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    pub student_id: i64,
+    pub github_username: String,
+    pub status: RunStatus,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+    pub stdout: String,
+    pub stderr: String,
+    pub ran_at: DateTime<Utc>,
+}
+
+impl TestResult {
+    /// Last non-empty line of stdout (or stderr if there is none), usually the test runner's summary
+    pub fn summary(&self) -> &str {
+        last_line(&self.stdout).or_else(|| last_line(&self.stderr)).unwrap_or("")
+    }
+}
+
+fn last_line(text: &str) -> Option<&str> {
+    text.lines().rev().map(str::trim).find(|line| !line.is_empty())
+}
+
+/// Run the grading command in every repo, at most `config.concurrency` at a time.
+///
+/// `on_progress` is called with (finished, total) as each repo finishes. Results are returned in
+/// the same order as `repos`. Dropping the returned future stops every command still running.
+pub async fn run_class(
+    config: &RunnerConfig,
+    repos: Vec<(Student, PathBuf)>,
+    on_progress: impl Fn(usize, usize) + Send + Sync + 'static,
+) -> Vec<TestResult> {
+    let semaphore = Arc::new(Semaphore::new(config.concurrency.max(1)));
+    let timeout = Duration::from_secs(config.timeout_secs.max(1));
+    let total = repos.len();
+    let finished = Arc::new(AtomicUsize::new(0));
+    let on_progress = Arc::new(on_progress);
+
+    let tasks: Vec<_> = repos
+        .into_iter()
+        .map(|(student, repo_path)| {
+            let semaphore = Arc::clone(&semaphore);
+            let finished = Arc::clone(&finished);
+            let on_progress = Arc::clone(&on_progress);
+            let command = config.command.clone();
+            let identity = (student.id, student.github_username.clone());
+            let task = tokio::spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let result = run_for_student(&command, &student, &repo_path, timeout).await;
+                on_progress(finished.fetch_add(1, Ordering::SeqCst) + 1, total);
+                result
+            });
+            (identity, AbortOnDrop(task))
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(tasks.len());
+    for ((student_id, github_username), mut task) in tasks {
+        let result = match (&mut task.0).await {
+            Ok(result) => result,
+            Err(e) => TestResult {
+                student_id,
+                github_username,
+                status: RunStatus::Error,
+                exit_code: None,
+                duration_ms: 0,
+                stdout: String::new(),
+                stderr: if e.is_panic() {
+                    "The test run for this repository crashed".to_string()
+                } else {
+                    "The test run for this repository was cancelled".to_string()
+                },
+                ran_at: Utc::now(),
+            },
+        };
+        results.push(result);
+    }
+    results
+}
+
+/// Aborts a spawned task when dropped, so cancelling a run also cancels the repos it started
+struct AbortOnDrop<T>(JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+async fn run_for_student(command: &str, student: &Student, repo_path: &Path, timeout: Duration) -> TestResult {
+    let started = Instant::now();
+    let ran_at = Utc::now();
+
+    let (status, exit_code, stdout, stderr) = if repo_path.exists() {
+        run_in_repo(command, repo_path, timeout).await
+    } else {
+        (RunStatus::NotCloned, None, String::new(), format!("Repository not found at {}", repo_path.display()))
+    };
+
+    TestResult {
+        student_id: student.id,
+        github_username: student.github_username.clone(),
+        status,
+        exit_code,
+        duration_ms: started.elapsed().as_millis() as u64,
+        stdout,
+        stderr,
+        ran_at,
+    }
+}
+
+/// Run `command` through the platform shell in `repo_path`, killing it if it exceeds `timeout`
+pub async fn run_in_repo(command: &str, repo_path: &Path, timeout: Duration) -> (RunStatus, Option<i32>, String, String) {
+    let mut shell = if cfg!(target_os = "windows") {
+        let mut shell = TokioCommand::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = TokioCommand::new("sh");
+        shell.arg("-c");
+        shell
+    };
+
+    // A group of its own lets a timeout kill whatever the shell started, such as a dev server or test workers
+    #[cfg(unix)]
+    shell.process_group(0);
+
+    let child = shell
+        .arg(command)
+        .current_dir(repo_path)
+        // Keeps test runners like jest out of interactive watch mode
+        .env("CI", "true")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => return (RunStatus::Error, None, String::new(), format!("Failed to start '{}': {}", command, e)),
+    };
+    let group = ProcessGroup(child.id());
+    let mut stdout = OutputTail::collect(child.stdout.take());
+    let mut stderr = OutputTail::collect(child.stderr.take());
+
+    let finished = tokio::time::timeout(timeout, async {
+        let status = child.wait().await;
+        stdout.finish().await;
+        stderr.finish().await;
+        status
+    })
+    .await;
+
+    match finished {
+        Ok(Ok(status)) => {
+            group.disarm();
+            let run_status = if status.success() {
+                RunStatus::Passed
+            } else {
+                RunStatus::Failed
+            };
+            (run_status, status.code(), stdout.text(), stderr.text())
+        }
+        Ok(Err(e)) => (RunStatus::Error, None, stdout.text(), format!("Failed to run '{}': {}", command, e)),
+        Err(_) => {
+            drop(group);
+            let _ = child.kill().await;
+            // Whatever was printed before the hang is what explains it, so keep it
+            let _ = tokio::time::timeout(OUTPUT_GRACE, async {
+                stdout.finish().await;
+                stderr.finish().await;
+            })
+            .await;
+            let mut stderr = stderr.text();
+            if !stderr.is_empty() && !stderr.ends_with('\n') {
+                stderr.push('\n');
+            }
+            stderr.push_str(&format!("Timed out after {} seconds", timeout.as_secs()));
+            (RunStatus::TimedOut, None, stdout.text(), stderr)
+        }
+    }
+}
+
+/// The command's process group, killed when dropped unless the command finished on its own.
+///
+/// That covers both a timeout and a cancelled run, which drops the command part way through.
+struct ProcessGroup(Option<u32>);
+
+impl ProcessGroup {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        if let Some(pid) = self.0 {
+            kill_process_group(pid);
+        }
+    }
+}
+
+/// The tail of one output stream, read in the background so it survives a timeout
+struct OutputTail {
+    buffer: Arc<Mutex<Vec<u8>>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl OutputTail {
+    fn collect<R: AsyncRead + Unpin + Send + 'static>(stream: Option<R>) -> Self {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&buffer);
+        let reader = tokio::spawn(async move {
+            let Some(mut stream) = stream else {
+                return;
+            };
+            let mut chunk = [0u8; 8192];
+            while let Ok(read) = stream.read(&mut chunk).await {
+                if read == 0 {
+                    break;
+                }
+                let mut buffer = sink.lock().unwrap();
+                buffer.extend_from_slice(&chunk[..read]);
+                if buffer.len() > 2 * MAX_CAPTURED_OUTPUT {
+                    let excess = buffer.len() - MAX_CAPTURED_OUTPUT;
+                    buffer.drain(..excess);
+                }
+            }
+        });
+        Self { buffer, reader: Some(reader) }
+    }
+
+    /// Wait until the stream closes
+    async fn finish(&mut self) {
+        if let Some(reader) = &mut self.reader {
+            let _ = reader.await;
+            self.reader = None;
+        }
+    }
+
+    fn text(&self) -> String {
+        capture(&self.buffer.lock().unwrap())
+    }
+}
+
+impl Drop for OutputTail {
+    fn drop(&mut self) {
+        if let Some(reader) = &self.reader {
+            reader.abort();
+        }
+    }
+}
+
+#[cfg(unix)]
+fn kill_process_group(pgid: u32) {
+    // SAFETY: kill has no memory safety requirements; a group that already exited just returns ESRCH
+    unsafe {
+        libc::kill(-(pgid as libc::pid_t), libc::SIGKILL);
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(_pgid: u32) {}
+
+fn capture(bytes: &[u8]) -> String {
+    let start = bytes.len().saturating_sub(MAX_CAPTURED_OUTPUT);
+    String::from_utf8_lossy(&bytes[start..]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_in_repo_captures_status_and_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let timeout = Duration::from_secs(5);
+
+        let (status, code, stdout, _) = run_in_repo("echo ok", dir.path(), timeout).await;
+        assert_eq!((status, code, stdout.trim()), (RunStatus::Passed, Some(0), "ok"));
+
+        let (status, code, _, stderr) = run_in_repo("echo broken >&2; exit 3", dir.path(), timeout).await;
+        assert_eq!((status, code, stderr.trim()), (RunStatus::Failed, Some(3), "broken"));
+
+        // Output printed before the hang is kept
+        let (status, _, stdout, stderr) = run_in_repo("echo started; sleep 5", dir.path(), Duration::from_millis(300)).await;
+        assert_eq!((status, stdout.trim()), (RunStatus::TimedOut, "started"));
+        assert!(stderr.contains("Timed out"), "{}", stderr);
+
+        // Processes the shell started in the background go down with it
+        let (status, ..) = run_in_repo("sleep 30 & echo $! > pid; wait", dir.path(), Duration::from_millis(300)).await;
+        assert_eq!(status, RunStatus::TimedOut);
+        let pid: libc::pid_t = std::fs::read_to_string(dir.path().join("pid")).unwrap().trim().parse().unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        // Reaping is up to init, so a killed process may linger briefly as a zombie
+        let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).unwrap_or_default();
+        assert!(stat.is_empty() || stat.contains(") Z "), "{}", stat);
+    }
+}
@@ -0,0 +1,179 @@
+/// Elements whose contents are raw text rather than markup
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// A start or end tag found in an HTML document
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tag {
+    Start {
+        name: String,
+        attributes: Vec<(String, Option<String>)>,
+        self_closing: bool,
+        line: usize,
+    },
+    End {
+        name: String,
+        line: usize,
+    },
+}
+
+impl Tag {
+    pub fn name(&self) -> &str {
+        match self {
+            Tag::Start { name, .. } | Tag::End { name, .. } => name,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        match self {
+            Tag::Start { line, .. } | Tag::End { line, .. } => *line,
+        }
+    }
+
+    /// Value of an attribute on a start tag; `Some("")` for attributes without a value
+    pub fn attribute(&self, attribute: &str) -> Option<&str> {
+        match self {
+            Tag::Start { attributes, .. } => attributes
+                .iter()
+                .find(|(name, _)| name == attribute)
+                .map(|(_, value)| value.as_deref().unwrap_or("")),
+            Tag::End { .. } => None,
+        }
+    }
+}
+
+/// Extract the tags from an HTML document, skipping text, comments, doctypes and raw text content.
+///
+/// This is a forgiving scanner for checking student sites, not a spec-compliant parser.
+pub fn tokenize(source: &str) -> Vec<Tag> {
+    let bytes = source.as_bytes();
+    // ASCII lowercasing keeps byte offsets identical to `source`
+    let lower = source.to_ascii_lowercase();
+    let mut tags = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'<' {
+            if bytes[i] == b'\n' {
+                line += 1;
+            }
+            i += 1;
+            continue;
+        }
+
+        // Comments, doctypes and processing instructions
+        if lower[i..].starts_with("<!--") {
+            let end = lower[i + 4..].find("-->").map(|e| i + 4 + e + 3).unwrap_or(bytes.len());
+            line += count_newlines(&bytes[i..end]);
+            i = end;
+            continue;
+        }
+        if matches!(bytes.get(i + 1), Some(b'!') | Some(b'?')) {
+            let end = lower[i..].find('>').map(|e| i + e + 1).unwrap_or(bytes.len());
+            line += count_newlines(&bytes[i..end]);
+            i = end;
+            continue;
+        }
+
+        let closing = bytes.get(i + 1) == Some(&b'/');
+        let name_start = i + 1 + closing as usize;
+        if !bytes.get(name_start).is_some_and(|b| b.is_ascii_alphabetic()) {
+            // A stray '<' in text
+            i += 1;
+            continue;
+        }
+
+        let name_end = scan(bytes, name_start, |b| b.is_ascii_alphanumeric() || b == b'-' || b == b':');
+        let name = lower[name_start..name_end].to_string();
+        let tag_line = line;
+
+        let mut attributes = Vec::new();
+        let mut self_closing = false;
+        let mut j = name_end;
+        loop {
+            let next = scan(bytes, j, |b| b.is_ascii_whitespace());
+            line += count_newlines(&bytes[j..next]);
+            j = next;
+
+            match bytes.get(j) {
+                None => break,
+                Some(b'>') => {
+                    j += 1;
+                    break;
+                }
+                Some(b'/') if bytes.get(j + 1) == Some(&b'>') => {
+                    self_closing = true;
+                    j += 2;
+                    break;
+                }
+                Some(b'/') => j += 1,
+                Some(_) => {
+                    let attr_end = scan(bytes, j, |b| {
+                        !b.is_ascii_whitespace() && b != b'=' && b != b'>' && b != b'/'
+                    });
+                    if attr_end == j {
+                        // Stray '=' or similar, skip it
+                        j += 1;
+                        continue;
+                    }
+                    let attr_name = lower[j..attr_end].to_string();
+                    j = scan(bytes, attr_end, |b| b == b' ' || b == b'\t');
+
+                    let mut value = None;
+                    if bytes.get(j) == Some(&b'=') {
+                        j = scan(bytes, j + 1, |b| b == b' ' || b == b'\t');
+                        match bytes.get(j) {
+                            Some(&quote) if quote == b'"' || quote == b'\'' => {
+                                let end = scan(bytes, j + 1, |b| b != quote);
+                                line += count_newlines(&bytes[j..end]);
+                                value = Some(source[j + 1..end].to_string());
+                                j = (end + 1).min(bytes.len());
+                            }
+                            _ => {
+                                let end = scan(bytes, j, |b| !b.is_ascii_whitespace() && b != b'>');
+                                value = Some(source[j..end].to_string());
+                                j = end;
+                            }
+                        }
+                    }
+                    attributes.push((attr_name, value));
+                }
+            }
+        }
+
+        if closing {
+            tags.push(Tag::End { name, line: tag_line });
+        } else {
+            let raw_text = !self_closing && RAW_TEXT_ELEMENTS.contains(&name.as_str());
+            let closing_tag = format!("</{}", name);
+            tags.push(Tag::Start {
+                name,
+                attributes,
+                self_closing,
+                line: tag_line,
+            });
+
+            if raw_text {
+                let end = lower[j..].find(&closing_tag).map(|e| j + e).unwrap_or(bytes.len());
+                line += count_newlines(&bytes[j..end]);
+                j = end;
+            }
+        }
+
+        i = j;
+    }
+
+    tags
+}
+
+fn scan(bytes: &[u8], start: usize, predicate: impl Fn(u8) -> bool) -> usize {
+    let mut i = start;
+    while i < bytes.len() && predicate(bytes[i]) {
+        i += 1;
+    }
+    i
+}
+
+fn count_newlines(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|b| **b == b'\n').count()
+}
@@ -0,0 +1,487 @@
+pub mod html;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::preview::percent_decode;
+use html::Tag;
+
+/// Assets larger than this are reported as oversized
+pub const MAX_ASSET_SIZE: u64 = 1024 * 1024;
+
+/// Cap on nesting findings per file so one broken layout doesn't drown out everything else
+const MAX_NESTING_FINDINGS: usize = 5;
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose end tag may be left out
+const OPTIONAL_END_ELEMENTS: &[&str] = &[
+    "html", "head", "body", "p", "li", "dt", "dd", "tr", "td", "th", "thead", "tbody", "tfoot",
+    "option", "optgroup", "colgroup", "caption", "rb", "rt", "rp",
+];
+
+/// Block elements that implicitly close an open `<p>`
+const CLOSES_PARAGRAPH: &[&str] = &[
+    "address", "article", "aside", "blockquote", "details", "div", "dl", "fieldset", "figcaption",
+    "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "main", "nav",
+    "ol", "p", "pre", "section", "table", "ul",
+];
+
+/// How serious a finding is; a report's status is its most serious finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Pass => "pass",
+            Severity::Warn => "warn",
+            Severity::Fail => "fail",
+        }
+    }
+}
+
+/// A single problem found in a site
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Finding {
+    pub severity: Severity,
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Finding {
+    fn new(severity: Severity, file: &str, line: usize, message: String) -> Self {
+        Self {
+            severity,
+            file: Some(file.to_string()),
+            line: Some(line),
+            message,
+        }
+    }
+
+    /// `file:line` for display, if the finding has a location
+    pub fn location(&self) -> Option<String> {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => Some(format!("{}:{}", file, line)),
+            (Some(file), None) => Some(file.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Results of checking one site
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SiteReport {
+    pub findings: Vec<Finding>,
+}
+
+impl SiteReport {
+    pub fn status(&self) -> Severity {
+        self.findings.iter().map(|f| f.severity).max().unwrap_or(Severity::Pass)
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|f| f.severity == severity).count()
+    }
+
+    /// One-line summary such as "2 failures, 1 warning"
+    pub fn summary(&self) -> String {
+        let failures = self.count(Severity::Fail);
+        let warnings = self.count(Severity::Warn);
+        match (failures, warnings) {
+            (0, 0) => "all checks passed".to_string(),
+            (0, w) => format!("{} warning{}", w, plural(w)),
+            (f, 0) => format!("{} failure{}", f, plural(f)),
+            (f, w) => format!("{} failure{}, {} warning{}", f, plural(f), w, plural(w)),
+        }
+    }
+}
+
+/// A stored site check for a student
+#[derive(Debug, Clone)]
+pub struct SiteCheck {
+    pub student_id: i64,
+    pub checked_at: DateTime<Utc>,
+    pub report: SiteReport,
+}
+
+/// Committable files of a site, keyed by `/`-separated path relative to the repo root
+struct SiteFiles {
+    files: HashMap<String, u64>,
+    /// Lowercased path -> actual path, for spotting case mismatches
+    lowercase: HashMap<String, String>,
+}
+
+enum Resolution {
+    Found,
+    WrongCase(String),
+    Missing,
+}
+
+impl SiteFiles {
+    fn load(root: &Path) -> Result<Self> {
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .git_ignore(true)
+            .git_exclude(true)
+            .git_global(false)
+            .parents(false)
+            .require_git(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .build();
+
+        let mut files = HashMap::new();
+        let mut lowercase = HashMap::new();
+
+        for entry in walker {
+            let entry = entry.context("Failed to read repository files")?;
+            if entry.depth() == 0 {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(root)?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                continue;
+            }
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            lowercase.insert(relative.to_lowercase(), relative.clone());
+            files.insert(relative, size);
+        }
+
+        Ok(Self { files, lowercase })
+    }
+
+    /// Resolve a site path the way GitHub Pages does, including directory indexes and
+    /// extensionless `.html` pages
+    fn resolve(&self, path: &str, trailing_slash: bool) -> Resolution {
+        let mut candidates = Vec::new();
+        if path.is_empty() || trailing_slash {
+            candidates.push(join(path, "index.html"));
+        } else {
+            candidates.push(path.to_string());
+            candidates.push(join(path, "index.html"));
+            candidates.push(format!("{}.html", path));
+        }
+
+        if candidates.iter().any(|c| self.files.contains_key(c)) {
+            return Resolution::Found;
+        }
+
+        candidates
+            .iter()
+            .find_map(|c| self.lowercase.get(&c.to_lowercase()))
+            .map(|actual| Resolution::WrongCase(actual.clone()))
+            .unwrap_or(Resolution::Missing)
+    }
+}
+
+/// Check a cloned site for problems that break or degrade it on GitHub Pages
+pub fn check_site(root: &Path) -> Result<SiteReport> {
+    if !root.exists() {
+        return Err(anyhow::anyhow!("Repository not found at {}", root.display()));
+    }
+
+    let site = SiteFiles::load(root)?;
+    let mut findings = Vec::new();
+
+    check_index(&site, &mut findings);
+
+    let mut paths: Vec<&String> = site.files.keys().collect();
+    paths.sort();
+
+    for path in paths {
+        let size = site.files[path];
+        if size > MAX_ASSET_SIZE {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                file: Some(path.clone()),
+                line: None,
+                message: format!("File is {:.1} MB; keep assets under 1 MB so the site loads quickly", size as f64 / (1024.0 * 1024.0)),
+            });
+        }
+
+        let extension = path.rsplit('.').next().unwrap_or_default().to_lowercase();
+        if extension != "html" && extension != "htm" && extension != "css" {
+            continue;
+        }
+        // Large files are almost certainly generated and not worth scanning
+        if size > MAX_ASSET_SIZE {
+            continue;
+        }
+
+        let Ok(bytes) = std::fs::read(root.join(path)) else { continue };
+        let source = String::from_utf8_lossy(&bytes);
+
+        if extension == "css" {
+            check_css(&site, path, &source, &mut findings);
+        } else {
+            let tags = html::tokenize(&source);
+            check_references(&site, path, &tags, &mut findings);
+            check_nesting(path, &tags, &mut findings);
+        }
+    }
+
+    Ok(SiteReport { findings })
+}
+
+fn check_index(site: &SiteFiles, findings: &mut Vec<Finding>) {
+    if site.files.contains_key("index.html") {
+        return;
+    }
+
+    let message = match site.lowercase.get("index.html") {
+        Some(actual) => format!("Found '{}' but GitHub Pages only serves a lowercase 'index.html'", actual),
+        // Jekyll builds these into the home page
+        None if site.files.contains_key("index.md") || site.files.contains_key("README.md") => return,
+        None => "No index.html at the root of the repository; the site has no home page".to_string(),
+    };
+
+    findings.push(Finding {
+        severity: Severity::Fail,
+        file: None,
+        line: None,
+        message,
+    });
+}
+
+fn check_references(site: &SiteFiles, file: &str, tags: &[Tag], findings: &mut Vec<Finding>) {
+    for tag in tags {
+        if !matches!(tag, Tag::Start { .. }) {
+            continue;
+        }
+
+        if tag.name() == "img" && tag.attribute("alt").is_none() {
+            findings.push(Finding::new(
+                Severity::Warn,
+                file,
+                tag.line(),
+                "<img> is missing an alt attribute".to_string(),
+            ));
+        }
+
+        let attribute = match tag.name() {
+            "a" | "link" | "area" => "href",
+            "img" | "script" | "iframe" | "source" | "video" | "audio" | "embed" | "track" => "src",
+            _ => continue,
+        };
+        if let Some(reference) = tag.attribute(attribute) {
+            let kind = match tag.name() {
+                "a" | "area" => "link",
+                "img" => "image",
+                _ => "file",
+            };
+            check_reference(site, file, tag.line(), reference, kind, findings);
+        }
+    }
+}
+
+fn check_css(site: &SiteFiles, file: &str, source: &str, findings: &mut Vec<Finding>) {
+    for (index, line) in source.lines().enumerate() {
+        let mut rest = line;
+        while let Some(start) = rest.find("url(") {
+            rest = &rest[start + 4..];
+            let Some(end) = rest.find(')') else { break };
+            let reference = rest[..end].trim().trim_matches(|c| c == '"' || c == '\'');
+            check_reference(site, file, index + 1, reference, "file", findings);
+            rest = &rest[end..];
+        }
+    }
+}
+
+fn check_reference(site: &SiteFiles, file: &str, line: usize, reference: &str, kind: &str, findings: &mut Vec<Finding>) {
+    let reference = reference.trim();
+    let lower = reference.to_lowercase();
+    let external = ["http:", "https:", "//", "mailto:", "tel:", "javascript:", "data:", "#"]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix));
+    // Skip empty and external references, and Liquid template expressions
+    if reference.is_empty() || external || reference.contains("{{") || reference.contains("{%") {
+        return;
+    }
+
+    let path = percent_decode(reference.split(['?', '#']).next().unwrap_or_default());
+    let base = if path.starts_with('/') {
+        ""
+    } else {
+        file.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+    };
+
+    let resolution = match normalize(base, &path) {
+        Some(target) => site.resolve(&target, path.ends_with('/')),
+        None => Resolution::Missing,
+    };
+
+    match resolution {
+        Resolution::Found => {}
+        Resolution::WrongCase(actual) => findings.push(Finding::new(
+            Severity::Fail,
+            file,
+            line,
+            format!("'{}' only matches '{}' with different capitalization, which breaks on GitHub Pages", reference, actual),
+        )),
+        Resolution::Missing => findings.push(Finding::new(
+            Severity::Fail,
+            file,
+            line,
+            format!("Broken {} reference '{}'", kind, reference),
+        )),
+    }
+}
+
+fn check_nesting(file: &str, tags: &[Tag], findings: &mut Vec<Finding>) {
+    let mut problems = Vec::new();
+    let mut stack: Vec<(&str, usize)> = Vec::new();
+
+    for tag in tags {
+        match tag {
+            Tag::Start { name, self_closing, line, .. } => {
+                let name = name.as_str();
+                while let Some((top, _)) = stack.last() {
+                    if implicitly_closes(name, top) {
+                        stack.pop();
+                    } else {
+                        break;
+                    }
+                }
+
+                if (name == "a" || name == "form") && stack.iter().any(|(open, _)| *open == name) {
+                    problems.push((*line, format!("<{}> cannot be nested inside another <{}>", name, name)));
+                }
+
+                if !VOID_ELEMENTS.contains(&name) && !self_closing {
+                    stack.push((name, *line));
+                }
+            }
+            Tag::End { name, line } => {
+                let name = name.as_str();
+                if VOID_ELEMENTS.contains(&name) {
+                    continue;
+                }
+                match stack.iter().rposition(|(open, _)| *open == name) {
+                    Some(position) => {
+                        for (open, open_line) in stack.drain(position + 1..) {
+                            if !OPTIONAL_END_ELEMENTS.contains(&open) {
+                                problems.push((*line, format!("<{}> from line {} is not closed before </{}>", open, open_line, name)));
+                            }
+                        }
+                        stack.pop();
+                    }
+                    None => problems.push((*line, format!("</{}> has no matching opening tag", name))),
+                }
+            }
+        }
+    }
+
+    for (open, line) in stack {
+        if !OPTIONAL_END_ELEMENTS.contains(&open) {
+            problems.push((line, format!("<{}> is never closed", open)));
+        }
+    }
+
+    let hidden = problems.len().saturating_sub(MAX_NESTING_FINDINGS);
+    for (line, message) in problems.into_iter().take(MAX_NESTING_FINDINGS) {
+        findings.push(Finding::new(Severity::Warn, file, line, message));
+    }
+    if hidden > 0 {
+        findings.push(Finding {
+            severity: Severity::Warn,
+            file: Some(file.to_string()),
+            line: None,
+            message: format!("{} more nesting problem{} not shown", hidden, plural(hidden)),
+        });
+    }
+}
+
+/// Whether opening `name` implicitly closes the open element `open`
+fn implicitly_closes(name: &str, open: &str) -> bool {
+    match open {
+        "p" => CLOSES_PARAGRAPH.contains(&name),
+        "li" => name == "li",
+        "dt" | "dd" => name == "dt" || name == "dd",
+        "td" | "th" => matches!(name, "td" | "th" | "tr" | "tbody" | "tfoot"),
+        "tr" => matches!(name, "tr" | "tbody" | "tfoot"),
+        "thead" | "tbody" => name == "tbody" || name == "tfoot",
+        "option" => name == "option" || name == "optgroup",
+        "head" => name == "body",
+        _ => false,
+    }
+}
+
+/// Join `path` onto `base`, resolving `.` and `..`; `None` if it escapes the site root
+fn normalize(base: &str, path: &str) -> Option<String> {
+    let mut segments: Vec<&str> = base.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            s => segments.push(s),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 { "" } else { "s" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_site_reports_pages_problems() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir_all(dir.path().join("images"))?;
+        std::fs::write(dir.path().join("images/Logo.png"), "png")?;
+        std::fs::write(dir.path().join("about.html"), "<html><body><p>About</p></body></html>")?;
+        std::fs::write(dir.path().join("style.css"), "body { background: url('images/missing.png'); }")?;
+        std::fs::write(
+            dir.path().join("index.html"),
+            "<!DOCTYPE html>\n<html>\n<head><link rel=\"stylesheet\" href=\"style.css\"></head>\n<body>\n\
+             <a href=\"about\">About</a> <a href=\"contact.html\">Contact</a>\n\
+             <img src=\"images/logo.png\">\n<div><span>unclosed</div>\n\
+             <script>if (a < b) { document.write('<p>'); }</script>\n</body>\n</html>\n",
+        )?;
+
+        let report = check_site(dir.path())?;
+        let messages: Vec<_> = report.findings.iter().map(|f| (f.location(), f.message.clone())).collect();
+
+        assert_eq!(report.status(), Severity::Fail);
+        assert!(messages.contains(&(Some("index.html:5".to_string()), "Broken link reference 'contact.html'".to_string())));
+        assert!(messages.iter().any(|(loc, m)| loc.as_deref() == Some("index.html:6") && m.contains("different capitalization")));
+        assert!(messages.contains(&(Some("index.html:6".to_string()), "<img> is missing an alt attribute".to_string())));
+        assert!(messages.contains(&(Some("index.html:7".to_string()), "<span> from line 7 is not closed before </div>".to_string())));
+        assert!(messages.contains(&(Some("style.css:1".to_string()), "Broken file reference 'images/missing.png'".to_string())));
+        assert_eq!(report.findings.len(), 5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+use std::{rc::Rc, time::Duration};
+
+use crate::{
+    app::AppEvent,
+    data::{Class, Student},
+    ui::{
+        components::input::AnimatedInput,
+        layout,
+        mouse::MouseAction,
+        screens::{ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::{fuzzy, launcher::OpenAction},
+};
+
+/// Something the palette can do, found by fuzzy search on its title and detail
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub title: String,
+    /// Shown dimmed after the title and searched too, e.g. the class a student is in
+    pub detail: String,
+    pub event: AppEvent,
+}
+
+impl PaletteEntry {
+    fn new(title: impl Into<String>, detail: impl Into<String>, event: AppEvent) -> Self {
+        Self {
+            title: title.into(),
+            detail: detail.into(),
+            event,
+        }
+    }
+}
+
+/// Entries that don't belong to a class
+pub fn global_entries() -> Vec<PaletteEntry> {
+    vec![
+        PaletteEntry::new("Select a class", "", AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::ClassSelection))),
+        PaletteEntry::new("Create a class", "", AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass))),
+        PaletteEntry::new("Settings", "", AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::Settings))),
+        PaletteEntry::new("Keyboard help", "keys for this screen", AppEvent::ShowHelp),
+        PaletteEntry::new("Quit", "", AppEvent::Quit),
+    ]
+}
+
+/// Class-wide actions plus per-student actions for everyone in the class
+pub fn class_entries(class: &Class, students: &[Student]) -> Vec<PaletteEntry> {
+    let in_class = |event: AppEvent| AppEvent::InClass(class.clone(), Box::new(event));
+    let screen = |variant: ScreenTypeVariant| {
+        in_class(AppEvent::NavigateToScreen(
+            ScreenType::new(variant).with_context(ScreenContext::Class(class.clone())),
+        ))
+    };
+
+    let mut entries = vec![
+        PaletteEntry::new(format!("Open {}", class.name), "dashboard", AppEvent::SelectClass(class.clone())),
+        PaletteEntry::new(format!("Class menu for {}", class.name), "", screen(ScreenTypeVariant::ClassManagement)),
+        PaletteEntry::new(format!("Week view for {}", class.name), "", in_class(AppEvent::ShowWeekView)),
+        PaletteEntry::new(format!("Latest activity in {}", class.name), "", in_class(AppEvent::ShowLatestActivity)),
+        PaletteEntry::new(format!("Activity heatmap for {}", class.name), "", screen(ScreenTypeVariant::ActivityHeatmap)),
+        PaletteEntry::new(format!("Manage students in {}", class.name), "", screen(ScreenTypeVariant::StudentManagement)),
+        PaletteEntry::new(format!("Manage repositories in {}", class.name), "", screen(ScreenTypeVariant::RepositoryManagement)),
+        PaletteEntry::new(format!("Assignments for {}", class.name), "", screen(ScreenTypeVariant::Assignments)),
+        PaletteEntry::new(format!("School calendar for {}", class.name), "", screen(ScreenTypeVariant::SchoolCalendar)),
+        PaletteEntry::new(format!("Run tests in {}", class.name), "", screen(ScreenTypeVariant::TestRunner)),
+        PaletteEntry::new(format!("Clone all repos in {}", class.name), "", in_class(AppEvent::CloneAllRepos)),
+        PaletteEntry::new(format!("Pull all repos in {}", class.name), "", in_class(AppEvent::PullRepositories)),
+        PaletteEntry::new(format!("Check all sites in {}", class.name), "", in_class(AppEvent::CheckAllSites)),
+        PaletteEntry::new(format!("Export gradebook for {}", class.name), "", in_class(AppEvent::ExportGradebook)),
+    ];
+
+    for student in students {
+        let github = &student.github_username;
+        let detail = format!("{} · {}", github, class.name);
+        let student_entries = [
+            (format!("Show {}", student.username), in_class(AppEvent::ShowStudentDetail(student.clone()))),
+            (format!("Open {} in terminal", student.username), in_class(AppEvent::OpenWith(OpenAction::Terminal, github.clone()))),
+            (format!("Open {} in editor", student.username), in_class(AppEvent::OpenWith(OpenAction::Editor, github.clone()))),
+            (format!("Open {}'s site in browser", student.username), in_class(AppEvent::OpenWith(OpenAction::Browser, github.clone()))),
+            (format!("Shell in {}'s repo", student.username), in_class(AppEvent::SuspendToShell(github.clone()))),
+            (format!("Pull {}'s repo", student.username), in_class(AppEvent::PullRepo(github.clone()))),
+            (format!("Clone {}'s repo", student.username), in_class(AppEvent::CloneRepo(github.clone()))),
+            (format!("Check {}'s site", student.username), in_class(AppEvent::CheckSite(github.clone()))),
+        ];
+        entries.extend(student_entries.into_iter().map(|(title, event)| PaletteEntry::new(title, detail.clone(), event)));
+    }
+
+    entries
+}
+
+/// Global fuzzy finder over classes, students and actions
+pub struct CommandPalette {
+    input: AnimatedInput,
+    entries: Vec<PaletteEntry>,
+    /// Indices into `entries` matching the query, best first
+    matches: Vec<usize>,
+    list_state: ListState,
+    is_open: bool,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        let mut input = AnimatedInput::new("Search classes, students and actions");
+        input.set_placeholder("e.g. pull period 3, open jdoe in terminal");
+
+        Self {
+            input,
+            entries: Vec::new(),
+            matches: Vec::new(),
+            list_state: ListState::default(),
+            is_open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn open(&mut self, entries: Vec<PaletteEntry>) {
+        self.entries = entries;
+        self.input.set_value(String::new());
+        self.input.focus();
+        self.is_open = true;
+        self.refilter();
+    }
+
+    pub fn close(&mut self) {
+        self.input.unfocus();
+        self.is_open = false;
+    }
+
+    fn refilter(&mut self) {
+        let query = self.input.value();
+        let mut scored: Vec<(usize, u32)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                // Titles carry the action, so they outrank a match only in the detail
+                let title = fuzzy::score(query, &entry.title).map(|score| score * 2);
+                let combined = fuzzy::score(query, &format!("{} {}", entry.title, entry.detail));
+                title.max(combined).map(|score| (index, score))
+            })
+            .collect();
+        scored.sort_by_key(|(index, score)| (std::cmp::Reverse(*score), *index));
+        self.matches = scored.into_iter().map(|(index, _)| index).collect();
+        self.list_state.select(if self.matches.is_empty() { None } else { Some(0) });
+    }
+
+    fn select_previous(&mut self) {
+        let selected = self.list_state.selected().unwrap_or(0);
+        if selected > 0 {
+            self.list_state.select(Some(selected - 1));
+        }
+    }
+
+    fn select_next(&mut self) {
+        let selected = self.list_state.selected().unwrap_or(0);
+        if selected + 1 < self.matches.len() {
+            self.list_state.select(Some(selected + 1));
+        }
+    }
+
+    /// Close and return the selected entry's event, if anything is selected
+    fn run_selected(&mut self) -> Option<AppEvent> {
+        let event = self
+            .list_state
+            .selected()
+            .and_then(|i| self.matches.get(i))
+            .map(|index| self.entries[*index].event.clone());
+        if event.is_some() {
+            self.close();
+        }
+        event
+    }
+
+    /// Handle a key while open; returns the chosen entry's event
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<AppEvent> {
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Enter => return self.run_selected(),
+            _ => {
+                self.input.handle_key_event(key);
+                self.refilter();
+            }
+        }
+        None
+    }
+
+    /// Click selects a match and double click runs it; clicking outside the popup closes it
+    pub fn handle_mouse_event(&mut self, mouse: MouseAction, area: Rect) -> Option<AppEvent> {
+        let (popup_area, chunks) = Self::areas(area);
+        let (column, row) = mouse.position();
+        match mouse {
+            MouseAction::ScrollUp { .. } => self.select_previous(),
+            MouseAction::ScrollDown { .. } => self.select_next(),
+            _ if !layout::contains(popup_area, column, row) => self.close(),
+            MouseAction::Click { .. } | MouseAction::DoubleClick { .. } => {
+                let index = layout::list_row_at(chunks[1], column, row, self.list_state.offset())?;
+                if index < self.matches.len() {
+                    self.list_state.select(Some(index));
+                    if matches!(mouse, MouseAction::DoubleClick { .. }) {
+                        return self.run_selected();
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The popup, and inside its border the query, matches and help rows
+    fn areas(area: Rect) -> (Rect, Rc<[Rect]>) {
+        let popup_area = layout::center_rect(70, 60, area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Query
+                Constraint::Min(1),    // Matches
+                Constraint::Length(1), // Help text
+            ])
+            .split(popup_area.inner(&Margin { vertical: 1, horizontal: 1 }));
+        (popup_area, chunks)
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        self.input.update(delta_time);
+    }
+
+    pub fn render(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        if !self.is_open {
+            return;
+        }
+
+        let (popup_area, chunks) = Self::areas(area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("🔎 Command Palette")
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        frame.render_widget(block, popup_area);
+
+        frame.render_widget(&self.input, chunks[0]);
+
+        if self.matches.is_empty() {
+            let empty = Paragraph::new("Nothing matches")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(empty, chunks[1]);
+        } else {
+            let items: Vec<ListItem> = self
+                .matches
+                .iter()
+                .map(|index| {
+                    let entry = &self.entries[*index];
+                    let mut spans = vec![Span::styled(entry.title.clone(), Style::default().fg(theme.text))];
+                    if !entry.detail.is_empty() {
+                        spans.push(Span::styled(format!("  {}", entry.detail), Style::default().fg(theme.text_secondary)));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect();
+            let list = List::new(items)
+                .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
+                .highlight_symbol("▶ ");
+            frame.render_stateful_widget(list, chunks[1], &mut self.list_state);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("↑/↓", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Run  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Close", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[2]);
+    }
+}
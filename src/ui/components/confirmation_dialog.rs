@@ -3,11 +3,12 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 use crossterm::event::{KeyCode, KeyEvent};
-use crate::ui::themes::Theme;
+use crate::app::{keymap, Action};
+use crate::ui::{layout, mouse::MouseAction, themes::Theme};
 
 /// A reusable confirmation dialog component
 pub struct ConfirmationDialog {
@@ -16,6 +17,12 @@ pub struct ConfirmationDialog {
     yes_text: String,
     no_text: String,
     is_visible: bool,
+    /// First message line shown, for messages taller than the terminal allows
+    scroll: u16,
+    /// Where the dialog and its Yes/No buttons were last drawn, for mouse hit-testing
+    popup_area: Rect,
+    yes_area: Rect,
+    no_area: Rect,
 }
 
 impl ConfirmationDialog {
@@ -27,6 +34,10 @@ impl ConfirmationDialog {
             yes_text: "Yes".to_string(),
             no_text: "No".to_string(),
             is_visible: false,
+            scroll: 0,
+            popup_area: Rect::default(),
+            yes_area: Rect::default(),
+            no_area: Rect::default(),
         }
     }
 
@@ -45,6 +56,7 @@ impl ConfirmationDialog {
     /// Show the dialog
     pub fn show(&mut self) {
         self.is_visible = true;
+        self.scroll = 0;
     }
 
     /// Hide the dialog
@@ -58,7 +70,8 @@ impl ConfirmationDialog {
     }
 
     /// Handle key events for the dialog
-    /// Returns true if "yes" was selected, false if "no" was selected or dialog was cancelled
+    /// Returns true if "yes" was selected, false if "no" was selected or dialog was cancelled;
+    /// up and down scroll a message too long to show at once
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<bool> {
         if !self.is_visible {
             return None;
@@ -73,18 +86,66 @@ impl ConfirmationDialog {
                 self.hide();
                 Some(false)
             },
+            _ if keymap::is(Action::Up, &key) => {
+                self.scroll = self.scroll.saturating_sub(1);
+                None
+            },
+            _ if keymap::is(Action::Down, &key) => {
+                self.scroll = self.scroll.saturating_add(1);
+                None
+            },
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+                None
+            },
+            KeyCode::PageDown => {
+                self.scroll = self.scroll.saturating_add(10);
+                None
+            },
             _ => None,
         }
     }
 
+    /// The key a click on the dialog stands for: Y on the yes button, N on the no button or
+    /// outside the dialog, and up or down for the wheel. Screens pass it on to their key handler
+    /// so both answer the same way.
+    pub fn key_for_click(&self, mouse: MouseAction) -> Option<KeyEvent> {
+        if !self.is_visible {
+            return None;
+        }
+        match mouse {
+            MouseAction::ScrollUp { .. } => return keymap::key_event(Action::Up),
+            MouseAction::ScrollDown { .. } => return keymap::key_event(Action::Down),
+            MouseAction::Click { .. } | MouseAction::DoubleClick { .. } => {}
+        }
+
+        let (column, row) = mouse.position();
+        if layout::contains(self.yes_area, column, row) {
+            Some(KeyEvent::from(KeyCode::Char('y')))
+        } else if layout::contains(self.no_area, column, row) || !layout::contains(self.popup_area, column, row) {
+            Some(KeyEvent::from(KeyCode::Char('n')))
+        } else {
+            None
+        }
+    }
+
     /// Render the dialog
-    pub fn render<B: Backend>(&self, frame: &mut Frame<B>, area: Rect, theme: &Theme) {
+    pub fn render<B: Backend>(&mut self, frame: &mut Frame<B>, area: Rect, theme: &Theme) {
         if !self.is_visible {
             return;
         }
 
-        // Create a centered popup
-        let popup_area = centered_rect(50, 30, area);
+        // Tall enough for the whole message where the terminal allows, scrolling the rest
+        let width = (area.width / 2).max(50).min(area.width);
+        let message_lines = wrapped_height(&self.message, width.saturating_sub(2));
+        let height = (message_lines + 2 + 3).max(8).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        self.popup_area = popup_area;
         
         // Clear the background
         frame.render_widget(Clear, popup_area);
@@ -107,49 +168,79 @@ impl ConfirmationDialog {
                 Constraint::Length(3),  // Buttons
             ])
             .split(inner_area);
+        
+        let overflows = message_lines > chunks[0].height;
+        let message_area = if overflows {
+            let hint_area = Rect::new(chunks[0].x, chunks[0].bottom().saturating_sub(1), chunks[0].width, 1.min(chunks[0].height));
+            let hint = Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Scroll to see everything", Style::default().fg(theme.text_secondary)),
+            ]);
+            frame.render_widget(Paragraph::new(hint).alignment(Alignment::Center), hint_area);
+            Rect { height: chunks[0].height.saturating_sub(1), ..chunks[0] }
+        } else {
+            chunks[0]
+        };
+        self.scroll = self.scroll.min(message_lines.saturating_sub(message_area.height));
             
         // Render the message
         let message = Paragraph::new(self.message.clone())
             .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .scroll((self.scroll, 0))
             .style(Style::default().fg(theme.text));
             
-        frame.render_widget(message, chunks[0]);
+        frame.render_widget(message, message_area);
         
-        // Render the buttons
-        let button_text = Line::from(vec![
-            Span::styled("[", Style::default().fg(theme.text_secondary)),
-            Span::styled(format!("Y - {}", self.yes_text), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
-            Span::styled("]", Style::default().fg(theme.text_secondary)),
-            Span::styled("   ", Style::default().fg(theme.text_secondary)),
-            Span::styled("[", Style::default().fg(theme.text_secondary)),
-            Span::styled(format!("N - {}", self.no_text), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
-            Span::styled("]", Style::default().fg(theme.text_secondary)),
-        ]);
-        
-        let buttons = Paragraph::new(button_text)
-            .alignment(Alignment::Center);
-            
-        frame.render_widget(buttons, chunks[1]);
+        // Render the buttons, each in its own clickable area centered on the first row
+        let yes_label = format!("Y - {}", self.yes_text);
+        let no_label = format!("N - {}", self.no_text);
+        let yes_width = yes_label.chars().count() as u16 + 2;
+        let no_width = no_label.chars().count() as u16 + 2;
+        let gap = 3;
+        let row = chunks[1];
+        let start = row.x + row.width.saturating_sub(yes_width + gap + no_width) / 2;
+        self.yes_area = Rect::new(start, row.y, yes_width.min(row.width), 1.min(row.height));
+        self.no_area = Rect::new(start + yes_width + gap, row.y, no_width, 1.min(row.height)).intersection(row);
+
+        let button = |label: String, color| {
+            Paragraph::new(Line::from(vec![
+                Span::styled("[", Style::default().fg(theme.text_secondary)),
+                Span::styled(label, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled("]", Style::default().fg(theme.text_secondary)),
+            ]))
+        };
+        frame.render_widget(button(yes_label, theme.warning), self.yes_area);
+        frame.render_widget(button(no_label, theme.primary), self.no_area);
     }
 }
 
-/// Helper function to create a centered rectangle
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+/// Rows `text` takes up when word-wrapped to `width` columns
+fn wrapped_height(text: &str, width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    let rows: usize = text
+        .lines()
+        .map(|line| {
+            let mut rows = 1;
+            let mut used = 0;
+            for word in line.split_whitespace() {
+                let len = word.chars().count();
+                if used > 0 && used + 1 + len > width {
+                    rows += 1;
+                    used = 0;
+                }
+                if used > 0 {
+                    used += 1;
+                }
+                used += len;
+                // A word longer than a row is broken across rows
+                while used > width {
+                    rows += 1;
+                    used -= width;
+                }
+            }
+            rows
+        })
+        .sum();
+    rows.min(u16::MAX as usize) as u16
 }
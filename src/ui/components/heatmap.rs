@@ -0,0 +1,124 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::Widget,
+};
+use std::collections::HashMap;
+
+use crate::{
+    ui::themes::{ActivityLevel, Theme},
+    utils::time,
+};
+
+const DAY_LABELS: [&str; 7] = ["Mon", "", "Wed", "", "Fri", "", "Sun"];
+const LABEL_WIDTH: u16 = 4;
+const CELL_WIDTH: u16 = 2;
+
+/// Commits per calendar day at school
+pub fn count_by_day(times: &[DateTime<Utc>]) -> HashMap<NaiveDate, u32> {
+    let mut counts = HashMap::new();
+    for time in times {
+        *counts.entry(time::school_date(*time)).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Monday of the first of `weeks` weeks ending with the week containing `end`
+pub fn first_monday(end: NaiveDate, weeks: usize) -> NaiveDate {
+    let end_monday = end - Duration::days(end.weekday().num_days_from_monday() as i64);
+    end_monday - Duration::weeks(weeks.max(1) as i64 - 1)
+}
+
+/// GitHub-style contribution grid: one column per week, one row per weekday, ending at `end`
+pub struct ActivityHeatmap<'a> {
+    counts: &'a HashMap<NaiveDate, u32>,
+    end: NaiveDate,
+    weeks: usize,
+    cursor: Option<NaiveDate>,
+    theme: &'a Theme,
+}
+
+impl<'a> ActivityHeatmap<'a> {
+    pub fn new(counts: &'a HashMap<NaiveDate, u32>, end: NaiveDate, theme: &'a Theme) -> Self {
+        Self { counts, end, weeks: 12, cursor: None, theme }
+    }
+
+    pub fn weeks(mut self, weeks: usize) -> Self {
+        self.weeks = weeks.max(1);
+        self
+    }
+
+    /// Highlight the cell for this date
+    pub fn cursor(mut self, date: NaiveDate) -> Self {
+        self.cursor = Some(date);
+        self
+    }
+
+    /// Monday of the first week shown
+    fn start(&self) -> NaiveDate {
+        first_monday(self.end, self.weeks)
+    }
+}
+
+impl Widget for ActivityHeatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Month labels on the first row, seven weekday rows, then the legend
+        if area.height < 9 || area.width < LABEL_WIDTH + CELL_WIDTH {
+            return;
+        }
+
+        // Drop the oldest weeks if the area is too narrow for all of them
+        let fits = ((area.width - LABEL_WIDTH) / CELL_WIDTH) as usize;
+        let weeks = self.weeks.min(fits);
+        let start = self.start() + Duration::weeks((self.weeks - weeks) as i64);
+
+        for (row, label) in DAY_LABELS.iter().enumerate() {
+            buf.set_string(area.x, area.y + 1 + row as u16, label, Style::default().fg(self.theme.text_secondary));
+        }
+
+        let mut last_month = None;
+        for week in 0..weeks {
+            let x = area.x + LABEL_WIDTH + week as u16 * CELL_WIDTH;
+            let monday = start + Duration::weeks(week as i64);
+
+            if last_month != Some(monday.month()) && x + 3 <= area.x + area.width {
+                buf.set_string(x, area.y, monday.format("%b").to_string(), Style::default().fg(self.theme.text_secondary));
+                last_month = Some(monday.month());
+            }
+
+            for day in 0..7 {
+                let date = monday + Duration::days(day);
+                if date > self.end {
+                    break;
+                }
+                let count = self.counts.get(&date).copied().unwrap_or(0);
+                let color = self.theme.activity_color(ActivityLevel::from_commit_count(count));
+                let style = if self.cursor == Some(date) {
+                    Style::default().fg(color).bg(self.theme.highlight)
+                } else {
+                    Style::default().fg(color)
+                };
+                buf.set_string(x, area.y + 1 + day as u16, "■", style);
+            }
+        }
+
+        let total: u32 = (0..weeks as i64 * 7)
+            .filter_map(|day| self.counts.get(&(start + Duration::days(day))))
+            .sum();
+        let legend_y = area.y + 8;
+        let mut x = area.x + LABEL_WIDTH;
+        buf.set_string(x, legend_y, "Less ", Style::default().fg(self.theme.text_secondary));
+        x += 5;
+        for level in [ActivityLevel::None, ActivityLevel::Low, ActivityLevel::Medium, ActivityLevel::High, ActivityLevel::Max] {
+            buf.set_string(x, legend_y, "■", Style::default().fg(self.theme.activity_color(level)));
+            x += 1;
+        }
+        let summary = format!(" More   {} commits in {} weeks", total, weeks);
+        if x < area.x + area.width {
+            let width = (area.x + area.width - x) as usize;
+            buf.set_stringn(x, legend_y, summary, width, Style::default().fg(self.theme.text_secondary));
+        }
+    }
+}
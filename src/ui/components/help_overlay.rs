@@ -0,0 +1,166 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{keymap, Action},
+    ui::{
+        layout,
+        mouse::MouseAction,
+        screens::{help::{self, HelpSection}, ScreenTypeVariant},
+        themes::Theme,
+    },
+};
+
+/// Popup listing every key the current screen responds to
+pub struct HelpOverlay {
+    title: String,
+    sections: Vec<HelpSection>,
+    scroll: u16,
+    is_visible: bool,
+}
+
+impl HelpOverlay {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            sections: Vec::new(),
+            scroll: 0,
+            is_visible: false,
+        }
+    }
+
+    /// Show the help for a screen
+    pub fn show(&mut self, variant: &ScreenTypeVariant) {
+        self.title = format!("❔ Help: {}", variant);
+        self.sections = help::for_screen(variant);
+        self.scroll = 0;
+        self.is_visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.is_visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Scroll, or close on Back or Help; every other key is swallowed while open
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            _ if keymap::is(Action::Back, &key) || keymap::is(Action::Help, &key) => self.hide(),
+            _ if keymap::is(Action::Up, &key) => self.scroll = self.scroll.saturating_sub(1),
+            _ if keymap::is(Action::Down, &key) => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Home => self.scroll = 0,
+            _ => {}
+        }
+    }
+
+    /// The wheel scrolls and a click outside the popup closes it
+    pub fn handle_mouse_event(&mut self, mouse: MouseAction, area: Rect) {
+        let (column, row) = mouse.position();
+        match mouse {
+            MouseAction::ScrollUp { .. } => self.scroll = self.scroll.saturating_sub(3),
+            MouseAction::ScrollDown { .. } => self.scroll = self.scroll.saturating_add(3),
+            _ if !layout::contains(Self::popup_area(area), column, row) => self.hide(),
+            _ => {}
+        }
+    }
+
+    fn popup_area(area: Rect) -> Rect {
+        layout::center_rect(70, 80, area)
+    }
+
+    fn lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let labelled: Vec<Vec<(String, &help::HelpEntry)>> = self
+            .sections
+            .iter()
+            .map(|section| section.entries.iter().map(|entry| (entry.keys.label(), entry)).collect())
+            .collect();
+        let key_width = labelled
+            .iter()
+            .flatten()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(20);
+
+        let mut lines = Vec::new();
+        for (section, entries) in self.sections.iter().zip(labelled) {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                section.title,
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+            )));
+            for (label, entry) in entries {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<width$}  ", label, width = key_width),
+                        Style::default().fg(theme.primary).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(entry.description.clone(), Style::default().fg(theme.text)),
+                ]));
+                if let Some(detail) = &entry.detail {
+                    lines.push(Line::from(Span::styled(
+                        format!("  {:<width$}  {}", "", detail, width = key_width),
+                        Style::default().fg(theme.text_secondary),
+                    )));
+                }
+            }
+        }
+        lines
+    }
+
+    pub fn render<B: Backend>(&mut self, frame: &mut Frame<B>, area: Rect, theme: &Theme) {
+        if !self.is_visible {
+            return;
+        }
+
+        let popup_area = Self::popup_area(area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(Span::styled(self.title.clone(), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // Entries
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        // Keep the last line reachable but don't scroll past it
+        let lines = self.lines(theme);
+        let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+        self.scroll = self.scroll.min(max_scroll);
+
+        let entries = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(entries, chunks[0]);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Scroll  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back, Action::Help]), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Close", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[1]);
+    }
+}
@@ -11,7 +11,7 @@ use ratatui::{
 use std::{time::Duration, future::Future, pin::Pin};
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     ui::{
         animations::AnimationState,
         components::menu::{AnimatedMenu, MenuPresets},
@@ -212,11 +212,11 @@ impl Screen for MainMenuScreen {
                 self.show_logo = !self.show_logo;
                 Ok(None)
             },
-            KeyCode::Up | KeyCode::Char('k') => {
+            _ if keymap::is(Action::Up, &key) => {
                 self.menu.select_previous();
                 Ok(None)
             },
-            KeyCode::Down | KeyCode::Char('j') => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.menu.select_next();
                 Ok(None)
             },
@@ -10,6 +10,8 @@ use std::time::Duration;
 
 use crate::ui::{
     animations::{AnimationState, EasingFunction},
+    layout,
+    mouse::MouseAction,
     themes::Theme,
 };
 
@@ -21,6 +23,8 @@ pub struct MenuItem {
     pub icon: Option<String>,
     pub enabled: bool,
     pub hotkey: Option<char>,
+    /// What the item really does, for the help overlay when the description doesn't say
+    pub detail: Option<String>,
 }
 
 impl MenuItem {
@@ -31,6 +35,7 @@ impl MenuItem {
             icon: None,
             enabled: true,
             hotkey: None,
+            detail: None,
         }
     }
 
@@ -49,6 +54,11 @@ impl MenuItem {
         self
     }
 
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
     pub fn disabled(mut self) -> Self {
         self.enabled = false;
         self
@@ -65,6 +75,8 @@ pub struct AnimatedMenu {
     animation_offset: f32,
     highlight_animation: f32,
     entrance_animation: f32,
+    /// Where the items were last drawn, for mouse hit-testing
+    items_area: Rect,
 }
 
 impl AnimatedMenu {
@@ -78,6 +90,7 @@ impl AnimatedMenu {
             animation_offset: 0.0,
             highlight_animation: 0.0,
             entrance_animation: 0.0,
+            items_area: Rect::default(),
         }
     }
 
@@ -121,6 +134,38 @@ impl AnimatedMenu {
         }
     }
 
+    /// Select the item with this hotkey; false if no item has it
+    pub fn select_hotkey(&mut self, key: char) -> bool {
+        match self.items.iter().position(|item| item.enabled && item.hotkey == Some(key)) {
+            Some(index) => {
+                self.select_item(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Click selects the item under the pointer and the wheel moves the selection. Returns
+    /// true for a double click on an item, which the screen should treat like Enter.
+    pub fn handle_mouse_event(&mut self, mouse: MouseAction) -> bool {
+        let (column, row) = mouse.position();
+        match mouse {
+            MouseAction::ScrollUp { .. } => self.select_previous(),
+            MouseAction::ScrollDown { .. } => self.select_next(),
+            MouseAction::Click { .. } | MouseAction::DoubleClick { .. } => {
+                let visible = self.items.len().min(self.items_area.height as usize);
+                match layout::list_row_at(self.items_area, column, row, 0) {
+                    Some(index) if index < visible => {
+                        self.select_item(index);
+                        return matches!(mouse, MouseAction::DoubleClick { .. });
+                    }
+                    _ => {}
+                }
+            }
+        }
+        false
+    }
+
     pub fn selected_item(&self) -> Option<&MenuItem> {
         self.items.get(self.selected)
     }
@@ -216,6 +261,7 @@ impl Widget for &mut AnimatedMenu {
 
 impl AnimatedMenu {
     fn render_menu_items(&mut self, area: Rect, buf: &mut Buffer, entrance_progress: f32) {
+        self.items_area = area;
         let theme = &crate::ui::themes::THEMES.neon_night; // TODO: Get from context
         
         for (i, item) in self.items.iter().enumerate() {
@@ -1,9 +1,16 @@
+pub mod command_palette;
 pub mod confirmation_dialog;
 pub mod dashboard;
+pub mod heatmap;
+pub mod help_overlay;
 pub mod input;
 pub mod loading;
 pub mod main_menu;
 pub mod menu;
+pub mod report_overlay;
+pub mod selection;
+pub mod syntax;
+pub mod table_filter;
 
 // Re-export the components that are being used
 pub use menu::{AnimatedMenu, MenuBuilder, MenuItem, MenuPresets};
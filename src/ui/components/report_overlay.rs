@@ -0,0 +1,140 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{keymap, Action},
+    site_check::Severity,
+    ui::{layout, mouse::MouseAction, themes::Theme},
+};
+
+/// Scrollable popup with a one-line headline over one line per item, such as a batch report
+pub struct ReportOverlay {
+    title: String,
+    headline: String,
+    outcome: Severity,
+    lines: Vec<String>,
+    scroll: u16,
+    is_visible: bool,
+}
+
+impl ReportOverlay {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            headline: String::new(),
+            outcome: Severity::Pass,
+            lines: Vec::new(),
+            scroll: 0,
+            is_visible: false,
+        }
+    }
+
+    /// Show a report; `outcome` colours the border and headline
+    pub fn show(&mut self, title: String, headline: String, outcome: Severity, lines: Vec<String>) {
+        self.title = title;
+        self.headline = headline;
+        self.outcome = outcome;
+        self.lines = lines;
+        self.scroll = 0;
+        self.is_visible = true;
+    }
+
+    pub fn hide(&mut self) {
+        self.is_visible = false;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Scroll, or close on Back or Select; every other key is swallowed while open
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            _ if keymap::is(Action::Back, &key) || keymap::is(Action::Select, &key) => self.hide(),
+            _ if keymap::is(Action::Up, &key) => self.scroll = self.scroll.saturating_sub(1),
+            _ if keymap::is(Action::Down, &key) => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Home => self.scroll = 0,
+            _ => {}
+        }
+    }
+
+    /// The wheel scrolls and a click outside the popup closes it
+    pub fn handle_mouse_event(&mut self, mouse: MouseAction, area: Rect) {
+        let (column, row) = mouse.position();
+        match mouse {
+            MouseAction::ScrollUp { .. } => self.scroll = self.scroll.saturating_sub(3),
+            MouseAction::ScrollDown { .. } => self.scroll = self.scroll.saturating_add(3),
+            _ if !layout::contains(Self::popup_area(area), column, row) => self.hide(),
+            _ => {}
+        }
+    }
+
+    fn popup_area(area: Rect) -> Rect {
+        layout::center_rect(70, 80, area)
+    }
+
+    pub fn render<B: Backend>(&mut self, frame: &mut Frame<B>, area: Rect, theme: &Theme) {
+        if !self.is_visible {
+            return;
+        }
+
+        let popup_area = Self::popup_area(area);
+        frame.render_widget(Clear, popup_area);
+
+        let color = match self.outcome {
+            Severity::Pass => theme.success,
+            Severity::Warn => theme.warning,
+            Severity::Fail => theme.error,
+        };
+        let block = Block::default()
+            .title(Span::styled(self.title.clone(), Style::default().fg(color).add_modifier(Modifier::BOLD)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Headline
+                Constraint::Min(1),    // One line per item
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        let headline = Paragraph::new(Span::styled(self.headline.clone(), Style::default().fg(color).add_modifier(Modifier::BOLD)))
+            .alignment(Alignment::Center);
+        frame.render_widget(headline, chunks[0]);
+
+        // Keep the last line reachable but don't scroll past it
+        let max_scroll = (self.lines.len() as u16).saturating_sub(chunks[1].height);
+        self.scroll = self.scroll.min(max_scroll);
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| Line::from(Span::styled(line.clone(), Style::default().fg(theme.text))))
+            .collect();
+        let items = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(items, chunks[1]);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Scroll  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back, Action::Select]), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Close", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[2]);
+    }
+}
@@ -0,0 +1,121 @@
+use crossterm::event::KeyEvent;
+use std::collections::HashSet;
+
+use crate::{
+    app::{keymap, Action},
+    data::Student,
+};
+
+const SELECTION_ACTIONS: &[Action] =
+    &[Action::ToggleSelection, Action::SelectAll, Action::SelectNone, Action::InvertSelection];
+
+/// Students marked for a batch action. Marks are kept by id so they survive the list being reloaded.
+#[derive(Debug, Default, Clone)]
+pub struct StudentSelection {
+    ids: HashSet<i64>,
+}
+
+impl StudentSelection {
+    pub fn is_selected(&self, student: &Student) -> bool {
+        self.ids.contains(&student.id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn toggle(&mut self, student: &Student) {
+        if !self.ids.remove(&student.id) {
+            self.ids.insert(student.id);
+        }
+    }
+
+    pub fn select_all(&mut self, students: &[Student]) {
+        self.ids = students.iter().map(|student| student.id).collect();
+    }
+
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+
+    pub fn invert(&mut self, students: &[Student]) {
+        self.ids = students
+            .iter()
+            .map(|student| student.id)
+            .filter(|id| !self.ids.contains(id))
+            .collect();
+    }
+
+    /// Forget marks on students that are no longer listed, e.g. after a batch delete or move
+    pub fn retain(&mut self, students: &[Student]) {
+        self.ids.retain(|id| students.iter().any(|student| student.id == *id));
+    }
+
+    /// The marked students in list order
+    pub fn students(&self, students: &[Student]) -> Vec<Student> {
+        students.iter().filter(|student| self.is_selected(student)).cloned().collect()
+    }
+
+    /// Handle the toggle, all, none and invert keys; `current` is the highlighted student.
+    /// Returns whether the key was used.
+    pub fn handle_key_event(&mut self, key: KeyEvent, students: &[Student], current: Option<&Student>) -> bool {
+        match keymap::action_for(SELECTION_ACTIONS, &key) {
+            Some(Action::ToggleSelection) => {
+                if let Some(student) = current {
+                    self.toggle(student);
+                }
+            }
+            Some(Action::SelectAll) => self.select_all(students),
+            Some(Action::SelectNone) => self.clear(),
+            Some(Action::InvertSelection) => self.invert(students),
+            _ => return false,
+        }
+        true
+    }
+
+    /// `[x] ` or `[ ] ` in front of a student's name
+    pub fn marker(&self, student: &Student) -> &'static str {
+        if self.is_selected(student) { "[x] " } else { "[ ] " }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_toggle_all_none_and_invert() {
+        let students: Vec<Student> = (1..=4)
+            .map(|id| Student {
+                id,
+                class_id: 1,
+                username: format!("student{}", id),
+                github_username: format!("student{}", id),
+                created_at: Utc::now(),
+            })
+            .collect();
+        let mut selection = StudentSelection::default();
+
+        selection.toggle(&students[0]);
+        selection.toggle(&students[2]);
+        assert_eq!(selection.students(&students).iter().map(|s| s.id).collect::<Vec<_>>(), vec![1, 3]);
+        selection.toggle(&students[0]);
+        assert_eq!(selection.len(), 1);
+
+        selection.invert(&students);
+        assert_eq!(selection.students(&students).iter().map(|s| s.id).collect::<Vec<_>>(), vec![1, 2, 4]);
+
+        selection.retain(&students[1..]);
+        assert_eq!(selection.len(), 2);
+
+        selection.select_all(&students);
+        assert_eq!(selection.len(), 4);
+        selection.clear();
+        assert!(selection.is_empty());
+    }
+}
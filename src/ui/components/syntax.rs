@@ -0,0 +1,337 @@
+use ratatui::style::{Modifier, Style};
+use std::path::Path;
+
+use crate::ui::themes::Theme;
+
+/// Languages with basic syntax highlighting support
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Html,
+    Css,
+    JavaScript,
+    Plain,
+}
+
+impl Language {
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "html" | "htm" => Language::Html,
+            "css" => Language::Css,
+            "js" | "mjs" | "cjs" | "json" => Language::JavaScript,
+            _ => Language::Plain,
+        }
+    }
+}
+
+/// Kinds of tokens produced by the highlighter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Plain,
+    Keyword,
+    String,
+    Number,
+    Comment,
+    Tag,
+    Attribute,
+    Property,
+}
+
+impl TokenKind {
+    /// Get the theme style for this token kind
+    pub fn style(&self, theme: &Theme) -> Style {
+        match self {
+            TokenKind::Plain => Style::default().fg(theme.text),
+            TokenKind::Keyword => Style::default().fg(theme.secondary).add_modifier(Modifier::BOLD),
+            TokenKind::String => Style::default().fg(theme.warning),
+            TokenKind::Number => Style::default().fg(theme.info),
+            TokenKind::Comment => Style::default().fg(theme.text_secondary).add_modifier(Modifier::ITALIC),
+            TokenKind::Tag => Style::default().fg(theme.primary),
+            TokenKind::Attribute => Style::default().fg(theme.accent),
+            TokenKind::Property => Style::default().fg(theme.accent),
+        }
+    }
+}
+
+pub type Token = (TokenKind, String);
+
+const JS_KEYWORDS: &[&str] = &[
+    "async", "await", "break", "case", "catch", "class", "const", "continue", "default", "delete",
+    "do", "else", "export", "extends", "false", "finally", "for", "from", "function", "if",
+    "import", "in", "instanceof", "let", "new", "null", "of", "return", "static", "super",
+    "switch", "this", "throw", "true", "try", "typeof", "undefined", "var", "void", "while",
+    "yield",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Normal,
+    BlockComment,
+    HtmlComment,
+    InTag,
+}
+
+/// Line-oriented highlighter that carries multi-line state (comments, open tags) between lines
+pub struct Highlighter {
+    language: Language,
+    state: State,
+    css_depth: usize,
+}
+
+impl Highlighter {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            state: State::Normal,
+            css_depth: 0,
+        }
+    }
+
+    /// Highlight a whole source file, one token list per line
+    pub fn highlight_source(language: Language, source: &str) -> Vec<Vec<Token>> {
+        let mut highlighter = Self::new(language);
+        source.lines().map(|line| highlighter.highlight_line(line)).collect()
+    }
+
+    pub fn highlight_line(&mut self, line: &str) -> Vec<Token> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut tokens = Vec::new();
+
+        match self.language {
+            Language::Html => self.highlight_html(&chars, &mut tokens),
+            Language::Css => self.highlight_css(&chars, &mut tokens),
+            Language::JavaScript => self.highlight_js(&chars, &mut tokens),
+            Language::Plain => push(&mut tokens, TokenKind::Plain, line),
+        }
+
+        tokens
+    }
+
+    fn highlight_js(&mut self, chars: &[char], tokens: &mut Vec<Token>) {
+        let mut i = 0;
+        while i < chars.len() {
+            if self.state == State::BlockComment {
+                i = self.consume_comment(chars, i, "*/", tokens);
+                continue;
+            }
+
+            let c = chars[i];
+            if starts_with(chars, i, "//") {
+                push_chars(tokens, TokenKind::Comment, &chars[i..]);
+                break;
+            } else if starts_with(chars, i, "/*") {
+                push_chars(tokens, TokenKind::Comment, &chars[i..i + 2]);
+                self.state = State::BlockComment;
+                i += 2;
+            } else if c == '"' || c == '\'' || c == '`' {
+                i = consume_string(chars, i, tokens);
+            } else if c.is_ascii_digit() {
+                let end = scan_while(chars, i, |c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+                push_chars(tokens, TokenKind::Number, &chars[i..end]);
+                i = end;
+            } else if is_ident_start(c) {
+                let end = scan_while(chars, i, is_ident_char);
+                let word: String = chars[i..end].iter().collect();
+                let kind = if JS_KEYWORDS.contains(&word.as_str()) {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Plain
+                };
+                push(tokens, kind, &word);
+                i = end;
+            } else {
+                push_chars(tokens, TokenKind::Plain, &chars[i..i + 1]);
+                i += 1;
+            }
+        }
+    }
+
+    fn highlight_css(&mut self, chars: &[char], tokens: &mut Vec<Token>) {
+        let mut i = 0;
+        while i < chars.len() {
+            if self.state == State::BlockComment {
+                i = self.consume_comment(chars, i, "*/", tokens);
+                continue;
+            }
+
+            let c = chars[i];
+            if starts_with(chars, i, "/*") {
+                push_chars(tokens, TokenKind::Comment, &chars[i..i + 2]);
+                self.state = State::BlockComment;
+                i += 2;
+            } else if c == '{' {
+                self.css_depth += 1;
+                push_chars(tokens, TokenKind::Plain, &chars[i..i + 1]);
+                i += 1;
+            } else if c == '}' {
+                self.css_depth = self.css_depth.saturating_sub(1);
+                push_chars(tokens, TokenKind::Plain, &chars[i..i + 1]);
+                i += 1;
+            } else if c == '"' || c == '\'' {
+                i = consume_string(chars, i, tokens);
+            } else if c == '@' {
+                let end = scan_while(chars, i + 1, |c| c.is_ascii_alphanumeric() || c == '-');
+                push_chars(tokens, TokenKind::Keyword, &chars[i..end]);
+                i = end;
+            } else if self.css_depth == 0 {
+                // Selector text
+                let end = scan_while(chars, i, |c| c != '{' && c != '/' && c != '"' && c != '\'');
+                let end = end.max(i + 1);
+                push_chars(tokens, TokenKind::Tag, &chars[i..end]);
+                i = end;
+            } else if c.is_ascii_digit() || (c == '#' && i + 1 < chars.len() && chars[i + 1].is_ascii_hexdigit()) {
+                let end = scan_while(chars, i + 1, |c| c.is_ascii_alphanumeric() || c == '.' || c == '%');
+                push_chars(tokens, TokenKind::Number, &chars[i..end]);
+                i = end;
+            } else if c.is_ascii_alphabetic() || c == '-' {
+                let end = scan_while(chars, i, |c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+                let next = scan_while(chars, end, |c| c == ' ' || c == '\t');
+                let kind = if next < chars.len() && chars[next] == ':' {
+                    TokenKind::Property
+                } else {
+                    TokenKind::Plain
+                };
+                push_chars(tokens, kind, &chars[i..end]);
+                i = end;
+            } else {
+                push_chars(tokens, TokenKind::Plain, &chars[i..i + 1]);
+                i += 1;
+            }
+        }
+    }
+
+    fn highlight_html(&mut self, chars: &[char], tokens: &mut Vec<Token>) {
+        let mut i = 0;
+        while i < chars.len() {
+            match self.state {
+                State::HtmlComment | State::BlockComment => {
+                    i = self.consume_comment(chars, i, "-->", tokens);
+                }
+                State::InTag => {
+                    let c = chars[i];
+                    if c == '>' {
+                        push_chars(tokens, TokenKind::Tag, &chars[i..i + 1]);
+                        self.state = State::Normal;
+                        i += 1;
+                    } else if starts_with(chars, i, "/>") {
+                        push_chars(tokens, TokenKind::Tag, &chars[i..i + 2]);
+                        self.state = State::Normal;
+                        i += 2;
+                    } else if c == '"' || c == '\'' {
+                        i = consume_string(chars, i, tokens);
+                    } else if c.is_whitespace() || c == '=' {
+                        push_chars(tokens, TokenKind::Plain, &chars[i..i + 1]);
+                        i += 1;
+                    } else {
+                        let end = scan_while(chars, i, |c| {
+                            !c.is_whitespace() && c != '=' && c != '>' && c != '/' && c != '"' && c != '\''
+                        });
+                        let end = end.max(i + 1);
+                        push_chars(tokens, TokenKind::Attribute, &chars[i..end]);
+                        i = end;
+                    }
+                }
+                State::Normal => {
+                    if starts_with(chars, i, "<!--") {
+                        push_chars(tokens, TokenKind::Comment, &chars[i..i + 4]);
+                        self.state = State::HtmlComment;
+                        i += 4;
+                    } else if chars[i] == '<' {
+                        let name_start = if i + 1 < chars.len() && (chars[i + 1] == '/' || chars[i + 1] == '!') {
+                            i + 2
+                        } else {
+                            i + 1
+                        };
+                        let end = scan_while(chars, name_start, |c| c.is_ascii_alphanumeric() || c == '-');
+                        push_chars(tokens, TokenKind::Tag, &chars[i..end]);
+                        self.state = State::InTag;
+                        i = end;
+                    } else {
+                        let end = scan_while(chars, i, |c| c != '<');
+                        push_chars(tokens, TokenKind::Plain, &chars[i..end]);
+                        i = end;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Consume comment text up to and including `terminator`, returning the new position
+    fn consume_comment(&mut self, chars: &[char], start: usize, terminator: &str, tokens: &mut Vec<Token>) -> usize {
+        let mut i = start;
+        while i < chars.len() {
+            if starts_with(chars, i, terminator) {
+                let end = i + terminator.chars().count();
+                push_chars(tokens, TokenKind::Comment, &chars[start..end]);
+                self.state = State::Normal;
+                return end;
+            }
+            i += 1;
+        }
+        push_chars(tokens, TokenKind::Comment, &chars[start..]);
+        chars.len()
+    }
+}
+
+fn consume_string(chars: &[char], start: usize, tokens: &mut Vec<Token>) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i] == quote {
+            i += 1;
+            break;
+        }
+        i += 1;
+    }
+    let end = i.min(chars.len());
+    push_chars(tokens, TokenKind::String, &chars[start..end]);
+    end
+}
+
+fn starts_with(chars: &[char], at: usize, pattern: &str) -> bool {
+    let len = pattern.chars().count();
+    at + len <= chars.len() && chars[at..at + len].iter().copied().eq(pattern.chars())
+}
+
+fn scan_while(chars: &[char], start: usize, predicate: impl Fn(char) -> bool) -> usize {
+    let mut i = start;
+    while i < chars.len() && predicate(chars[i]) {
+        i += 1;
+    }
+    i
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+fn push_chars(tokens: &mut Vec<Token>, kind: TokenKind, chars: &[char]) {
+    let text: String = chars.iter().collect();
+    push(tokens, kind, &text);
+}
+
+/// Append text, merging with the previous token when the kind matches
+fn push(tokens: &mut Vec<Token>, kind: TokenKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some((last_kind, last_text)) = tokens.last_mut() {
+        if *last_kind == kind {
+            last_text.push_str(text);
+            return;
+        }
+    }
+    tokens.push((kind, text.to_string()));
+}
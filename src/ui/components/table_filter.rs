@@ -0,0 +1,153 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use std::{cmp::Ordering, time::Duration};
+
+use crate::{
+    app::{keymap, Action},
+    ui::{components::input::AnimatedInput, themes::Theme},
+    utils::fuzzy,
+};
+
+const TABLE_FILTER_ACTIONS: &[Action] =
+    &[Action::Filter, Action::Sort, Action::ReverseSort, Action::OnlyInactive, Action::OnlyErrors];
+
+/// A column a student table can be sorted by
+pub trait SortColumn: Copy + PartialEq + 'static {
+    /// Columns in the order `s` cycles through them
+    const ALL: &'static [Self];
+
+    fn label(&self) -> &'static str;
+}
+
+/// Sorting, a filter prompt and quick toggles for the student tables
+pub struct TableFilter<C: SortColumn> {
+    pub sort: C,
+    pub descending: bool,
+    /// Only students without any commits
+    pub only_inactive: bool,
+    /// Only students whose activity couldn't be fetched
+    pub only_errors: bool,
+    input: AnimatedInput,
+    editing: bool,
+}
+
+impl<C: SortColumn> TableFilter<C> {
+    pub fn new(sort: C) -> Self {
+        let mut input = AnimatedInput::new("Filter by name or GitHub username");
+        input.set_placeholder("Type to filter, fuzzy matches allowed");
+
+        Self {
+            sort,
+            descending: false,
+            only_inactive: false,
+            only_errors: false,
+            input,
+            editing: false,
+        }
+    }
+
+    /// Whether the filter prompt is taking keystrokes
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Rows the bar needs: the prompt while editing, a status line otherwise
+    pub fn height(&self) -> u16 {
+        if self.editing { 3 } else { 1 }
+    }
+
+    /// How well a row matches the filter, from its name and handle; `None` hides the row
+    pub fn score(&self, fields: &[&str]) -> Option<u32> {
+        fuzzy::best_score(self.input.value(), fields)
+    }
+
+    /// Apply the sort direction to an ascending comparison
+    pub fn order(&self, ordering: Ordering) -> Ordering {
+        if self.descending { ordering.reverse() } else { ordering }
+    }
+
+    /// Handle sort, filter and toggle keys; returns whether the key was used
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        if self.editing {
+            match key.code {
+                KeyCode::Esc => {
+                    self.input.set_value(String::new());
+                    self.stop_editing();
+                }
+                KeyCode::Enter => self.stop_editing(),
+                _ => self.input.handle_key_event(key),
+            }
+            return true;
+        }
+
+        match keymap::action_for(TABLE_FILTER_ACTIONS, &key) {
+            Some(Action::Filter) => {
+                self.input.focus();
+                self.editing = true;
+            }
+            Some(Action::Sort) => {
+                let current = C::ALL.iter().position(|column| *column == self.sort).unwrap_or(0);
+                self.sort = C::ALL[(current + 1) % C::ALL.len()];
+            }
+            Some(Action::ReverseSort) => self.descending = !self.descending,
+            Some(Action::OnlyInactive) => self.only_inactive = !self.only_inactive,
+            Some(Action::OnlyErrors) => self.only_errors = !self.only_errors,
+            _ => return false,
+        }
+        true
+    }
+
+    fn stop_editing(&mut self) {
+        self.input.unfocus();
+        self.editing = false;
+    }
+
+    pub fn update(&mut self, delta_time: Duration) {
+        self.input.update(delta_time);
+    }
+
+    /// The filter prompt while editing, otherwise the current sort, filter and row count
+    pub fn render(
+        &self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        theme: &Theme,
+        shown: usize,
+        total: usize,
+    ) {
+        if self.editing {
+            frame.render_widget(&self.input, area);
+            return;
+        }
+
+        let mut spans = vec![
+            Span::styled("Sort: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(
+                format!("{} {}", self.sort.label(), if self.descending { "↓" } else { "↑" }),
+                Style::default().fg(theme.primary).add_modifier(Modifier::BOLD),
+            ),
+        ];
+        let query = self.input.value().trim();
+        if !query.is_empty() {
+            spans.push(Span::styled("  Filter: ", Style::default().fg(theme.text_secondary)));
+            spans.push(Span::styled(format!("\"{}\"", query), Style::default().fg(theme.accent)));
+        }
+        if self.only_inactive {
+            spans.push(Span::styled("  No activity only", Style::default().fg(theme.warning)));
+        }
+        if self.only_errors {
+            spans.push(Span::styled("  Errors only", Style::default().fg(theme.error)));
+        }
+        spans.push(Span::styled(
+            format!("  {} of {} students", shown, total),
+            Style::default().fg(theme.text_secondary),
+        ));
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
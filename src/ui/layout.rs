@@ -25,6 +25,11 @@ impl ResponsiveLayout {
     pub fn is_large_screen(&self) -> bool {
         self.width >= 120 && self.height >= 40
     }
+
+    /// The whole terminal, which overlays are centered in
+    pub fn area(&self) -> Rect {
+        Rect::new(0, 0, self.width, self.height)
+    }
 }
 
 /// Helper function to center a rectangle
@@ -51,4 +56,24 @@ pub fn center_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 /// Create a margin around a rect
 pub fn margin(horizontal: u16, vertical: u16) -> ratatui::layout::Margin {
     ratatui::layout::Margin { horizontal, vertical }
-}
\ No newline at end of file
+}
+
+/// Whether a terminal cell is inside `area`
+pub fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.x + area.width && row >= area.y && row < area.y + area.height
+}
+
+/// Index of the one-line item under a cell in a list drawn in `body`, scrolled by `offset`
+pub fn list_row_at(body: Rect, column: u16, row: u16, offset: usize) -> Option<usize> {
+    contains(body, column, row).then(|| offset + (row - body.y) as usize)
+}
+
+/// Index of the row under a cell in a table drawn in `inner` (inside its block) below a one-line header
+pub fn table_row_at(inner: Rect, column: u16, row: u16, offset: usize) -> Option<usize> {
+    let body = Rect {
+        y: inner.y.saturating_add(1),
+        height: inner.height.saturating_sub(1),
+        ..inner
+    };
+    list_row_at(body, column, row, offset)
+}
@@ -1,6 +1,7 @@
 pub mod animations;
 pub mod components;
 pub mod layout;
+pub mod mouse;
 pub mod screens;
 pub mod themes;
 
@@ -0,0 +1,85 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use std::time::{Duration, Instant};
+
+/// Two left clicks on the same cell within this long make a double click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// A mouse gesture at a terminal cell, after double clicks have been recognised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseAction {
+    Click { column: u16, row: u16 },
+    DoubleClick { column: u16, row: u16 },
+    ScrollUp { column: u16, row: u16 },
+    ScrollDown { column: u16, row: u16 },
+}
+
+impl MouseAction {
+    pub fn position(&self) -> (u16, u16) {
+        match *self {
+            MouseAction::Click { column, row }
+            | MouseAction::DoubleClick { column, row }
+            | MouseAction::ScrollUp { column, row }
+            | MouseAction::ScrollDown { column, row } => (column, row),
+        }
+    }
+}
+
+/// Turns crossterm's raw mouse events into clicks, double clicks and scrolls.
+/// Terminals only report button presses, so double clicks are timed here.
+#[derive(Debug, Default)]
+pub struct ClickTracker {
+    last_click: Option<(Instant, u16, u16)>,
+}
+
+impl ClickTracker {
+    pub fn interpret(&mut self, event: MouseEvent) -> Option<MouseAction> {
+        self.interpret_at(event, Instant::now())
+    }
+
+    fn interpret_at(&mut self, event: MouseEvent, now: Instant) -> Option<MouseAction> {
+        let (column, row) = (event.column, event.row);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let double = self.last_click.is_some_and(|(at, last_column, last_row)| {
+                    (last_column, last_row) == (column, row) && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+                });
+                // A third click starts over instead of making another double click
+                self.last_click = if double { None } else { Some((now, column, row)) };
+                Some(if double {
+                    MouseAction::DoubleClick { column, row }
+                } else {
+                    MouseAction::Click { column, row }
+                })
+            }
+            MouseEventKind::ScrollUp => Some(MouseAction::ScrollUp { column, row }),
+            MouseEventKind::ScrollDown => Some(MouseAction::ScrollDown { column, row }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_double_clicks_need_the_same_cell_and_quick_succession() {
+        let event = |kind, column, row| MouseEvent { kind, column, row, modifiers: KeyModifiers::NONE };
+        let click = |column, row| event(MouseEventKind::Down(MouseButton::Left), column, row);
+        let start = Instant::now();
+        let later = |millis| start + Duration::from_millis(millis);
+        let mut tracker = ClickTracker::default();
+
+        assert_eq!(tracker.interpret_at(click(3, 4), start), Some(MouseAction::Click { column: 3, row: 4 }));
+        assert_eq!(tracker.interpret_at(click(3, 4), later(200)), Some(MouseAction::DoubleClick { column: 3, row: 4 }));
+        assert_eq!(tracker.interpret_at(click(3, 4), later(300)), Some(MouseAction::Click { column: 3, row: 4 }));
+        // Too slow, then on a different cell
+        assert_eq!(tracker.interpret_at(click(3, 4), later(1000)), Some(MouseAction::Click { column: 3, row: 4 }));
+        assert_eq!(tracker.interpret_at(click(3, 5), later(1100)), Some(MouseAction::Click { column: 3, row: 5 }));
+
+        assert_eq!(tracker.interpret_at(event(MouseEventKind::ScrollDown, 1, 1), later(1200)), Some(MouseAction::ScrollDown { column: 1, row: 1 }));
+        assert_eq!(tracker.interpret_at(event(MouseEventKind::Up(MouseButton::Left), 3, 5), later(1300)), None);
+        assert_eq!(tracker.interpret_at(event(MouseEventKind::Moved, 3, 5), later(1400)), None);
+    }
+}
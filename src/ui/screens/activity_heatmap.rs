@@ -0,0 +1,317 @@
+use anyhow::Result;
+use chrono::{Duration as ChronoDuration, NaiveDate};
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{CachedCommits, Class, Student},
+    ui::{
+        animations::AnimationState,
+        components::heatmap::{first_monday, ActivityHeatmap},
+        screens::{Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+/// Roughly one semester
+const SEMESTER_WEEKS: usize = 20;
+
+/// Contribution grid for one student or the whole class, drawn from the commit cache
+pub struct ActivityHeatmapScreen {
+    class: Class,
+    students: Vec<Student>,
+    cache: HashMap<i64, CachedCommits>,
+    /// Index into `students`, or `None` for the class aggregate
+    scope: Option<usize>,
+    end: NaiveDate,
+    cursor: NaiveDate,
+    /// Commits per day for the current scope
+    counts: HashMap<NaiveDate, u32>,
+    /// Students with at least one commit per day for the current scope
+    active_students: HashMap<NaiveDate, u32>,
+}
+
+impl ActivityHeatmapScreen {
+    pub fn new(class: Class, students: Vec<Student>, cache: HashMap<i64, CachedCommits>, student: Option<Student>) -> Self {
+        let scope = student.and_then(|student| students.iter().position(|s| s.id == student.id));
+        let today = time::today();
+        let mut screen = Self {
+            class,
+            students,
+            cache,
+            scope,
+            end: today,
+            cursor: today,
+            counts: HashMap::new(),
+            active_students: HashMap::new(),
+        };
+        screen.recount();
+        screen
+    }
+
+    pub fn set_cache(&mut self, cache: HashMap<i64, CachedCommits>) {
+        self.cache = cache;
+        self.recount();
+    }
+
+    /// Students shown in the current scope
+    pub fn scope_students(&self) -> Vec<Student> {
+        match self.scope {
+            Some(index) => self.students.get(index).cloned().into_iter().collect(),
+            None => self.students.clone(),
+        }
+    }
+
+    fn recount(&mut self) {
+        self.counts.clear();
+        self.active_students.clear();
+        for student in self.scope_students() {
+            let Some(cached) = self.cache.get(&student.id) else {
+                continue;
+            };
+            let days = crate::ui::components::heatmap::count_by_day(&cached.times);
+            for (day, count) in days {
+                *self.counts.entry(day).or_insert(0) += count;
+                *self.active_students.entry(day).or_insert(0) += 1;
+            }
+        }
+    }
+
+    fn start(&self) -> NaiveDate {
+        first_monday(self.end, SEMESTER_WEEKS)
+    }
+
+    fn move_cursor(&mut self, days: i64) {
+        let moved = self.cursor + ChronoDuration::days(days);
+        self.cursor = moved.clamp(self.start(), self.end);
+    }
+
+    fn cycle_scope(&mut self, forward: bool) {
+        let positions = self.students.len() + 1;
+        let current = self.scope.map_or(0, |index| index + 1);
+        let next = if forward { (current + 1) % positions } else { (current + positions - 1) % positions };
+        self.scope = next.checked_sub(1);
+        self.recount();
+    }
+
+    fn scope_name(&self) -> String {
+        match self.scope.and_then(|index| self.students.get(index)) {
+            Some(student) => student.username.clone(),
+            None => format!("{} (all students)", self.class.name),
+        }
+    }
+
+    /// Longest run of consecutive days with commits inside the grid
+    fn longest_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut current = 0;
+        for day in self.start().iter_days().take_while(|day| *day <= self.end) {
+            if self.counts.get(&day).copied().unwrap_or(0) > 0 {
+                current += 1;
+                longest = longest.max(current);
+            } else {
+                current = 0;
+            }
+        }
+        longest
+    }
+
+    fn render_details(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let count = self.counts.get(&self.cursor).copied().unwrap_or(0);
+        let mut cursor_line = vec![
+            Span::styled(self.cursor.format("%a %b %-d, %Y").to_string(), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!(": {} commit{}", count, if count == 1 { "" } else { "s" }),
+                Style::default().fg(theme.text),
+            ),
+        ];
+        if self.scope.is_none() {
+            let active = self.active_students.get(&self.cursor).copied().unwrap_or(0);
+            cursor_line.push(Span::styled(
+                format!(" by {} of {} students", active, self.students.len()),
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+
+        let (total, active_days) = self
+            .start()
+            .iter_days()
+            .take_while(|day| *day <= self.end)
+            .filter_map(|day| self.counts.get(&day))
+            .fold((0, 0), |(total, days), count| (total + count, days + u32::from(*count > 0)));
+        let summary = format!(
+            "{} commits on {} days · longest streak {} days",
+            total,
+            active_days,
+            self.longest_streak()
+        );
+
+        let students = self.scope_students();
+        let cached: Vec<&CachedCommits> = students.iter().filter_map(|student| self.cache.get(&student.id)).collect();
+        let freshness = match cached.iter().map(|cached| cached.updated_at).min() {
+            None => Span::styled(format!("No cached commits yet, press {} to fetch them", keymap::label(&[Action::UpdateHistory])), Style::default().fg(theme.warning)),
+            Some(oldest) if cached.len() < students.len() => Span::styled(
+                format!("Cached for {} of {} students, oldest {}", cached.len(), students.len(), time::format_time_ago(oldest)),
+                Style::default().fg(theme.warning),
+            ),
+            Some(oldest) => Span::styled(
+                format!("Cached {}", time::format_time_ago(oldest)),
+                Style::default().fg(theme.text_secondary),
+            ),
+        };
+
+        let lines = vec![
+            Line::from(cursor_line),
+            Line::from(Span::styled(summary, Style::default().fg(theme.text_secondary))),
+            Line::from(freshness),
+        ];
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+    }
+}
+
+impl Screen for ActivityHeatmapScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        let context = match self.scope.and_then(|index| self.students.get(index)) {
+            Some(student) => ScreenContext::ClassAndStudent(self.class.clone(), student.clone()),
+            None => ScreenContext::Class(self.class.clone()),
+        };
+        ScreenType::new(ScreenTypeVariant::ActivityHeatmap).with_context(context)
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        _state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let result = match key.code {
+            _ if keymap::is(Action::Left, &key) => {
+                self.move_cursor(-7);
+                None
+            }
+            _ if keymap::is(Action::Right, &key) => {
+                self.move_cursor(7);
+                None
+            }
+            _ if keymap::is(Action::Up, &key) => {
+                self.move_cursor(-1);
+                None
+            }
+            _ if keymap::is(Action::Down, &key) => {
+                self.move_cursor(1);
+                None
+            }
+            _ if keymap::is(Action::Top, &key) => {
+                self.cursor = self.end;
+                None
+            }
+            _ if keymap::is(Action::Next, &key) => {
+                self.cycle_scope(true);
+                None
+            }
+            _ if keymap::is(Action::Previous, &key) => {
+                self.cycle_scope(false);
+                None
+            }
+            _ if keymap::is(Action::UpdateHistory, &key) => Some(AppEvent::UpdateCommitCache(self.scope_students())),
+            _ if keymap::is(Action::ThisWeek, &key) => {
+                self.cursor = self.end;
+                None
+            }
+            _ if keymap::is(Action::Select, &key) => self
+                .scope
+                .and_then(|index| self.students.get(index))
+                .map(|student| AppEvent::ShowStudentDetail(student.clone())),
+            _ if keymap::is(Action::Back, &key) => Some(AppEvent::GoBack),
+            _ => None,
+        };
+
+        Box::pin(async { Ok(result) })
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        _delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📈 Activity Heatmap - {}", self.scope_name()))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),     // Spacing
+                Constraint::Length(9),  // Heatmap
+                Constraint::Length(1),  // Spacing
+                Constraint::Length(3),  // Cursor details
+                Constraint::Min(0),     // Spacing
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        // Centre the grid: day labels plus two columns per week
+        let grid_width = (4 + 2 * SEMESTER_WEEKS as u16).min(chunks[1].width);
+        let grid_area = Rect {
+            x: chunks[1].x + (chunks[1].width - grid_width) / 2,
+            width: grid_width,
+            ..chunks[1]
+        };
+        let heatmap = ActivityHeatmap::new(&self.counts, self.end, theme)
+            .weeks(SEMESTER_WEEKS)
+            .cursor(self.cursor);
+        frame.render_widget(heatmap, grid_area);
+
+        self.render_details(frame, chunks[3], theme);
+
+        let mut help = vec![
+            Span::styled(keymap::label(&[Action::Left, Action::Right]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Week  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Day  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Next]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Student/class  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::UpdateHistory]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Update cache  ", Style::default().fg(theme.text_secondary)),
+        ];
+        if self.scope.is_some() {
+            help.push(Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)));
+            help.push(Span::styled(" Details  ", Style::default().fg(theme.text_secondary)));
+        }
+        help.push(Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)));
+        help.push(Span::styled(" Back  ", Style::default().fg(theme.text_secondary)));
+        help.push(Span::styled(keymap::label(&[Action::Help]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)));
+        help.push(Span::styled(" Help", Style::default().fg(theme.text_secondary)));
+        let help = Paragraph::new(Line::from(help))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help, chunks[5]);
+    }
+}
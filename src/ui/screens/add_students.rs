@@ -34,6 +34,10 @@ impl super::Screen for AddStudentsScreen {
         self
     }
 
+    fn captures_text_input(&self) -> bool {
+        true
+    }
+
     fn screen_type(&self) -> super::ScreenType {
         super::ScreenType::new(super::ScreenTypeVariant::AddStudents)
             .with_context(super::ScreenContext::Class(self.class.clone()))
@@ -0,0 +1,631 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{
+        assignments::{format_points, format_rubric, parse_due, parse_rubric, StudentSubmission, Submission},
+        Assignment, Class, RubricCriterion, Snapshot, Student,
+    },
+    preview::short_sha,
+    ui::{
+        animations::AnimationState,
+        mouse::MouseAction,
+        components::{input::AnimatedInput, ConfirmationDialog},
+        screens::{scroll_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+const DATE_FORMAT: &str = "%a %b %d %H:%M";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    List,
+    Create,
+    EditRubric,
+}
+
+pub struct AssignmentsScreen {
+    class: Class,
+    students: Vec<Student>,
+    assignments: Vec<Assignment>,
+    selected: usize,
+    /// Submission results per assignment id, then per student id
+    submissions: HashMap<i64, HashMap<i64, StudentSubmission>>,
+    /// Deadline snapshots per assignment id, then per student id
+    snapshots: HashMap<i64, HashMap<i64, Snapshot>>,
+    /// Rubric criteria per assignment id
+    rubrics: HashMap<i64, Vec<RubricCriterion>>,
+    mode: Mode,
+    inputs: Vec<AnimatedInput>,
+    rubric_input: AnimatedInput,
+    focused_input: usize,
+    error: Option<String>,
+    confirm_delete: ConfirmationDialog,
+}
+
+impl AssignmentsScreen {
+    pub fn new(
+        class: Class,
+        students: Vec<Student>,
+        assignments: Vec<Assignment>,
+        snapshots: Vec<Snapshot>,
+        rubrics: HashMap<i64, Vec<RubricCriterion>>,
+    ) -> Self {
+        let mut inputs = vec![
+            AnimatedInput::new("Assignment Name"),
+            AnimatedInput::new("Due (YYYY-MM-DD HH:MM, local time)"),
+            AnimatedInput::new("Repository ({github} = student's username)"),
+        ];
+        inputs[0].set_placeholder("Project 1: Personal Homepage");
+        inputs[1].set_placeholder("2024-03-01 23:59");
+        let mut rubric_input = AnimatedInput::new("Rubric (Criterion: points, ...)");
+        rubric_input.set_placeholder("Layout: 10, Content: 10, Accessibility: 5");
+
+        let mut screen = Self {
+            class,
+            students,
+            assignments,
+            selected: 0,
+            submissions: HashMap::new(),
+            snapshots: HashMap::new(),
+            rubrics,
+            mode: Mode::List,
+            inputs,
+            rubric_input,
+            focused_input: 0,
+            error: None,
+            confirm_delete: ConfirmationDialog::new("Delete Assignment", ""),
+        };
+        screen.add_snapshots(snapshots);
+        screen
+    }
+
+    pub fn add_snapshots(&mut self, snapshots: Vec<Snapshot>) {
+        for snapshot in snapshots {
+            self.snapshots
+                .entry(snapshot.assignment_id)
+                .or_default()
+                .insert(snapshot.student_id, snapshot);
+        }
+    }
+
+    pub fn set_submissions(&mut self, assignment_id: i64, submissions: Vec<StudentSubmission>) {
+        let by_student = submissions.into_iter().map(|s| (s.student_id, s)).collect();
+        self.submissions.insert(assignment_id, by_student);
+    }
+
+    fn selected_assignment(&self) -> Option<&Assignment> {
+        self.assignments.get(self.selected)
+    }
+
+    fn start_creating(&mut self) {
+        self.inputs[0].set_value(String::new());
+        self.inputs[1].set_value(String::new());
+        self.inputs[2].set_value(Assignment::DEFAULT_REPO_PATTERN.to_string());
+        self.focus_input(0);
+        self.error = None;
+        self.mode = Mode::Create;
+    }
+
+    fn start_editing_rubric(&mut self) {
+        let Some(assignment_id) = self.selected_assignment().map(|a| a.id) else {
+            return;
+        };
+        let current = self.rubrics.get(&assignment_id).map(|rubric| format_rubric(rubric)).unwrap_or_default();
+        self.rubric_input.set_value(current);
+        self.rubric_input.focus();
+        self.error = None;
+        self.mode = Mode::EditRubric;
+    }
+
+    async fn save_rubric(&mut self, state: &AppState) -> Result<()> {
+        let Some(assignment_id) = self.selected_assignment().map(|a| a.id) else {
+            return Ok(());
+        };
+        let criteria = match parse_rubric(self.rubric_input.value()) {
+            Ok(criteria) => criteria,
+            Err(e) => {
+                self.error = Some(e);
+                return Ok(());
+            }
+        };
+
+        match state.database.set_rubric(assignment_id, &criteria).await {
+            Ok(rubric) => {
+                self.rubrics.insert(assignment_id, rubric);
+                self.rubric_input.unfocus();
+                self.mode = Mode::List;
+            }
+            Err(e) => self.error = Some(format!("Failed to save rubric: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn focus_input(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.focused_input = index;
+        self.inputs[index].focus();
+    }
+
+    async fn save_assignment(&mut self, state: &AppState) -> Result<()> {
+        let name = self.inputs[0].value().trim().to_string();
+        let repo_pattern = self.inputs[2].value().trim().to_string();
+
+        if name.is_empty() {
+            self.error = Some("Assignment name cannot be empty".to_string());
+            return Ok(());
+        }
+        let Some(due_at) = parse_due(self.inputs[1].value()) else {
+            self.error = Some("Due date must look like 2024-03-01 23:59".to_string());
+            return Ok(());
+        };
+        if !repo_pattern.contains("{github}") {
+            self.error = Some("Repository must include {github}".to_string());
+            return Ok(());
+        }
+
+        match state.database.create_assignment(self.class.id, &name, due_at, &repo_pattern).await {
+            Ok(assignment) => {
+                let id = assignment.id;
+                self.assignments.push(assignment);
+                self.assignments.sort_by_key(|a| a.due_at);
+                self.selected = self.assignments.iter().position(|a| a.id == id).unwrap_or(0);
+                self.mode = Mode::List;
+            }
+            Err(e) => self.error = Some(format!("Failed to create assignment: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn render_assignments(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Assignments")
+            .border_style(theme.border_focused_style());
+
+        if self.assignments.is_empty() {
+            let paragraph = Paragraph::new(format!("No assignments yet.\n\nPress {} to add one.", keymap::label(&[Action::New])))
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let now = chrono::Utc::now();
+        let items: Vec<ListItem> = self.assignments.iter().map(|assignment| {
+            let due_style = if assignment.due_at < now {
+                Style::default().fg(theme.text_secondary)
+            } else {
+                Style::default().fg(theme.warning)
+            };
+            let rubric = match self.rubrics.get(&assignment.id).filter(|rubric| !rubric.is_empty()) {
+                Some(rubric) => format!(
+                    "  Rubric: {} pts, {} criteria",
+                    format_points(rubric.iter().map(|c| c.max_points).sum()),
+                    rubric.len()
+                ),
+                None => "  No rubric".to_string(),
+            };
+            ListItem::new(vec![
+                Line::from(Span::styled(assignment.name.clone(), Style::default().fg(theme.text).add_modifier(Modifier::BOLD))),
+                Line::from(Span::styled(
+                    format!("  Due {}", time::format(assignment.due_at, DATE_FORMAT)),
+                    due_style,
+                )),
+                Line::from(Span::styled(rubric, Style::default().fg(theme.text_secondary))),
+            ])
+        }).collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background));
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    fn render_submissions(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let Some(assignment) = self.selected_assignment() else {
+            let block = Block::default().borders(Borders::ALL).title("Submissions").border_style(theme.border_style());
+            frame.render_widget(block, area);
+            return;
+        };
+
+        let title = format!("Submissions — {}", assignment.repo_pattern);
+        let block = Block::default().borders(Borders::ALL).title(title).border_style(theme.border_style());
+
+        let submissions = self.submissions.get(&assignment.id);
+        let snapshots = self.snapshots.get(&assignment.id);
+        if submissions.is_none() && snapshots.is_none() {
+            let paragraph = Paragraph::new(format!("Press {} to check submissions against the deadline", keymap::label(&[Action::CheckSubmissions])))
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let format_time = |at: Option<chrono::DateTime<chrono::Utc>>| {
+            at.map(|t| time::format(t, DATE_FORMAT))
+                .unwrap_or_else(|| "-".to_string())
+        };
+
+        let header = Row::new(
+            ["Student", "Status", "Last on time", "Last commit", "Source", "Snapshot"]
+                .into_iter()
+                .map(|title| Cell::from(title).style(Style::default().add_modifier(Modifier::BOLD))),
+        );
+
+        let rows: Vec<Row> = self.students.iter().map(|student| {
+            let snapshot = snapshots
+                .and_then(|snapshots| snapshots.get(&student.id))
+                .map(|snapshot| short_sha(&snapshot.sha).to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let Some(submission) = submissions.and_then(|submissions| submissions.get(&student.id)) else {
+                return Row::new(vec![
+                    Cell::from(student.github_username.clone()),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                    Cell::from(snapshot),
+                ]);
+            };
+
+            let (status, style, last_on_time, last_commit) = match &submission.outcome {
+                Ok(Submission::OnTime { last_commit }) => {
+                    ("On time".to_string(), Style::default().fg(theme.success), Some(*last_commit), Some(*last_commit))
+                }
+                Ok(late @ Submission::Late { last_on_time, last_commit, .. }) => {
+                    (late.label(), Style::default().fg(theme.warning), *last_on_time, Some(*last_commit))
+                }
+                Ok(Submission::Missing) => ("Missing".to_string(), Style::default().fg(theme.error), None, None),
+                Err(e) => (format!("Error: {}", e), Style::default().fg(theme.error), None, None),
+            };
+
+            Row::new(vec![
+                Cell::from(student.github_username.clone()),
+                Cell::from(status).style(style),
+                Cell::from(format_time(last_on_time)),
+                Cell::from(format_time(last_commit)),
+                Cell::from(submission.source.label()).style(Style::default().fg(theme.text_secondary)),
+                Cell::from(snapshot),
+            ])
+        }).collect();
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .widths(&[
+                Constraint::Length(20), // Student
+                Constraint::Length(16), // Status
+                Constraint::Length(17), // Last on-time commit
+                Constraint::Length(17), // Last commit
+                Constraint::Length(11), // Source
+                Constraint::Min(8),     // Snapshot
+            ]);
+
+        frame.render_widget(table, area);
+    }
+
+    fn render_create_form(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(60, 60, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("📝 New Assignment")
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Name
+                Constraint::Length(3), // Due date
+                Constraint::Length(3), // Repository pattern
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        for (input, chunk) in self.inputs.iter().zip(chunks.iter()) {
+            frame.render_widget(input, *chunk);
+        }
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[3]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Next Field  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[5]);
+    }
+
+    fn render_rubric_form(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(70, 40, area);
+        frame.render_widget(Clear, popup_area);
+
+        let title = self.selected_assignment().map(|a| format!("📋 Rubric - {}", a.name)).unwrap_or_default();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Rubric
+                Constraint::Length(2), // Note
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(&self.rubric_input, chunks[0]);
+
+        let note = Paragraph::new("Scores are kept for criteria that keep their name; removed criteria lose their scores.")
+            .style(Style::default().fg(theme.text_secondary))
+            .alignment(Alignment::Center);
+        frame.render_widget(note, chunks[1]);
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[2]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[4]);
+    }
+}
+
+impl Screen for AssignmentsScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.mode != Mode::List
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::Assignments)
+            .with_context(ScreenContext::Class(self.class.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.confirm_delete.is_visible() {
+                if let Some(true) = self.confirm_delete.handle_key_event(key) {
+                    if let Some(assignment) = self.assignments.get(self.selected).cloned() {
+                        match state.database.delete_assignment(assignment.id).await {
+                            Ok(_) => {
+                                self.assignments.retain(|a| a.id != assignment.id);
+                                self.submissions.remove(&assignment.id);
+                                self.snapshots.remove(&assignment.id);
+                                self.selected = self.selected.min(self.assignments.len().saturating_sub(1));
+                            }
+                            Err(e) => self.error = Some(format!("Failed to delete assignment: {}", e)),
+                        }
+                    }
+                }
+                return Ok(None);
+            }
+
+            if self.mode == Mode::List {
+                self.error = None;
+            }
+            match self.mode {
+                Mode::List => match key.code {
+                    _ if keymap::is(Action::Up, &key) => self.selected = self.selected.saturating_sub(1),
+                    _ if keymap::is(Action::Down, &key) && self.selected + 1 < self.assignments.len() => {
+                        self.selected += 1;
+                    }
+                    _ if keymap::is(Action::Select, &key) || keymap::is(Action::CheckSubmissions, &key) => {
+                        return Ok(self.selected_assignment().map(|a| AppEvent::CheckAssignment(a.id)));
+                    }
+                    _ if keymap::is(Action::Snapshot, &key) => {
+                        return Ok(self.selected_assignment().map(|a| AppEvent::SnapshotAssignment(a.id)));
+                    }
+                    _ if keymap::is(Action::RestoreLatest, &key) => return Ok(Some(AppEvent::RestoreLatest)),
+                    _ if keymap::is(Action::EditRubric, &key) => self.start_editing_rubric(),
+                    _ if keymap::is(Action::Grade, &key) => {
+                        if let Some(assignment) = self.selected_assignment() {
+                            return Ok(Some(AppEvent::NavigateToScreen(
+                                ScreenType::new(ScreenTypeVariant::Grading)
+                                    .with_context(ScreenContext::ClassAndAssignment(self.class.clone(), assignment.clone())),
+                            )));
+                        }
+                    }
+                    _ if keymap::is(Action::Export, &key) => return Ok(Some(AppEvent::ExportGradebook)),
+                    _ if keymap::is(Action::New, &key) => self.start_creating(),
+                    _ if keymap::is(Action::Delete, &key) => {
+                        if let Some(assignment) = self.selected_assignment() {
+                            self.confirm_delete = ConfirmationDialog::new(
+                                "Delete Assignment",
+                                &format!("Delete '{}'? Student repositories are not affected.", assignment.name),
+                            ).with_yes_text("Delete").with_no_text("Cancel");
+                            self.confirm_delete.show();
+                        }
+                    }
+                    _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                    _ => {}
+                },
+                Mode::Create => match key.code {
+                    KeyCode::Esc => self.mode = Mode::List,
+                    KeyCode::Tab | KeyCode::Down => self.focus_input((self.focused_input + 1) % self.inputs.len()),
+                    KeyCode::BackTab | KeyCode::Up => {
+                        self.focus_input((self.focused_input + self.inputs.len() - 1) % self.inputs.len())
+                    }
+                    KeyCode::Enter => self.save_assignment(state).await?,
+                    _ => {
+                        self.inputs[self.focused_input].handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+                Mode::EditRubric => match key.code {
+                    KeyCode::Esc => {
+                        self.rubric_input.unfocus();
+                        self.mode = Mode::List;
+                    }
+                    KeyCode::Enter => self.save_rubric(state).await?,
+                    _ => {
+                        self.rubric_input.handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirm_delete.is_visible() {
+            self.confirm_delete.key_for_click(mouse)
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        for input in &mut self.inputs {
+            input.update(delta_time);
+        }
+        self.rubric_input.update(delta_time);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📅 Assignments - {}", self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),     // Assignments and submissions
+                Constraint::Length(3),  // Help text
+            ])
+            .split(inner_area);
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(70),
+            ])
+            .split(chunks[0]);
+
+        self.render_assignments(frame, panes[0], theme);
+        self.render_submissions(frame, panes[1], theme);
+
+        let help_text = vec![
+            Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::CheckSubmissions]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Check Submissions  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Snapshot]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Snapshot at Deadline  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::RestoreLatest]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back to Latest  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+            ]),
+            Line::from(vec![
+                Span::styled(keymap::label(&[Action::EditRubric]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Rubric  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Grade]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Grade  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Export]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Export Gradebook  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::New]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" New  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Delete]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Delete", Style::default().fg(theme.text_secondary)),
+            ]),
+        ];
+        // A failed delete replaces the help until the next key press
+        let help_paragraph = match (&self.mode, &self.error) {
+            (Mode::List, Some(error)) => Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error))),
+            _ => Paragraph::new(help_text),
+        };
+        let help_paragraph = help_paragraph
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[1]);
+
+        match self.mode {
+            Mode::Create => self.render_create_form(frame, area, theme),
+            Mode::EditRubric => self.render_rubric_form(frame, area, theme),
+            Mode::List => {}
+        }
+        self.confirm_delete.render(frame, area, theme);
+    }
+}
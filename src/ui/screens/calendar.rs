@@ -0,0 +1,633 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use std::{future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{
+        calendar::{parse_ics, parse_span, IcsEvent, SchoolCalendar},
+        CalendarEntry, Class, DayKind,
+    },
+    ui::{
+        animations::AnimationState,
+        mouse::MouseAction,
+        components::{input::AnimatedInput, ConfirmationDialog},
+        screens::{scroll_key, table_mouse_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    List,
+    /// Adding a day off, or a make-up school day when `school_day` is set
+    Add { school_day: bool },
+    Import,
+    /// Choosing which events of the read file to import
+    Preview,
+}
+
+pub struct CalendarScreen {
+    class: Class,
+    calendar: SchoolCalendar,
+    table_state: TableState,
+    /// The table's area inside its block when last drawn, for mouse hit-testing
+    table_area: Rect,
+    mode: Mode,
+    inputs: Vec<AnimatedInput>,
+    focused_input: usize,
+    path_input: AnimatedInput,
+    /// Events read from the file and whether each will be imported
+    preview: Vec<(IcsEvent, bool)>,
+    preview_state: TableState,
+    error: Option<String>,
+    message: Option<String>,
+    confirm_delete: ConfirmationDialog,
+}
+
+impl CalendarScreen {
+    pub fn new(class: Class, calendar: SchoolCalendar) -> Self {
+        let mut inputs = vec![
+            AnimatedInput::new("Name"),
+            AnimatedInput::new("Dates (YYYY-MM-DD or YYYY-MM-DD..YYYY-MM-DD)"),
+        ];
+        inputs[0].set_placeholder("Winter Break");
+        inputs[1].set_placeholder("2024-12-23..2025-01-03");
+        let mut path_input = AnimatedInput::new("Path to .ics file");
+        path_input.set_placeholder("~/Downloads/school-calendar.ics");
+
+        // Start on the first entry that hasn't ended yet
+        let today = time::today();
+        let upcoming = calendar.entries().iter().position(|entry| entry.end >= today);
+        let mut table_state = TableState::default();
+        table_state.select(upcoming.or(calendar.entries().len().checked_sub(1)));
+
+        Self {
+            class,
+            calendar,
+            table_state,
+            table_area: Rect::default(),
+            mode: Mode::List,
+            inputs,
+            focused_input: 0,
+            path_input,
+            preview: Vec::new(),
+            preview_state: TableState::default(),
+            error: None,
+            message: None,
+            confirm_delete: ConfirmationDialog::new("Delete Entry", ""),
+        }
+    }
+
+    fn selected_entry(&self) -> Option<&CalendarEntry> {
+        self.table_state.selected().and_then(|i| self.calendar.entries().get(i))
+    }
+
+    fn focus_input(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.focused_input = index;
+        self.inputs[index].focus();
+    }
+
+    fn start_adding(&mut self, school_day: bool) {
+        for input in &mut self.inputs {
+            input.set_value(String::new());
+        }
+        self.focus_input(0);
+        self.error = None;
+        self.mode = Mode::Add { school_day };
+    }
+
+    fn start_import(&mut self) {
+        self.path_input.focus();
+        self.error = None;
+        self.mode = Mode::Import;
+    }
+
+    fn close_form(&mut self) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.path_input.unfocus();
+        self.preview.clear();
+        self.mode = Mode::List;
+    }
+
+    /// Reload the calendar and keep the cursor on `id` if it's still there
+    async fn reload(&mut self, state: &AppState, id: Option<i64>) {
+        match state.database.get_calendar(self.class.id).await {
+            Ok(calendar) => self.calendar = calendar,
+            Err(e) => {
+                self.error = Some(format!("Failed to reload the calendar: {}", e));
+                return;
+            }
+        }
+        let len = self.calendar.entries().len();
+        let index = id
+            .and_then(|id| self.calendar.entries().iter().position(|entry| entry.id == id))
+            .or_else(|| self.table_state.selected().map(|i| i.min(len.saturating_sub(1))));
+        self.table_state.select(if len == 0 { None } else { index.or(Some(0)) });
+    }
+
+    async fn save_entry(&mut self, state: &AppState, school_day: bool) {
+        let name = self.inputs[0].value().trim().to_string();
+        if name.is_empty() {
+            self.error = Some("Name cannot be empty".to_string());
+            return;
+        }
+        let (start, end) = match parse_span(self.inputs[1].value()) {
+            Ok(span) => span,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                return;
+            }
+        };
+        let kind = match (school_day, start == end) {
+            (true, _) => DayKind::SchoolDay,
+            (false, true) => DayKind::Holiday,
+            (false, false) => DayKind::Break,
+        };
+
+        match state.database.add_calendar_entry(self.class.id, &name, start, end, kind).await {
+            Ok(entry) => {
+                self.close_form();
+                self.reload(state, Some(entry.id)).await;
+            }
+            Err(e) => self.error = Some(format!("Failed to save: {}", e)),
+        }
+    }
+
+    /// Read the file and show its events to choose from. Whole-day events start ticked;
+    /// timed ones are usually meetings and assemblies, so they start unticked.
+    async fn read_import_file(&mut self) {
+        let raw = self.path_input.value().trim().to_string();
+        let path = match (raw.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => std::path::PathBuf::from(&raw),
+        };
+
+        let events = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => parse_ics(&contents),
+            Err(e) => Err(anyhow::anyhow!("Could not read {}: {}", path.display(), e)),
+        };
+        match events {
+            Ok(events) if events.is_empty() => self.error = Some("The file has no events".to_string()),
+            Ok(events) => {
+                self.path_input.unfocus();
+                self.preview = events.into_iter().map(|event| {
+                    let all_day = event.all_day;
+                    (event, all_day)
+                }).collect();
+                self.preview_state.select(Some(0));
+                self.mode = Mode::Preview;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    /// Add the ticked events as days off
+    async fn import_selected(&mut self, state: &AppState) {
+        let events: Vec<IcsEvent> = self.preview.iter().filter(|(_, ticked)| *ticked).map(|(event, _)| event.clone()).collect();
+        if events.is_empty() {
+            self.error = Some("Tick at least one event to import".to_string());
+            return;
+        }
+        match state.database.import_calendar_events(self.class.id, &events).await {
+            Ok(added) => {
+                let read = self.preview.len();
+                self.close_form();
+                self.reload(state, None).await;
+                self.message = Some(format!(
+                    "Imported {} of {} events ({} already on the calendar, {} left out)",
+                    added, read, events.len() - added, read - events.len()
+                ));
+            }
+            Err(e) => self.error = Some(format!("Failed to import: {}", e)),
+        }
+    }
+
+    fn format_span(entry: &CalendarEntry) -> String {
+        if entry.start == entry.end {
+            entry.start.format("%a %b %-d, %Y").to_string()
+        } else if entry.start.format("%Y").to_string() == entry.end.format("%Y").to_string() {
+            format!("{} – {}", entry.start.format("%b %-d"), entry.end.format("%b %-d, %Y"))
+        } else {
+            format!("{} – {}", entry.start.format("%b %-d, %Y"), entry.end.format("%b %-d, %Y"))
+        }
+    }
+
+    /// "Today" line: whether there is school and when the next day off is
+    fn render_summary(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let today = time::today();
+        let status = if self.calendar.is_school_day(today) {
+            Span::styled("Today is a school day", Style::default().fg(theme.success))
+        } else {
+            let reason = self.calendar.closure(today).map(|entry| entry.name.clone()).unwrap_or_else(|| "Weekend".to_string());
+            Span::styled(format!("No school today ({})", reason), Style::default().fg(theme.warning))
+        };
+        let next_off = self
+            .calendar
+            .entries()
+            .iter()
+            .find(|entry| entry.kind != DayKind::SchoolDay && entry.start > today)
+            .map(|entry| format!("   Next day off: {} ({})", entry.name, entry.start.format("%a %b %-d")))
+            .unwrap_or_default();
+
+        let mut lines = vec![Line::from(vec![status, Span::styled(next_off, Style::default().fg(theme.text_secondary))])];
+        if let Some(error) = self.error.as_ref().filter(|_| self.mode == Mode::List) {
+            lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(theme.error))));
+        } else if let Some(message) = &self.message {
+            lines.push(Line::from(Span::styled(message.clone(), Style::default().fg(theme.info))));
+        }
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn render_entries(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Holidays, Breaks and Make-up Days")
+            .border_style(theme.border_focused_style());
+
+        if self.calendar.entries().is_empty() {
+            let paragraph = Paragraph::new(format!(
+                "No calendar entries yet. Weekdays count as school days.\n\nPress {} to add a day off or {} to import an .ics file.",
+                keymap::label(&[Action::New]),
+                keymap::label(&[Action::Import])
+            ))
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let today = time::today();
+        let rows: Vec<Row> = self.calendar.entries().iter().map(|entry| {
+            let style = if entry.end < today {
+                Style::default().fg(theme.text_secondary)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let kind_style = match entry.kind {
+                DayKind::SchoolDay => Style::default().fg(theme.success),
+                _ if entry.end < today => style,
+                _ => Style::default().fg(theme.warning),
+            };
+            let days = (entry.end - entry.start).num_days() + 1;
+            Row::new(vec![
+                Cell::from(Self::format_span(entry)),
+                Cell::from(days.to_string()),
+                Cell::from(entry.kind.label()).style(kind_style),
+                Cell::from(entry.name.clone()),
+            ])
+            .style(style)
+        }).collect();
+
+        let header = Row::new(vec!["Dates", "Days", "Type", "Name"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD));
+        self.table_area = block.inner(area);
+        let table = Table::new(rows)
+            .header(header)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
+            .highlight_symbol("▶ ")
+            .widths(&[
+                Constraint::Length(30),
+                Constraint::Length(5),
+                Constraint::Length(11),
+                Constraint::Min(10),
+            ]);
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn render_form(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(60, 40, area);
+        frame.render_widget(Clear, popup_area);
+
+        let (title, fields): (&str, Vec<&AnimatedInput>) = match self.mode {
+            Mode::Add { school_day: true } => ("🏫 Add Make-up School Day", self.inputs.iter().collect()),
+            Mode::Add { school_day: false } => ("🏖️ Add Day Off", self.inputs.iter().collect()),
+            _ => ("📥 Import Calendar (.ics)", vec![&self.path_input]),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let mut constraints: Vec<Constraint> = fields.iter().map(|_| Constraint::Length(3)).collect();
+        constraints.extend([
+            Constraint::Length(2), // Error message
+            Constraint::Min(0),    // Spacing
+            Constraint::Length(1), // Help text
+        ]);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        for (input, chunk) in fields.iter().zip(chunks.iter()) {
+            frame.render_widget(*input, *chunk);
+        }
+
+        let error_area = chunks[fields.len()];
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, error_area);
+        } else if self.mode == Mode::Import {
+            let note = Paragraph::new("You can choose which events to import as days off next.")
+                .style(Style::default().fg(theme.text_secondary))
+                .alignment(Alignment::Center);
+            frame.render_widget(note, error_area);
+        }
+
+        let mut help = Vec::new();
+        if fields.len() > 1 {
+            help.push(Span::styled("Tab", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)));
+            help.push(Span::styled(" Next Field  ", Style::default().fg(theme.text_secondary)));
+        }
+        help.extend([
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(if self.mode == Mode::Import { " Read File  " } else { " Save  " }, Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(Line::from(help)).alignment(Alignment::Center), chunks[fields.len() + 2]);
+    }
+
+    fn render_preview(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(80, 70, area);
+        frame.render_widget(Clear, popup_area);
+
+        let ticked = self.preview.iter().filter(|(_, ticked)| *ticked).count();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📥 Import {} of {} Events", ticked, self.preview.len()))
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),    // Events
+                Constraint::Length(1), // Error message
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        let rows: Vec<Row> = self.preview.iter().map(|(event, ticked)| {
+            let style = if *ticked { Style::default().fg(theme.text) } else { Style::default().fg(theme.text_secondary) };
+            let entry = CalendarEntry {
+                id: 0,
+                class_id: self.class.id,
+                name: event.name.clone(),
+                start: event.start,
+                end: event.end,
+                kind: event.kind(),
+            };
+            Row::new(vec![
+                Cell::from(if *ticked { "[x]" } else { "[ ]" }),
+                Cell::from(Self::format_span(&entry)),
+                Cell::from(if event.all_day { "All day" } else { "Timed" }),
+                Cell::from(event.name.clone()),
+            ])
+            .style(style)
+        }).collect();
+        let table = Table::new(rows)
+            .header(Row::new(vec!["", "Dates", "Length", "Name"]).style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)))
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
+            .widths(&[
+                Constraint::Length(3),
+                Constraint::Length(30),
+                Constraint::Length(7),
+                Constraint::Min(10),
+            ]);
+        frame.render_stateful_widget(table, chunks[0], &mut self.preview_state);
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[1]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::ToggleSelection]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Tick  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::SelectAll, Action::SelectNone]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" All/none  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Import Ticked  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[2]);
+    }
+}
+
+impl Screen for CalendarScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        matches!(self.mode, Mode::Add { .. } | Mode::Import)
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::SchoolCalendar)
+            .with_context(ScreenContext::Class(self.class.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.confirm_delete.is_visible() {
+                if let Some(true) = self.confirm_delete.handle_key_event(key) {
+                    if let Some(id) = self.selected_entry().map(|entry| entry.id) {
+                        match state.database.delete_calendar_entry(id).await {
+                            Ok(_) => self.reload(state, None).await,
+                            Err(e) => self.error = Some(format!("Failed to delete: {}", e)),
+                        }
+                    }
+                }
+                return Ok(None);
+            }
+
+            match self.mode {
+                Mode::List => {
+                    self.message = None;
+                    self.error = None;
+                    let len = self.calendar.entries().len();
+                    let selected = self.table_state.selected().unwrap_or(0);
+                    match key.code {
+                        _ if keymap::is(Action::Up, &key) && len > 0 => {
+                            self.table_state.select(Some(selected.saturating_sub(1)));
+                        }
+                        _ if keymap::is(Action::Down, &key) && selected + 1 < len => {
+                            self.table_state.select(Some(selected + 1));
+                        }
+                        _ if keymap::is(Action::New, &key) => self.start_adding(false),
+                        _ if keymap::is(Action::AddSchoolDay, &key) => self.start_adding(true),
+                        _ if keymap::is(Action::Import, &key) => self.start_import(),
+                        _ if keymap::is(Action::Delete, &key) => {
+                            if let Some(entry) = self.selected_entry() {
+                                self.confirm_delete = ConfirmationDialog::new(
+                                    "Delete Entry",
+                                    &format!("Remove '{}' from the calendar?", entry.name),
+                                ).with_yes_text("Delete").with_no_text("Cancel");
+                                self.confirm_delete.show();
+                            }
+                        }
+                        _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                        _ => {}
+                    }
+                }
+                Mode::Add { school_day } => match key.code {
+                    KeyCode::Esc => self.close_form(),
+                    KeyCode::Tab | KeyCode::Down => self.focus_input((self.focused_input + 1) % self.inputs.len()),
+                    KeyCode::BackTab | KeyCode::Up => {
+                        self.focus_input((self.focused_input + self.inputs.len() - 1) % self.inputs.len())
+                    }
+                    KeyCode::Enter => self.save_entry(state, school_day).await,
+                    _ => {
+                        self.inputs[self.focused_input].handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+                Mode::Import => match key.code {
+                    KeyCode::Esc => self.close_form(),
+                    KeyCode::Enter => self.read_import_file().await,
+                    _ => {
+                        self.path_input.handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+                Mode::Preview => {
+                    self.error = None;
+                    let selected = self.preview_state.selected().unwrap_or(0);
+                    match key.code {
+                        _ if keymap::is(Action::Back, &key) => self.close_form(),
+                        _ if keymap::is(Action::Select, &key) => self.import_selected(state).await,
+                        _ if keymap::is(Action::Up, &key) => self.preview_state.select(Some(selected.saturating_sub(1))),
+                        _ if keymap::is(Action::Down, &key) && selected + 1 < self.preview.len() => {
+                            self.preview_state.select(Some(selected + 1));
+                        }
+                        _ if keymap::is(Action::ToggleSelection, &key) => {
+                            if let Some((_, ticked)) = self.preview.get_mut(selected) {
+                                *ticked = !*ticked;
+                            }
+                        }
+                        _ if keymap::is(Action::SelectAll, &key) => self.preview.iter_mut().for_each(|(_, ticked)| *ticked = true),
+                        _ if keymap::is(Action::SelectNone, &key) => self.preview.iter_mut().for_each(|(_, ticked)| *ticked = false),
+                        _ => {}
+                    }
+                }
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirm_delete.is_visible() {
+            self.confirm_delete.key_for_click(mouse)
+        } else if self.mode == Mode::List {
+            let rows = self.calendar.entries().len();
+            table_mouse_key(&mut self.table_state, self.table_area, rows, mouse, None)
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        for input in &mut self.inputs {
+            input.update(delta_time);
+        }
+        self.path_input.update(delta_time);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🗓️ School Calendar - {}", self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Summary
+                Constraint::Min(5),    // Entries
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner_area);
+
+        self.render_summary(frame, chunks[0], theme);
+        self.render_entries(frame, chunks[1], theme);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::New]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Day off  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::AddSchoolDay]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Make-up day  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Import]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Import .ics  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Delete]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Delete  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+        ]);
+        let help = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help, chunks[2]);
+
+        match self.mode {
+            Mode::List => {}
+            Mode::Preview => self.render_preview(frame, area, theme),
+            _ => self.render_form(frame, area, theme),
+        }
+        self.confirm_delete.render(frame, area, theme);
+    }
+}
@@ -0,0 +1,564 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{
+        alerts::{self, Alert, StudentStatus},
+        calendar::SchoolCalendar,
+        metrics::{self, StudentMetrics},
+        AlertThresholds, CachedCommits, Class, MetricSettings, Student,
+    },
+    git::GitManager,
+    ui::{
+        animations::AnimationState,
+        mouse::MouseAction,
+        components::input::AnimatedInput,
+        screens::{scroll_key, table_mouse_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+/// Landing screen for a class: who needs a check-in, most urgent first
+pub struct ClassDashboardScreen {
+    class: Class,
+    students: Vec<Student>,
+    calendar: SchoolCalendar,
+    thresholds: AlertThresholds,
+    metric_settings: MetricSettings,
+    /// Stored commit history per student id; alerts are worked out from this, not fetched on every visit
+    cache: HashMap<i64, CachedCommits>,
+    /// Work-habit metrics per student id, for students with cached commits
+    metrics: HashMap<i64, StudentMetrics>,
+    /// Status per student id, worked out by `assess_students`
+    statuses: HashMap<i64, StudentStatus>,
+    /// Set when the history or settings change, so the next update re-checks every student
+    needs_assessment: bool,
+    table_state: TableState,
+    /// The table's area inside its block when last drawn, for mouse hit-testing
+    table_area: Rect,
+    editing_thresholds: bool,
+    inputs: Vec<AnimatedInput>,
+    focused_input: usize,
+    error: Option<String>,
+}
+
+impl ClassDashboardScreen {
+    pub fn new(
+        class: Class,
+        students: Vec<Student>,
+        calendar: SchoolCalendar,
+        thresholds: AlertThresholds,
+        metric_settings: MetricSettings,
+        cache: HashMap<i64, CachedCommits>,
+    ) -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(if students.is_empty() { None } else { Some(0) });
+
+        Self {
+            class,
+            students,
+            calendar,
+            thresholds,
+            metric_settings,
+            cache,
+            metrics: HashMap::new(),
+            statuses: HashMap::new(),
+            needs_assessment: true,
+            table_state,
+            table_area: Rect::default(),
+            editing_thresholds: false,
+            inputs: vec![
+                AnimatedInput::new("Inactive after N school days without commits"),
+                AnimatedInput::new("Declining when weekly commits drop by (%)"),
+                AnimatedInput::new("Commits for a day to count as active"),
+                AnimatedInput::new("Active school days expected per week"),
+                AnimatedInput::new("Weeks in the consistency score"),
+            ],
+            focused_input: 0,
+            error: None,
+        }
+    }
+
+    /// Swap in freshly fetched commit history
+    pub fn set_cache(&mut self, cache: HashMap<i64, CachedCommits>) {
+        self.cache = cache;
+        self.needs_assessment = true;
+    }
+
+    /// Work out metrics and alerts for every student from the stored history
+    fn assess_students(&mut self, git_manager: &GitManager) {
+        let today = time::today();
+        self.metrics = metrics::for_class(&self.cache, &self.calendar, &self.metric_settings, today);
+        self.statuses = self
+            .students
+            .iter()
+            .map(|student| {
+                let cloned = git_manager.repo_exists(&student.github_username, &self.class.name);
+                let status = alerts::assess(self.cache.get(&student.id), cloned, &self.calendar, &self.thresholds, today);
+                (student.id, status)
+            })
+            .collect();
+        self.needs_assessment = false;
+    }
+
+    /// Students ordered by their most urgent alert, then by name
+    fn ordered_students(&self) -> Vec<&Student> {
+        let mut students: Vec<&Student> = self.students.iter().collect();
+        students.sort_by_key(|student| {
+            let severity = self.statuses.get(&student.id).and_then(StudentStatus::severity);
+            (std::cmp::Reverse(severity), student.username.to_lowercase())
+        });
+        students
+    }
+
+    fn selected_student(&self) -> Option<&Student> {
+        self.table_state.selected().and_then(|i| self.ordered_students().get(i).copied())
+    }
+
+    fn start_editing_thresholds(&mut self) {
+        self.inputs[0].set_value(self.thresholds.inactive_school_days.to_string());
+        self.inputs[1].set_value(self.thresholds.decline_percent.to_string());
+        self.inputs[2].set_value(self.metric_settings.min_daily_commits.to_string());
+        self.inputs[3].set_value(self.metric_settings.weekly_target_days.to_string());
+        self.inputs[4].set_value(self.metric_settings.consistency_weeks.to_string());
+        self.focus_input(0);
+        self.error = None;
+        self.editing_thresholds = true;
+    }
+
+    fn focus_input(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.focused_input = index;
+        self.inputs[index].focus();
+    }
+
+    /// Save the thresholds and metric definitions, then re-check every student against them
+    async fn save_thresholds(&mut self, state: &AppState) -> Result<Option<AppEvent>> {
+        let inactive_school_days = match self.inputs[0].value().trim().parse::<u32>() {
+            Ok(days) if days > 0 => days,
+            _ => {
+                self.error = Some("School days must be a whole number above zero".to_string());
+                return Ok(None);
+            }
+        };
+        let decline_percent = match self.inputs[1].value().trim().trim_end_matches('%').parse::<u32>() {
+            Ok(percent) if (1..=100).contains(&percent) => percent,
+            _ => {
+                self.error = Some("Decline must be a percentage from 1 to 100".to_string());
+                return Ok(None);
+            }
+        };
+
+        let mut counts = Vec::new();
+        for (input, (label, max)) in self.inputs[2..].iter().zip([("Commits per day", 100), ("Days per week", 7), ("Weeks", 52)]) {
+            match input.value().trim().parse::<u32>() {
+                Ok(value) if (1..=max).contains(&value) => counts.push(value),
+                _ => {
+                    self.error = Some(format!("{} must be a whole number from 1 to {}", label, max));
+                    return Ok(None);
+                }
+            }
+        }
+        let metric_settings = MetricSettings {
+            min_daily_commits: counts[0],
+            weekly_target_days: counts[1],
+            consistency_weeks: counts[2],
+        };
+
+        let thresholds = AlertThresholds { inactive_school_days, decline_percent };
+        if let Err(e) = state.database.set_alert_thresholds(self.class.id, &thresholds).await {
+            self.error = Some(format!("Failed to save thresholds: {}", e));
+            return Ok(None);
+        }
+        if let Err(e) = state.database.set_metric_settings(self.class.id, &metric_settings).await {
+            self.error = Some(format!("Failed to save metric definitions: {}", e));
+            return Ok(None);
+        }
+        self.thresholds = thresholds;
+        self.metric_settings = metric_settings;
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.editing_thresholds = false;
+        self.needs_assessment = true;
+        Ok(None)
+    }
+
+    /// Class averages and how the metrics are defined for this class
+    fn metrics_summary(&self) -> String {
+        let definition = format!(
+            "Active day: {}+ commits · Consistency: {} active school days a week over {} weeks",
+            self.metric_settings.min_daily_commits, self.metric_settings.weekly_target_days, self.metric_settings.consistency_weeks
+        );
+        let metrics: Vec<&StudentMetrics> = self
+            .students
+            .iter()
+            .filter_map(|student| self.metrics.get(&student.id))
+            .collect();
+        if metrics.is_empty() {
+            return format!("{} · No stored commit history yet, update it from the heatmap", definition);
+        }
+        let average = metrics.iter().map(|m| m.consistency).sum::<u32>() / metrics.len() as u32;
+        let streaking = metrics.iter().filter(|m| m.current_streak > 0).count();
+        format!(
+            "{} · Class average {}% · {} of {} on a streak",
+            definition,
+            average,
+            streaking,
+            self.students.len()
+        )
+    }
+
+    fn render_summary(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let flagged = self.statuses.values().filter(|status| !status.alerts.is_empty()).count();
+        let headline = if flagged == 0 {
+            Span::styled(format!("All {} students are on track", self.students.len()), Style::default().fg(theme.success).add_modifier(Modifier::BOLD))
+        } else {
+            Span::styled(
+                format!("{} of {} students need a check-in", flagged, self.students.len()),
+                Style::default().fg(theme.warning).add_modifier(Modifier::BOLD),
+            )
+        };
+
+        let today = if self.calendar.is_school_day(time::today()) { "" } else { "   No school today" };
+        // The oldest fetch is how stale the least fresh student is
+        let history = match self.cache.values().map(|cached| cached.updated_at).min() {
+            Some(updated_at) => format!("   History updated {}, {} to update", time::format_time_ago(updated_at), keymap::label(&[Action::Refresh])),
+            None => format!("   No stored history yet, {} to fetch it", keymap::label(&[Action::Refresh])),
+        };
+        let lines = vec![
+            Line::from(vec![
+                headline,
+                Span::styled(today, Style::default().fg(theme.text_secondary)),
+                Span::styled(history, Style::default().fg(theme.text_secondary)),
+            ]),
+            Line::from(Span::styled(
+                format!(
+                    "Inactive after {} school days without commits · Declining when commits over the last {} school days drop {}% from the {} before",
+                    self.thresholds.inactive_school_days, alerts::TREND_SCHOOL_DAYS, self.thresholds.decline_percent, alerts::TREND_SCHOOL_DAYS
+                ),
+                Style::default().fg(theme.text_secondary),
+            )),
+            Line::from(Span::styled(self.metrics_summary(), Style::default().fg(theme.text_secondary))),
+        ];
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn render_students(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Students")
+            .border_style(theme.border_focused_style());
+
+        if self.students.is_empty() {
+            let paragraph = Paragraph::new(format!(
+                "No students in this class yet.\n\nPress {} for the class menu to add some.",
+                keymap::label(&[Action::OpenClassMenu])
+            ))
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let rows: Vec<Row> = self.ordered_students().into_iter().map(|student| {
+            let Some(status) = self.statuses.get(&student.id) else {
+                return Row::new(vec![
+                    Cell::from(student.username.clone()),
+                    Cell::from("…"),
+                    Cell::from(""),
+                    Cell::from(""),
+                    Cell::from(""),
+                    Cell::from(""),
+                    Cell::from(Span::styled("Loading", Style::default().fg(theme.text_secondary))),
+                ]);
+            };
+
+            let last_commit = status
+                .latest_commit
+                .map(time::format_time_ago)
+                .unwrap_or_else(|| "-".to_string());
+            let flags = if status.alerts.is_empty() {
+                Line::from(Span::styled("✅ On track", Style::default().fg(theme.success)))
+            } else {
+                let spans: Vec<Span> = status.alerts.iter().enumerate().flat_map(|(i, alert)| {
+                    let color = match alert {
+                        Alert::RepoMissing | Alert::Inactive { .. } => theme.error,
+                        Alert::Declining { .. } | Alert::NotCloned => theme.warning,
+                        Alert::NotFetched => theme.text_secondary,
+                    };
+                    let separator = if i == 0 { "" } else { " · " };
+                    [
+                        Span::styled(separator, Style::default().fg(theme.text_secondary)),
+                        Span::styled(alert.label(), Style::default().fg(color)),
+                    ]
+                }).collect();
+                Line::from(spans)
+            };
+
+            let (streak, consistency) = match self.metrics.get(&student.id) {
+                Some(metrics) => (
+                    format!("{} (best {})", metrics.current_streak, metrics.longest_streak),
+                    format!("{}%", metrics.consistency),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+
+            Row::new(vec![
+                Cell::from(student.username.clone()),
+                Cell::from(last_commit),
+                Cell::from(status.recent_commits.to_string()),
+                Cell::from(status.previous_commits.to_string()),
+                Cell::from(streak),
+                Cell::from(consistency),
+                Cell::from(flags),
+            ])
+        }).collect();
+
+        let header = Row::new(vec!["Student", "Last Commit", "Recent", "Prior", "Streak", "Consistency", "Status"])
+            .style(Style::default().fg(theme.primary).add_modifier(Modifier::BOLD));
+        self.table_area = block.inner(area);
+        let table = Table::new(rows)
+            .header(header)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
+            .highlight_symbol("▶ ")
+            .widths(&[
+                Constraint::Length(20),
+                Constraint::Length(16),
+                Constraint::Length(7),
+                Constraint::Length(8),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Min(20),
+            ]);
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn render_threshold_form(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(60, 60, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("🚦 Alerts and Metrics")
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Inactive days
+                Constraint::Length(3), // Decline percent
+                Constraint::Length(3), // Active day commits
+                Constraint::Length(3), // Weekly target
+                Constraint::Length(3), // Consistency weeks
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        for (input, chunk) in self.inputs.iter().zip(chunks.iter()) {
+            frame.render_widget(input, *chunk);
+        }
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[5]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Next Field  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[7]);
+    }
+}
+
+impl Screen for ClassDashboardScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.editing_thresholds
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::ClassDashboard)
+            .with_context(ScreenContext::Class(self.class.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.editing_thresholds {
+                match key.code {
+                    KeyCode::Esc => {
+                        for input in &mut self.inputs {
+                            input.unfocus();
+                        }
+                        self.editing_thresholds = false;
+                    }
+                    KeyCode::Tab | KeyCode::Down => self.focus_input((self.focused_input + 1) % self.inputs.len()),
+                    KeyCode::BackTab | KeyCode::Up => {
+                        self.focus_input((self.focused_input + self.inputs.len() - 1) % self.inputs.len())
+                    }
+                    KeyCode::Enter => return self.save_thresholds(state).await,
+                    _ => {
+                        self.inputs[self.focused_input].handle_key_event(key);
+                        self.error = None;
+                    }
+                }
+                return Ok(None);
+            }
+
+            let selected = self.table_state.selected().unwrap_or(0);
+            match key.code {
+                _ if keymap::is(Action::Up, &key) && !self.students.is_empty() => {
+                    self.table_state.select(Some(selected.saturating_sub(1)));
+                }
+                _ if keymap::is(Action::Down, &key) && selected + 1 < self.students.len() => {
+                    self.table_state.select(Some(selected + 1));
+                }
+                _ if keymap::is(Action::Select, &key) => {
+                    return Ok(self.selected_student().map(|student| AppEvent::ShowStudentDetail(student.clone())));
+                }
+                _ if keymap::is(Action::OpenClassMenu, &key) => {
+                    return Ok(Some(AppEvent::NavigateToScreen(
+                        ScreenType::new(ScreenTypeVariant::ClassManagement)
+                            .with_context(ScreenContext::Class(self.class.clone())),
+                    )));
+                }
+                _ if keymap::is(Action::OpenWeekView, &key) => return Ok(Some(AppEvent::ShowWeekView)),
+                _ if keymap::is(Action::OpenHeatmap, &key) => {
+                    return Ok(Some(AppEvent::NavigateToScreen(
+                        ScreenType::new(ScreenTypeVariant::ActivityHeatmap)
+                            .with_context(ScreenContext::Class(self.class.clone())),
+                    )));
+                }
+                _ if keymap::is(Action::EditThresholds, &key) => self.start_editing_thresholds(),
+                _ if keymap::is(Action::Refresh, &key) => return Ok(Some(AppEvent::UpdateCommitCache(self.students.clone()))),
+                _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                _ => {}
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // Clicks only pick rows while the table has the keyboard
+        let rows = self.students.len();
+        let key = if !self.captures_text_input() {
+            table_mouse_key(&mut self.table_state, self.table_area, rows, mouse, keymap::key_event(Action::Select))
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        for input in &mut self.inputs {
+            input.update(delta_time);
+        }
+        if self.needs_assessment {
+            self.assess_students(&state.git_manager);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🏫 {} - Dashboard", self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(4), // Summary
+                Constraint::Min(5),    // Students
+                Constraint::Length(2), // Help text
+            ])
+            .split(inner_area);
+
+        self.render_summary(frame, chunks[0], theme);
+        self.render_students(frame, chunks[1], theme);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Details  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenClassMenu]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Class menu  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenWeekView]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Week view  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenHeatmap]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Heatmap  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::EditThresholds]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Settings  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Refresh]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Refresh  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Help]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Help", Style::default().fg(theme.text_secondary)),
+        ]);
+        let help = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help, chunks[2]);
+
+        if self.editing_thresholds {
+            self.render_threshold_form(frame, area, theme);
+        }
+    }
+}
@@ -2,86 +2,163 @@ use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 use std::{future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     data::{Class, database::Database},
     ui::{
         animations::AnimationState,
-        components::ConfirmationDialog,
-        screens::{Screen, ScreenType, ScreenTypeVariant},
+        mouse::MouseAction,
+        layout,
+        components::{input::AnimatedInput, menu::MenuItem, ConfirmationDialog},
+        screens::{scroll_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
         themes::Theme,
     },
+    utils::time,
 };
 
 pub struct ClassManagementScreen {
     class: Class,
     selected: usize,
-    menu_items: Vec<MenuOption>,
+    menu_items: Vec<MenuItem>,
     confirmation_dialog: ConfirmationDialog,
+    /// The options list inside its block and its scroll offset when last drawn, for mouse hit-testing
+    list_area: Rect,
+    list_offset: usize,
+    /// The name prompt for renaming or duplicating the class, while open
+    name_input: Option<(NameEdit, AnimatedInput)>,
+    name_error: Option<String>,
 }
 
-#[derive(Clone)]
-struct MenuOption {
-    title: String,
-    description: String,
-    icon: String,
-    hotkey: char,
+/// What the name prompt is for
+#[derive(Clone, Copy, PartialEq)]
+enum NameEdit {
+    Rename,
+    Duplicate,
 }
 
 impl ClassManagementScreen {
     pub fn new(class: Class) -> Self {
-        let menu_items = vec![
-            MenuOption {
-                title: "Manage Students".to_string(),
-                description: "Add or remove students".to_string(),
-                icon: "👥".to_string(),
-                hotkey: 's',
-            },
-            MenuOption {
-                title: "Manage Repositories".to_string(),
-                description: "Clone, pull, or clean repositories".to_string(),
-                icon: "📁".to_string(),
-                hotkey: 'r',
-            },
-            MenuOption {
-                title: "View GitHub Activity".to_string(),
-                description: "Check student GitHub activity".to_string(),
-                icon: "📊".to_string(),
-                hotkey: 'a',
-            },
-            MenuOption {
-                title: "Delete Class".to_string(),
-                description: "Delete this class and its data".to_string(),
-                icon: "🗑️".to_string(),
-                hotkey: 'd',
-            },
-            MenuOption {
-                title: "Back".to_string(),
-                description: "Return to main menu".to_string(),
-                icon: "↩️".to_string(),
-                hotkey: 'b',
-            },
-        ];
-        
         // Store class name before moving class into struct
         let class_name = class.name.clone();
         
         Self { 
             class, 
             selected: 0,
-            menu_items,
+            menu_items: Self::menu_items(),
             confirmation_dialog: ConfirmationDialog::new(
                 "Delete Class", 
                 &format!("Are you sure you want to delete the class '{}'?\nThis action cannot be undone.", class_name)
             ).with_yes_text("Delete").with_no_text("Cancel"),
+            list_area: Rect::default(),
+            list_offset: 0,
+            name_input: None,
+            name_error: None,
+        }
+    }
+
+    fn open_name_input(&mut self, edit: NameEdit) {
+        let (title, name) = match edit {
+            NameEdit::Rename => ("New name", self.class.name.clone()),
+            NameEdit::Duplicate => ("Name for the new class", format!("{} (copy)", self.class.name)),
+        };
+        let mut input = AnimatedInput::new(title);
+        input.set_value(name);
+        input.focus();
+        self.name_input = Some((edit, input));
+        self.name_error = None;
+    }
+
+    /// Keys for the open name prompt; Enter hands the new name to the app
+    fn handle_name_input(&mut self, key: KeyEvent) -> Option<AppEvent> {
+        let (edit, input) = self.name_input.as_mut()?;
+        match key.code {
+            KeyCode::Esc => self.name_input = None,
+            KeyCode::Enter => {
+                let name = input.value().trim().to_string();
+                if name.is_empty() {
+                    self.name_error = Some("Class name cannot be empty".to_string());
+                } else if name.contains(['/', '\\']) {
+                    // The name is also the folder clones live in
+                    self.name_error = Some("Class names can't contain / or \\".to_string());
+                } else if name == self.class.name {
+                    self.name_error = Some("Pick a name different from the current one".to_string());
+                } else {
+                    let event = match edit {
+                        NameEdit::Rename => AppEvent::RenameClass(self.class.clone(), name),
+                        NameEdit::Duplicate => AppEvent::DuplicateClass(self.class.clone(), name),
+                    };
+                    self.name_input = None;
+                    return Some(event);
+                }
+            }
+            _ => {
+                input.handle_key_event(key);
+                self.name_error = None;
+            }
         }
+        None
+    }
+
+    fn render_name_input(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let Some((edit, input)) = &self.name_input else {
+            return;
+        };
+
+        let popup_area = layout::center_rect(60, 30, area);
+        frame.render_widget(Clear, popup_area);
+
+        let title = match edit {
+            NameEdit::Rename => format!("✏️ Rename {}", self.class.name),
+            NameEdit::Duplicate => format!("📋 Duplicate {}", self.class.name),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Name
+                Constraint::Length(2), // Error message or what happens
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(input, chunks[0]);
+
+        let note = match (&self.name_error, edit) {
+            (Some(error), _) => Span::styled(error.clone(), Style::default().fg(theme.error)),
+            (None, NameEdit::Rename) => Span::styled(
+                "Cloned repos move to a folder with the new name",
+                Style::default().fg(theme.text_secondary),
+            ),
+            (None, NameEdit::Duplicate) => Span::styled(
+                "Copies the student list only; clone their repos again in the new class",
+                Style::default().fg(theme.text_secondary),
+            ),
+        };
+        frame.render_widget(Paragraph::new(note).alignment(Alignment::Center), chunks[1]);
+
+        let help_text = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[3]);
     }
 
     fn select_next(&mut self) {
@@ -100,9 +177,64 @@ impl ClassManagementScreen {
         }
     }
 
-    fn get_selected_item(&self) -> Option<&MenuOption> {
+    fn get_selected_item(&self) -> Option<&MenuItem> {
         self.menu_items.get(self.selected)
     }
+
+    /// The class menu with each item's hotkey
+    pub(crate) fn menu_items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Manage Students").with_description("Add or remove students").with_icon("👥").with_hotkey('s'),
+            MenuItem::new("Manage Repositories").with_description("Clone, pull, or clean repositories").with_icon("📁").with_hotkey('r'),
+            MenuItem::new("View GitHub Activity").with_description("Check student GitHub activity").with_icon("📊").with_hotkey('a'),
+            MenuItem::new("Run Tests").with_description("Run a grading command in every student repo").with_icon("🧪").with_hotkey('t'),
+            MenuItem::new("Assignments").with_description("Set deadlines and find late or missing work").with_icon("📅").with_hotkey('g'),
+            MenuItem::new("School Calendar").with_description("Holidays, breaks and make-up days").with_icon("🗓️").with_hotkey('c'),
+            MenuItem::new("Rename Class")
+                .with_description("Change the name and move its clone folder to match")
+                .with_icon("✏️")
+                .with_hotkey('n')
+                .with_detail("The folder of clones under the repos directory is renamed to match."),
+            MenuItem::new("Duplicate Class")
+                .with_description("Start a new class with this roster, e.g. for a new semester")
+                .with_icon("📋")
+                .with_hotkey('p')
+                .with_detail("Only the student list is copied: no notes, grades, assignments or clones."),
+            MenuItem::new("Delete Class")
+                .with_description("Delete this class and its data")
+                .with_icon("🗑️")
+                .with_hotkey('d')
+                .with_detail("Removes the class with its students, assignments, notes and grades from the database. Local clones stay on disk."),
+            MenuItem::new("Back").with_description("Return to the class dashboard").with_icon("↩️").with_hotkey('b'),
+        ]
+    }
+
+    /// Open what the selected item is for
+    fn activate(&mut self) -> Option<AppEvent> {
+        let class = ScreenContext::Class(self.class.clone());
+        match self.get_selected_item()?.title.as_str() {
+            "Manage Students" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::StudentManagement).with_context(class))),
+            "Manage Repositories" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::RepositoryManagement).with_context(class))),
+            "View GitHub Activity" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::GitHubActivity).with_context(class))),
+            "Run Tests" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::TestRunner).with_context(class))),
+            "Assignments" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::Assignments).with_context(class))),
+            "School Calendar" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::SchoolCalendar).with_context(class))),
+            "Rename Class" => {
+                self.open_name_input(NameEdit::Rename);
+                None
+            }
+            "Duplicate Class" => {
+                self.open_name_input(NameEdit::Duplicate);
+                None
+            }
+            "Delete Class" => {
+                self.confirmation_dialog.show();
+                None
+            }
+            "Back" => Some(AppEvent::GoBack),
+            _ => None,
+        }
+    }
 }
 
 impl Screen for ClassManagementScreen {
@@ -111,7 +243,7 @@ impl Screen for ClassManagementScreen {
     }
 
     fn screen_type(&self) -> ScreenType {
-        ScreenType::new(ScreenTypeVariant::ClassManagement)
+        ScreenType::new(ScreenTypeVariant::ClassManagement).with_context(ScreenContext::Class(self.class.clone()))
     }
 
     fn handle_key_event<'a>(
@@ -145,60 +277,75 @@ impl Screen for ClassManagementScreen {
             return Box::pin(async move { Ok(None) });
         }
         
+        if self.name_input.is_some() {
+            let event = self.handle_name_input(key);
+            return Box::pin(async move { Ok(event) });
+        }
+        
         // Normal key handling when dialog is not visible
         let result = match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            _ if keymap::is(Action::Up, &key) => {
                 self.select_previous();
                 Ok(None)
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.select_next();
                 Ok(None)
             }
-            KeyCode::Enter | KeyCode::Char(' ') => {
-                if let Some(selected) = self.get_selected_item() {
-                    match selected.title.as_str() {
-                        "Manage Students" => Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::StudentManagement).with_context(crate::ui::screens::ScreenContext::Class(self.class.clone()))))),
-                        "Manage Repositories" => Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::RepositoryManagement).with_context(crate::ui::screens::ScreenContext::Class(self.class.clone()))))),
-                        "Delete Class" => {
-                            // Show confirmation dialog
-                            self.confirmation_dialog.show();
-                            Ok(None)
-                        },
-                        "Back" => Ok(Some(AppEvent::GoBack)),
-                        _ => Ok(None),
-                    }
-                } else {
-                    Ok(None)
-                }
-            }
-            KeyCode::Char(c) => {
-                // Handle hotkeys
-                match c {
-                    's' => Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::StudentManagement).with_context(crate::ui::screens::ScreenContext::Class(self.class.clone()))))),
-                    'r' => Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::RepositoryManagement).with_context(crate::ui::screens::ScreenContext::Class(self.class.clone()))))),
-                    'a' => Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::GitHubActivity).with_context(crate::ui::screens::ScreenContext::Class(self.class.clone()))))),
-                    'd' => {
-                        // Show confirmation dialog for delete
-                        self.confirmation_dialog.show();
-                        Ok(None)
-                    },
-                    'b' => Ok(Some(AppEvent::GoBack)),
-                    _ => Ok(None),
+            _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => Ok(self.activate()),
+            // Hotkeys, as defined on the menu items
+            KeyCode::Char(c) => match self.menu_items.iter().position(|item| item.hotkey == Some(c)) {
+                Some(index) => {
+                    self.selected = index;
+                    Ok(self.activate())
                 }
-            }
-            KeyCode::Char('b') | KeyCode::Esc => Ok(Some(AppEvent::GoBack)),
+                None => Ok(None),
+            },
+            _ if keymap::is(Action::Back, &key) => Ok(Some(AppEvent::GoBack)),
             _ => Ok(None),
         };
 
         Box::pin(async { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirmation_dialog.is_visible() {
+            self.confirmation_dialog.key_for_click(mouse)
+        } else if self.name_input.is_some() {
+            None
+        } else if let MouseAction::Click { column, row } | MouseAction::DoubleClick { column, row } = mouse {
+            match layout::list_row_at(self.list_area, column, row, self.list_offset).filter(|i| *i < self.menu_items.len()) {
+                Some(index) => {
+                    self.selected = index;
+                    matches!(mouse, MouseAction::DoubleClick { .. }).then(|| keymap::key_event(Action::Select)).flatten()
+                }
+                None => None,
+            }
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.name_input.is_some()
+    }
+
     fn update<'a>(
         &'a mut self,
-        _delta_time: Duration,
+        delta_time: Duration,
         _state: &'a mut AppState,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        if let Some((_, input)) = &mut self.name_input {
+            input.update(delta_time);
+        }
         Box::pin(async { Ok(()) })
     }
 
@@ -231,12 +378,12 @@ impl Screen for ClassManagementScreen {
             .constraints([
                 Constraint::Length(2),  // Class info
                 Constraint::Min(5),     // Menu
-                Constraint::Length(3),  // Help
+                Constraint::Length(2),  // Help
             ])
             .split(inner_area);
         
         // Render class info
-        let class_info = Paragraph::new(format!("Class ID: {} • Created: {}", self.class.id, self.class.created_at.format("%Y-%m-%d")))
+        let class_info = Paragraph::new(format!("Class ID: {} • Created: {}", self.class.id, time::format(self.class.created_at, "%Y-%m-%d")))
             .alignment(Alignment::Center)
             .style(Style::default().fg(theme.text_secondary));
         frame.render_widget(class_info, chunks[0]);
@@ -252,7 +399,15 @@ impl Screen for ClassManagementScreen {
             };
             
             let prefix = if is_selected { "▶ " } else { "  " };
-            let content = format!("{}{} {} - {}", prefix, item.icon, item.title, item.description);
+            let hotkey = item.hotkey.map(|key| format!(" ({})", key)).unwrap_or_default();
+            let content = format!(
+                "{}{} {}{} - {}",
+                prefix,
+                item.icon.as_deref().unwrap_or(""),
+                item.title,
+                hotkey,
+                item.description.as_deref().unwrap_or("")
+            );
             
             ListItem::new(content).style(style)
         }).collect();
@@ -270,28 +425,19 @@ impl Screen for ClassManagementScreen {
         list_state.select(Some(self.selected));
         
         frame.render_stateful_widget(list, chunks[1], &mut list_state);
+        self.list_area = chunks[1].inner(&Margin { vertical: 1, horizontal: 1 });
+        self.list_offset = list_state.offset();
         
         // Render help
         let help_lines = vec![
             Line::from(vec![
-                Span::styled("↑/↓ or k/j", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                 Span::styled(": Navigate  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("Enter", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                 Span::styled(": Select  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("Esc", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                 Span::styled(": Back", Style::default().fg(theme.text_secondary)),
             ]),
-            Line::from(vec![
-                Span::styled("Hotkeys: ", Style::default().fg(theme.text_secondary)),
-                Span::styled("s", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
-                Span::styled(":Students  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("r", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
-                Span::styled(":Repos  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("a", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
-                Span::styled(":Activity  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("d", Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
-                Span::styled(":Delete", Style::default().fg(theme.text_secondary)),
-            ]),
         ];
         
         let help = Paragraph::new(help_lines)
@@ -300,6 +446,8 @@ impl Screen for ClassManagementScreen {
             .style(Style::default().fg(theme.text_secondary));
         frame.render_widget(help, chunks[2]);
         
+        self.render_name_input(frame, area, theme);
+        
         // Render confirmation dialog if visible
         self.confirmation_dialog.render(frame, area, theme);
     }
@@ -15,10 +15,11 @@ use std::{
 };
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     data::Class,
     ui::{
         animations::AnimationState,
+        mouse::MouseAction,
         components::{
             loading::LoadingWidget,
             menu::{AnimatedMenu, MenuBuilder, MenuItem, MenuPresets},
@@ -94,7 +95,7 @@ impl ClassSelectionScreen {
             // Show empty state with option to create a class
             builder = builder
                 .item(MenuItem::new("No classes found")
-                    .with_description("Press 'n' to create your first class")
+                    .with_description("Select to create your first class")
                     .with_icon("ℹ️"));
         } else {
             // Add each class to the menu
@@ -105,16 +106,39 @@ impl ClassSelectionScreen {
             }
         }
         
-        builder
-            .item(MenuItem::new("Create New Class")
+        for item in Self::action_items() {
+            builder = builder.item(item);
+        }
+        builder.build()
+    }
+
+    /// The items listed after the classes, with their hotkeys
+    pub(crate) fn action_items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Create New Class")
                 .with_description("Add a new class")
                 .with_icon("➕")
-                .with_hotkey('c'))
-            .item(MenuItem::new("Back")
+                .with_hotkey('n'),
+            MenuItem::new("Back")
                 .with_description("Return to main menu")
                 .with_icon("↩️")
-                .with_hotkey('b'))
-            .build()
+                .with_hotkey('b'),
+        ]
+    }
+
+    /// What the selected item opens: a class, the new class form or the previous screen
+    fn activate(&self) -> Option<AppEvent> {
+        let title = self.menu.selected_item()?.title.as_str();
+        if let Some(class) = self.classes.iter().find(|c| c.name == title) {
+            return Some(AppEvent::SelectClass(class.clone()));
+        }
+        match title {
+            "Create New Class" | "No classes found" => {
+                Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass)))
+            }
+            "Back" => Some(AppEvent::GoBack),
+            _ => None,
+        }
     }
 }
 
@@ -140,42 +164,30 @@ impl Screen for ClassSelectionScreen {
         // Handle navigation
         match key.code {
             // Navigation keys
-            KeyCode::Char('j') | KeyCode::Down => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.menu.select_next();
             },
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if keymap::is(Action::Up, &key) => {
                 self.menu.select_previous();
             },
             // Enter key for selection
-            KeyCode::Enter | KeyCode::Char(' ') => {
-                if self.classes.is_empty() {
-                    // If no classes, allow creating a new one
-                    return Box::pin(async { 
-                        Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass)))) 
-                    });
-                } else if let Some(selected_item) = self.menu.selected_item() {
-                    // Find the selected class
-                    if let Some(class) = self.classes.iter().find(|c| c.name == selected_item.title) {
-                        return Box::pin(async { 
-                            Ok(Some(AppEvent::SelectClass(class.clone()))) 
-                        });
-                    }
-                }
-            },
-            // Create new class
-            KeyCode::Char('n') => {
-                return Box::pin(async { 
-                    Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass)))) 
-                });
+            _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => {
+                let event = self.activate();
+                return Box::pin(async { Ok(event) });
             },
             // Refresh class list
-            KeyCode::Char('r') => {
+            _ if keymap::is(Action::Refresh, &key) => {
                 return Box::pin(async { 
                     Ok(Some(AppEvent::RefreshData)) 
                 });
             },
+            // Hotkeys, as defined on the menu items
+            KeyCode::Char(c) if self.menu.select_hotkey(c) => {
+                let event = self.activate();
+                return Box::pin(async { Ok(event) });
+            },
             // Go back to previous screen
-            KeyCode::Esc => {
+            _ if keymap::is(Action::Back, &key) => {
                 return Box::pin(async { 
                     Ok(Some(AppEvent::GoBack))
                 });
@@ -186,6 +198,18 @@ impl Screen for ClassSelectionScreen {
         Box::pin(async { Ok(None) })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // A double click activates the item like Select
+        if let Some(key) = self.menu.handle_mouse_event(mouse).then(|| keymap::key_event(Action::Select)).flatten() {
+            return self.handle_key_event(key, state);
+        }
+        Box::pin(async { Ok(None) })
+    }
+
     fn update<'a>(
         &'a mut self,
         delta_time: Duration,
@@ -268,16 +292,14 @@ impl Screen for ClassSelectionScreen {
             
             // Render help text
             let help_text = Line::from(vec![
-                Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(": Navigate  "),
-                Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Select]), Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(": Select  "),
-                Span::styled("n", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(": New Class  "),
-                Span::styled("r", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Refresh]), Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(": Refresh  "),
-                Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(": Quit"),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(": Back"),
             ]);
             
             frame.render_widget(
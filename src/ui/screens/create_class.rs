@@ -93,6 +93,10 @@ impl Screen for CreateClassScreen {
         self
     }
 
+    fn captures_text_input(&self) -> bool {
+        true
+    }
+
     fn render(
         &mut self,
         frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
@@ -1,8 +1,8 @@
 use anyhow::Result;
-use crossterm::event::{KeyEvent, KeyCode};
+use crossterm::event::KeyEvent;
 use ratatui::{
-    layout::{Alignment, Rect},
-    style::Style,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
@@ -10,15 +10,23 @@ use tokio::pin;
 use std::pin::Pin;
 
 use crate::{
-    app::AppEvent,
+    app::{keymap, Action, AppEvent, BatchAction},
     data::{Class, Database, Student},
-    ui::themes::Theme,
+    ui::{
+        components::{selection::StudentSelection, ConfirmationDialog},
+        mouse::MouseAction,
+        themes::Theme,
+    },
 };
 
 pub struct DeleteStudentScreen {
     class: Class,
     students: Vec<Student>,
     selected_index: usize,
+    selection: StudentSelection,
+    confirm_delete: ConfirmationDialog,
+    /// The students the open confirmation is about
+    pending: Vec<Student>,
 }
 
 impl DeleteStudentScreen {
@@ -27,6 +35,25 @@ impl DeleteStudentScreen {
             class,
             students,
             selected_index: 0,
+            selection: StudentSelection::default(),
+            confirm_delete: ConfirmationDialog::new("Delete Students", ""),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Swap in the reloaded list after a delete
+    pub fn set_students(&mut self, students: Vec<Student>) {
+        self.selection.retain(&students);
+        self.selected_index = self.selected_index.min(students.len().saturating_sub(1));
+        self.students = students;
+    }
+
+    /// The marked students, or the highlighted one when none are marked
+    fn targets(&self) -> Vec<Student> {
+        if self.selection.is_empty() {
+            self.students.get(self.selected_index).cloned().into_iter().collect()
+        } else {
+            self.selection.students(&self.students)
         }
     }
 }
@@ -41,36 +68,60 @@ impl super::Screen for DeleteStudentScreen {
         Box::pin(async move { Ok(()) })
     }
 
-    fn handle_key_event(&mut self, key: KeyEvent, state: &crate::app::AppState) -> Pin<Box<dyn std::future::Future<Output = Result<Option<AppEvent>>> + Send + '_>> {
+    fn handle_key_event(&mut self, key: KeyEvent, _state: &crate::app::AppState) -> Pin<Box<dyn std::future::Future<Output = Result<Option<AppEvent>>> + Send + '_>> {
+        if self.confirm_delete.is_visible() {
+            let students = std::mem::take(&mut self.pending);
+            return match self.confirm_delete.handle_key_event(key) {
+                Some(true) => Box::pin(async move { Ok(Some(AppEvent::RunBatch(BatchAction::Delete, students))) }),
+                Some(false) => Box::pin(async move { Ok(None) }),
+                None => {
+                    self.pending = students;
+                    Box::pin(async move { Ok(None) })
+                }
+            };
+        }
+
         match key.code {
-            KeyCode::Esc => Box::pin(async move { Ok(Some(AppEvent::GoBack)) }),
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if keymap::is(Action::Back, &key) => Box::pin(async move { Ok(Some(AppEvent::GoBack)) }),
+            _ if keymap::is(Action::Up, &key) && !self.students.is_empty() => {
                 self.selected_index = (self.selected_index + self.students.len() - 1) % self.students.len();
                 Box::pin(async move { Ok(None) })
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            _ if keymap::is(Action::Down, &key) && !self.students.is_empty() => {
                 self.selected_index = (self.selected_index + 1) % self.students.len();
                 Box::pin(async move { Ok(None) })
             }
-            KeyCode::Enter => {
-                if self.students.is_empty() {
-                    return Box::pin(async move { Ok(None) });
+            _ if self.selection.handle_key_event(key, &self.students, self.students.get(self.selected_index)) => {
+                Box::pin(async move { Ok(None) })
+            }
+            _ if keymap::is(Action::Select, &key) => {
+                let students = self.targets();
+                if !students.is_empty() {
+                    self.confirm_delete = ConfirmationDialog::new(
+                        "Delete Students",
+                        &BatchAction::Delete.confirmation(&students),
+                    ).with_yes_text("Delete").with_no_text("Cancel");
+                    self.confirm_delete.show();
+                    self.pending = students;
                 }
-                
-                let student_id = self.students[self.selected_index].id;
-                let db = state.database.clone();
-                
-                Box::pin(async move {
-                    if let Err(e) = db.delete_student(student_id).await {
-                        log::error!("Failed to delete student: {}", e);
-                    }
-                    Ok(Some(AppEvent::GoBack))
-                })
+                Box::pin(async move { Ok(None) })
             }
             _ => Box::pin(async move { Ok(None) }),
         }
     }
 
+    fn handle_mouse_event<'a>(&'a mut self, mouse: MouseAction, state: &'a crate::app::AppState) -> Pin<Box<dyn std::future::Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirm_delete.is_visible() {
+            self.confirm_delete.key_for_click(mouse)
+        } else {
+            super::scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async move { Ok(None) }),
+        }
+    }
+
     fn render(
         &mut self, 
         frame: &mut ratatui::Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, 
@@ -81,9 +132,14 @@ impl super::Screen for DeleteStudentScreen {
     ) {
         frame.render_widget(Clear, area);
         
+        let title = if self.selection.is_empty() {
+            "Delete Student".to_string()
+        } else {
+            format!("Delete Student ({} marked)", self.selection.len())
+        };
         let block = Block::default()
             .borders(Borders::ALL)
-            .title("Delete Student")
+            .title(title)
             .title_alignment(Alignment::Center)
             .style(Style::default().fg(theme.primary));
             
@@ -97,14 +153,37 @@ impl super::Screen for DeleteStudentScreen {
                 Style::default().fg(theme.text)
             };
             
-            Line::from(Span::styled(&student.username, style))
+            Line::from(vec![
+                Span::styled(self.selection.marker(student), Style::default().fg(theme.accent)),
+                Span::styled(&student.username, style),
+            ])
         }).collect::<Vec<_>>();
         
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner_area);
+        
         let paragraph = Paragraph::new(items)
             .wrap(Wrap { trim: true })
             .alignment(Alignment::Left);
             
-        frame.render_widget(paragraph, inner_area);
+        frame.render_widget(paragraph, chunks[0]);
+        
+        let key_style = Style::default().fg(theme.primary).add_modifier(Modifier::BOLD);
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::ToggleSelection]), key_style),
+            Span::styled(" Mark  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::SelectAll, Action::SelectNone, Action::InvertSelection]), key_style),
+            Span::styled(" All/None/Invert  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Select]), key_style),
+            Span::styled(" Delete marked (or highlighted)  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), key_style),
+            Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[1]);
+        
+        self.confirm_delete.render(frame, area, theme);
     }
 
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
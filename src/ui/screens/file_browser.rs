@@ -0,0 +1,389 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::{future::Future, path::PathBuf, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Class, Student},
+    git::tree::FileTree,
+    ui::{
+        animations::AnimationState,
+        components::syntax::{Highlighter, Language, Token},
+        screens::{Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Focus {
+    Tree,
+    Viewer,
+}
+
+struct OpenFile {
+    path: PathBuf,
+    lines: Vec<Vec<Token>>,
+    scroll: usize,
+}
+
+pub struct FileBrowserScreen {
+    class: Class,
+    student: Student,
+    tree: Option<FileTree>,
+    selected: usize,
+    open_file: Option<OpenFile>,
+    viewer_message: Option<String>,
+    focus: Focus,
+    needs_load: bool,
+    error: Option<String>,
+    viewer_height: usize,
+}
+
+impl FileBrowserScreen {
+    pub fn new(class: Class, student: Student) -> Self {
+        Self {
+            class,
+            student,
+            tree: None,
+            selected: 0,
+            open_file: None,
+            viewer_message: None,
+            focus: Focus::Tree,
+            needs_load: true,
+            error: None,
+            viewer_height: 20,
+        }
+    }
+
+    fn load_tree(&mut self, state: &AppState) {
+        let repo_path = state.git_manager.get_repo_path(&self.student.github_username, &self.class.name);
+        match FileTree::load(&repo_path) {
+            Ok(tree) => {
+                self.tree = Some(tree);
+                self.error = None;
+            }
+            Err(e) => {
+                self.tree = None;
+                self.error = Some(e.to_string());
+            }
+        }
+        self.selected = 0;
+    }
+
+    fn visible_count(&self) -> usize {
+        self.tree.as_ref().map(|tree| tree.visible_entries().len()).unwrap_or(0)
+    }
+
+    fn activate_selected(&mut self) {
+        let Some(tree) = self.tree.as_mut() else { return };
+        let Some(entry) = tree.visible_entries().get(self.selected).map(|e| (*e).clone()) else { return };
+
+        if entry.is_dir {
+            tree.toggle(&entry.relative_path);
+            return;
+        }
+
+        match tree.read_file(&entry.relative_path) {
+            Ok(contents) => {
+                let language = Language::from_path(&entry.relative_path);
+                let contents = contents.replace('\t', "    ");
+                self.open_file = Some(OpenFile {
+                    path: entry.relative_path.clone(),
+                    lines: Highlighter::highlight_source(language, &contents),
+                    scroll: 0,
+                });
+                self.viewer_message = None;
+                self.focus = Focus::Viewer;
+            }
+            Err(e) => {
+                self.open_file = None;
+                self.viewer_message = Some(e.to_string());
+            }
+        }
+    }
+
+    fn expand_selected(&mut self) {
+        let Some(tree) = self.tree.as_mut() else { return };
+        let Some(entry) = tree.visible_entries().get(self.selected).map(|e| (*e).clone()) else { return };
+
+        if entry.is_dir {
+            tree.expand(&entry.relative_path);
+        } else {
+            self.activate_selected();
+        }
+    }
+
+    fn collapse_selected(&mut self) {
+        let Some(tree) = self.tree.as_mut() else { return };
+        let visible = tree.visible_entries();
+        let Some(entry) = visible.get(self.selected).map(|e| (*e).clone()) else { return };
+        let parent_index = entry.relative_path.parent()
+            .and_then(|parent| visible.iter().position(|e| e.relative_path == parent));
+
+        if entry.is_dir && tree.is_expanded(&entry) {
+            tree.collapse(&entry.relative_path);
+        } else if let Some(index) = parent_index {
+            // Jump to the parent directory
+            self.selected = index;
+        }
+    }
+
+    fn scroll_viewer(&mut self, amount: isize) {
+        if let Some(file) = self.open_file.as_mut() {
+            let max_scroll = file.lines.len().saturating_sub(1);
+            let scroll = file.scroll as isize + amount;
+            file.scroll = scroll.clamp(0, max_scroll as isize) as usize;
+        }
+    }
+
+    fn handle_tree_key(&mut self, key: KeyEvent) -> Option<AppEvent> {
+        match key.code {
+            _ if keymap::is(Action::Up, &key) => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            _ if keymap::is(Action::Down, &key) && self.selected + 1 < self.visible_count() => {
+                self.selected += 1;
+            }
+            _ if keymap::is(Action::Select, &key) => self.activate_selected(),
+            _ if keymap::is(Action::Right, &key) => self.expand_selected(),
+            _ if keymap::is(Action::Left, &key) => self.collapse_selected(),
+            _ if keymap::is(Action::Next, &key) && self.open_file.is_some() => self.focus = Focus::Viewer,
+            _ if keymap::is(Action::Refresh, &key) => self.needs_load = true,
+            _ if keymap::is(Action::Back, &key) => return Some(AppEvent::GoBack),
+            _ => {}
+        }
+        None
+    }
+
+    fn handle_viewer_key(&mut self, key: KeyEvent) -> Option<AppEvent> {
+        let page = self.viewer_height.max(1) as isize;
+        match key.code {
+            _ if keymap::is(Action::Up, &key) => self.scroll_viewer(-1),
+            _ if keymap::is(Action::Down, &key) => self.scroll_viewer(1),
+            _ if keymap::is(Action::PageUp, &key) => self.scroll_viewer(-page),
+            _ if keymap::is(Action::PageDown, &key) => self.scroll_viewer(page),
+            _ if keymap::is(Action::Top, &key) => self.scroll_viewer(isize::MIN / 2),
+            _ if keymap::is(Action::Bottom, &key) => self.scroll_viewer(isize::MAX / 2),
+            _ if keymap::is(Action::Next, &key) || keymap::is(Action::Back, &key) || keymap::is(Action::Left, &key) => {
+                self.focus = Focus::Tree
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn render_tree(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let border_style = if self.focus == Focus::Tree {
+            theme.border_focused_style()
+        } else {
+            theme.border_style()
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Files")
+            .border_style(border_style);
+
+        let Some(tree) = &self.tree else {
+            let message = self.error.clone().unwrap_or_else(|| "Loading...".to_string());
+            let paragraph = Paragraph::new(message)
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.error));
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        if tree.is_empty() {
+            let paragraph = Paragraph::new("Repository is empty")
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = tree.visible_entries().iter().map(|entry| {
+            let indent = "  ".repeat(entry.depth);
+            let (marker, style) = if entry.is_dir {
+                let marker = if tree.is_expanded(entry) { "▾ " } else { "▸ " };
+                (marker, Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))
+            } else {
+                ("  ", Style::default().fg(theme.text))
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(indent),
+                Span::styled(marker, style),
+                Span::styled(entry.name.clone(), style),
+            ]))
+        }).collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background));
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    fn render_viewer(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let border_style = if self.focus == Focus::Viewer {
+            theme.border_focused_style()
+        } else {
+            theme.border_style()
+        };
+
+        let Some(file) = &self.open_file else {
+            let message = self.viewer_message.clone()
+                .unwrap_or_else(|| format!("Select a file and press {} to view it", keymap::label(&[Action::Select])));
+            let paragraph = Paragraph::new(message)
+                .block(Block::default().borders(Borders::ALL).title("Viewer").border_style(border_style))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        let title = format!(
+            "{} ({}/{})",
+            file.path.display(),
+            (file.scroll + 1).min(file.lines.len()),
+            file.lines.len()
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(border_style);
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        self.viewer_height = inner_area.height as usize;
+
+        let gutter_width = file.lines.len().to_string().len().max(3);
+        let lines: Vec<Line> = file.lines.iter()
+            .enumerate()
+            .skip(file.scroll)
+            .take(inner_area.height as usize)
+            .map(|(index, tokens)| {
+                let mut spans = vec![Span::styled(
+                    format!("{:>width$} │ ", index + 1, width = gutter_width),
+                    Style::default().fg(theme.text_secondary),
+                )];
+                spans.extend(tokens.iter().map(|(kind, text)| Span::styled(text.clone(), kind.style(theme))));
+                Line::from(spans)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), inner_area);
+    }
+}
+
+impl Screen for FileBrowserScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::FileBrowser)
+            .with_context(ScreenContext::ClassAndStudent(self.class.clone(), self.student.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        _state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let result = match self.focus {
+            Focus::Tree => self.handle_tree_key(key),
+            Focus::Viewer => self.handle_viewer_key(key),
+        };
+        Box::pin(async move { Ok(result) })
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        _delta_time: Duration,
+        state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        if self.needs_load {
+            self.needs_load = false;
+            self.load_tree(state);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📂 {} - {}", self.student.github_username, self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),     // Tree and viewer
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(70),
+            ])
+            .split(chunks[0]);
+
+        self.render_tree(frame, panes[0], theme);
+        self.render_viewer(frame, panes[1], theme);
+
+        let help_text = match self.focus {
+            Focus::Tree => Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Select, Action::Right]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Open/Expand  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Left]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Collapse  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Next]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Viewer  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Refresh]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Reload  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+            ]),
+            Focus::Viewer => Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Scroll  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::PageUp, Action::PageDown]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Page  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Top, Action::Bottom]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Top/Bottom  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Next, Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Files", Style::default().fg(theme.text_secondary)),
+            ]),
+        };
+
+        let help_paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[1]);
+    }
+}
@@ -10,10 +10,11 @@ use ratatui::{
 use std::{future::Future, pin::Pin, time::Duration};
 
 use crate::{ui::screens::ScreenContext,
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     data::Class,
     ui::{
         animations::AnimationState,
+        mouse::MouseAction,
         components::menu::{AnimatedMenu, MenuBuilder, MenuItem, MenuPresets},
         screens::{Screen, ScreenType, ScreenTypeVariant},
         themes::Theme,
@@ -60,15 +61,15 @@ impl Screen for GitHubActivityScreen {
         _state: &'a AppState,
     ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
         let result = match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            _ if keymap::is(Action::Up, &key) => {
                 self.menu.select_previous();
                 Ok(None)
             },
-            KeyCode::Down | KeyCode::Char('j') => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.menu.select_next();
                 Ok(None)
             },
-            KeyCode::Enter | KeyCode::Char(' ') => {
+            _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => {
                 if let Some(selected_item) = self.menu.selected_item() {
                     match selected_item.title.as_str() {
                         "Week View" => Ok(Some(AppEvent::ShowWeekView)),
@@ -80,13 +81,25 @@ impl Screen for GitHubActivityScreen {
                     Ok(None)
                 }
             },
-            KeyCode::Esc => Ok(Some(AppEvent::GoBack)),
+            _ if keymap::is(Action::Back, &key) => Ok(Some(AppEvent::GoBack)),
             _ => Ok(None),
         };
 
         Box::pin(async { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // A double click activates the item like Select
+        if let Some(key) = self.menu.handle_mouse_event(mouse).then(|| keymap::key_event(Action::Select)).flatten() {
+            return self.handle_key_event(key, state);
+        }
+        Box::pin(async { Ok(None) })
+    }
+
     fn update<'a>(
         &'a mut self,
         delta_time: Duration,
@@ -143,11 +156,11 @@ impl Screen for GitHubActivityScreen {
         
         // Render help text
         let help_text = Line::from(vec![
-            Span::styled("↑/↓", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(": Navigate  "),
-            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(keymap::label(&[Action::Select]), Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(": Select  "),
-            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(": Back"),
         ]);
         
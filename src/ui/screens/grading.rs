@@ -0,0 +1,526 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState, Scope},
+    data::{assignments::format_points, Assignment, Class, Grade, RubricCriterion, Snapshot, Student},
+    preview::short_sha,
+    runner::{RunStatus, TestResult},
+    site_check::{Severity, SiteCheck},
+    ui::{
+        animations::AnimationState,
+        components::{input::AnimatedInput, ConfirmationDialog},
+        mouse::MouseAction,
+        screens::{scroll_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+/// Everything already known about a class that is shown beside the rubric
+pub struct GradingContext {
+    pub site_checks: HashMap<i64, SiteCheck>,
+    pub snapshots: HashMap<i64, Snapshot>,
+    pub test_results: HashMap<i64, TestResult>,
+}
+
+pub struct GradingScreen {
+    class: Class,
+    assignment: Assignment,
+    students: Vec<Student>,
+    rubric: Vec<RubricCriterion>,
+    grades: HashMap<i64, Grade>,
+    context: GradingContext,
+    current: usize,
+    /// One input per rubric criterion followed by the comment input
+    inputs: Vec<AnimatedInput>,
+    focused_input: usize,
+    /// Whether keys are typed into the focused input rather than moving between inputs
+    editing: bool,
+    /// The inputs' values when the student was loaded or last saved
+    saved_values: Vec<String>,
+    confirm_discard: ConfirmationDialog,
+    error: Option<String>,
+}
+
+impl GradingScreen {
+    pub fn new(
+        class: Class,
+        assignment: Assignment,
+        students: Vec<Student>,
+        rubric: Vec<RubricCriterion>,
+        grades: HashMap<i64, Grade>,
+        context: GradingContext,
+    ) -> Self {
+        let mut inputs: Vec<AnimatedInput> = rubric
+            .iter()
+            .map(|criterion| AnimatedInput::new(format!("{} (out of {})", criterion.name, format_points(criterion.max_points))))
+            .collect();
+        let mut comment = AnimatedInput::new("Comment");
+        comment.set_placeholder("Feedback for the student");
+        inputs.push(comment);
+
+        let mut screen = Self {
+            class,
+            assignment,
+            students,
+            rubric,
+            grades,
+            context,
+            current: 0,
+            inputs,
+            focused_input: 0,
+            editing: false,
+            saved_values: Vec::new(),
+            confirm_discard: ConfirmationDialog::new("Unsaved Grade", ""),
+            error: None,
+        };
+        screen.load_student();
+        screen
+    }
+
+    fn current_student(&self) -> Option<&Student> {
+        self.students.get(self.current)
+    }
+
+    /// Fill the inputs from the current student's saved grade, if any
+    fn load_student(&mut self) {
+        let grade = self.current_student().and_then(|student| self.grades.get(&student.id)).cloned();
+
+        for (input, criterion) in self.inputs.iter_mut().zip(&self.rubric) {
+            let points = grade.as_ref().and_then(|grade| grade.scores.get(&criterion.id));
+            input.set_value(points.map(|points| format_points(*points)).unwrap_or_default());
+        }
+        if let Some(comment) = self.inputs.last_mut() {
+            comment.set_value(grade.map(|grade| grade.comment).unwrap_or_default());
+        }
+
+        self.saved_values = self.values();
+        self.error = None;
+        self.editing = false;
+        self.focus_input(0);
+    }
+
+    fn values(&self) -> Vec<String> {
+        self.inputs.iter().map(|input| input.value().to_string()).collect()
+    }
+
+    /// Whether anything was typed since the student was loaded or saved
+    fn is_dirty(&self) -> bool {
+        self.values() != self.saved_values
+    }
+
+    /// Select an input; it only shows a cursor while it is being edited
+    fn focus_input(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.focused_input = index;
+        if self.editing {
+            self.inputs[index].focus();
+        }
+    }
+
+    fn set_editing(&mut self, editing: bool) {
+        self.editing = editing;
+        self.focus_input(self.focused_input);
+    }
+
+    fn move_to(&mut self, index: usize) {
+        if index < self.students.len() {
+            self.current = index;
+            self.load_student();
+        }
+    }
+
+    /// Go to another student, saving this one's grade first if it changed; stays put if it can't be saved
+    async fn save_and_move(&mut self, state: &AppState, index: usize) -> Result<()> {
+        if index >= self.students.len() || index == self.current {
+            return Ok(());
+        }
+        if self.is_dirty() && !self.save_grade(state).await? {
+            return Ok(());
+        }
+        self.move_to(index);
+        Ok(())
+    }
+
+    /// Save the grade and go on to the next student, or stop editing after the last one
+    async fn save_and_next(&mut self, state: &AppState) -> Result<()> {
+        if !self.save_grade(state).await? {
+            return Ok(());
+        }
+        if self.current + 1 < self.students.len() {
+            self.move_to(self.current + 1);
+        } else {
+            self.error = None;
+            self.set_editing(false);
+        }
+        Ok(())
+    }
+
+    /// Points typed so far, skipping criteria left blank
+    fn entered_scores(&self) -> Result<HashMap<i64, f64>, String> {
+        let mut scores = HashMap::new();
+        for (input, criterion) in self.inputs.iter().zip(&self.rubric) {
+            let value = input.value().trim();
+            if value.is_empty() {
+                continue;
+            }
+            let points: f64 = value
+                .parse()
+                .map_err(|_| format!("{}: '{}' is not a number", criterion.name, value))?;
+            if !(0.0..=criterion.max_points).contains(&points) {
+                return Err(format!("{}: score must be between 0 and {}", criterion.name, format_points(criterion.max_points)));
+            }
+            scores.insert(criterion.id, points);
+        }
+        Ok(scores)
+    }
+
+    /// Save the current student's grade; returns whether it was saved
+    async fn save_grade(&mut self, state: &AppState) -> Result<bool> {
+        let Some(student_id) = self.current_student().map(|student| student.id) else {
+            return Ok(false);
+        };
+        let scores = match self.entered_scores() {
+            Ok(scores) => scores,
+            Err(e) => {
+                self.error = Some(e);
+                return Ok(false);
+            }
+        };
+
+        let grade = Grade {
+            assignment_id: self.assignment.id,
+            student_id,
+            scores,
+            comment: self.inputs.last().map(|input| input.value().trim().to_string()).unwrap_or_default(),
+            graded_at: chrono::Utc::now(),
+        };
+        if let Err(e) = state.database.save_grade(&grade).await {
+            self.error = Some(format!("Failed to save grade: {}", e));
+            return Ok(false);
+        }
+        self.grades.insert(student_id, grade);
+        self.saved_values = self.values();
+        Ok(true)
+    }
+
+    fn render_repo_status(
+        &self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        state: &AppState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Repository")
+            .border_style(theme.border_style());
+
+        let Some(student) = self.current_student() else {
+            frame.render_widget(Paragraph::new("No students in this class").block(block), area);
+            return;
+        };
+
+        let label = |text: &str| Span::styled(format!("{:<10}", text), Style::default().fg(theme.text_secondary));
+        let mut lines = Vec::new();
+
+        let repo_path = state.git_manager.get_repo_path(&student.github_username, &self.class.name);
+        lines.push(Line::from(vec![
+            label("Clone"),
+            if repo_path.exists() {
+                Span::styled(repo_path.display().to_string(), Style::default().fg(theme.text))
+            } else {
+                Span::styled("not cloned", Style::default().fg(theme.error))
+            },
+        ]));
+
+        lines.push(Line::from(vec![
+            label("Snapshot"),
+            match self.context.snapshots.get(&student.id) {
+                Some(snapshot) => Span::styled(
+                    format!("{} ({})", short_sha(&snapshot.sha), self.assignment.snapshot_tag()),
+                    Style::default().fg(theme.primary),
+                ),
+                None => Span::styled("none, clone is at its latest commit", Style::default().fg(theme.text_secondary)),
+            },
+        ]));
+
+        lines.push(Line::from(vec![
+            label("Tests"),
+            match self.context.test_results.get(&student.id) {
+                Some(result) => {
+                    let color = if result.status == RunStatus::Passed { theme.success } else { theme.error };
+                    Span::styled(format!("{} {}", result.status.label(), result.summary()), Style::default().fg(color))
+                }
+                None => Span::styled("not run", Style::default().fg(theme.text_secondary)),
+            },
+        ]));
+
+        match self.context.site_checks.get(&student.id) {
+            Some(check) => {
+                let color = match check.report.status() {
+                    Severity::Pass => theme.success,
+                    Severity::Warn => theme.warning,
+                    Severity::Fail => theme.error,
+                };
+                lines.push(Line::from(vec![
+                    label("Site"),
+                    Span::styled(check.report.summary(), Style::default().fg(color)),
+                ]));
+                lines.push(Line::from(""));
+                lines.extend(check.report.findings.iter().map(|finding| {
+                    let (icon, color) = match finding.severity {
+                        Severity::Fail => ("✗ ", theme.error),
+                        Severity::Warn => ("⚠ ", theme.warning),
+                        Severity::Pass => ("✓ ", theme.success),
+                    };
+                    let mut spans = vec![Span::styled(icon, Style::default().fg(color))];
+                    if let Some(location) = finding.location() {
+                        spans.push(Span::styled(format!("{} ", location), Style::default().fg(theme.primary)));
+                    }
+                    spans.push(Span::styled(finding.message.clone(), Style::default().fg(theme.text)));
+                    Line::from(spans)
+                }));
+            }
+            None => lines.push(Line::from(vec![
+                label("Site"),
+                Span::styled("not checked", Style::default().fg(theme.text_secondary)),
+            ])),
+        }
+
+        let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_rubric(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Rubric")
+            .border_style(theme.border_focused_style());
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let mut constraints: Vec<Constraint> = self.inputs.iter().map(|_| Constraint::Length(3)).collect();
+        constraints.push(Constraint::Length(2)); // Total and status
+        constraints.push(Constraint::Min(0));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner_area);
+
+        for (index, (input, chunk)) in self.inputs.iter().zip(chunks.iter()).enumerate() {
+            // The selected input is marked while choosing; while editing it shows a cursor instead
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(2), Constraint::Min(1)])
+                .split(*chunk);
+            if index == self.focused_input && !self.editing {
+                let marker = Rect { y: columns[0].y + 1, height: 1.min(columns[0].height), ..columns[0] };
+                frame.render_widget(Paragraph::new(Span::styled("▶", Style::default().fg(theme.primary))), marker);
+            }
+            frame.render_widget(input, columns[1]);
+        }
+
+        let status_area = chunks[self.inputs.len()];
+        let line = if let Some(error) = &self.error {
+            Line::from(Span::styled(error.clone(), Style::default().fg(theme.error)))
+        } else if self.rubric.is_empty() {
+            Line::from(Span::styled(
+                format!("No rubric yet. Press {} on the Assignments screen to add one.", keymap::label(&[Action::EditRubric])),
+                Style::default().fg(theme.warning),
+            ))
+        } else {
+            let max: f64 = self.rubric.iter().map(|criterion| criterion.max_points).sum();
+            let total = self.entered_scores().map(|scores| format_points(scores.values().sum())).unwrap_or_else(|_| "?".to_string());
+            let saved = match self.current_student().and_then(|student| self.grades.get(&student.id)) {
+                Some(grade) => Span::styled(
+                    format!("  saved {}", time::format(grade.graded_at, "%b %d %H:%M")),
+                    Style::default().fg(theme.success),
+                ),
+                None => Span::styled("  not graded", Style::default().fg(theme.text_secondary)),
+            };
+            Line::from(vec![
+                Span::styled(format!("Total: {} / {}", total, format_points(max)), Style::default().fg(theme.text).add_modifier(Modifier::BOLD)),
+                saved,
+            ])
+        };
+        frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), status_area);
+    }
+}
+
+impl Screen for GradingScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.editing
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::Grading)
+            .with_context(ScreenContext::ClassAndAssignment(self.class.clone(), self.assignment.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.confirm_discard.is_visible() {
+                return Ok(match self.confirm_discard.handle_key_event(key) {
+                    Some(true) => Some(AppEvent::GoBack),
+                    _ => None,
+                });
+            }
+
+            let fields = self.inputs.len();
+            // Up and down are ordinary keys while typing, since j and k are bound to them
+            match keymap::action_for(Scope::Grading.actions(), &key) {
+                Some(Action::Next) => self.focus_input((self.focused_input + 1) % fields),
+                Some(Action::Previous) => self.focus_input((self.focused_input + fields - 1) % fields),
+                Some(Action::Down) if !self.editing => self.focus_input((self.focused_input + 1) % fields),
+                Some(Action::Up) if !self.editing => self.focus_input((self.focused_input + fields - 1) % fields),
+                Some(Action::NextStudent) => self.save_and_move(state, self.current + 1).await?,
+                Some(Action::PreviousStudent) if self.current > 0 => self.save_and_move(state, self.current - 1).await?,
+                Some(Action::Select) if self.editing => self.save_and_next(state).await?,
+                Some(Action::Select) => self.set_editing(true),
+                Some(Action::Back) if self.editing => self.set_editing(false),
+                Some(Action::Back) if self.is_dirty() => {
+                    let username = self.current_student().map(|student| student.github_username.clone()).unwrap_or_default();
+                    self.confirm_discard = ConfirmationDialog::new(
+                        "Unsaved Grade",
+                        &format!("Leave without saving the changes to {}'s grade?", username),
+                    ).with_yes_text("Discard").with_no_text("Keep grading");
+                    self.confirm_discard.show();
+                }
+                Some(Action::Back) => return Ok(Some(AppEvent::GoBack)),
+                _ if self.editing => {
+                    self.inputs[self.focused_input].handle_key_event(key);
+                    self.error = None;
+                }
+                _ => {}
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirm_discard.is_visible() {
+            self.confirm_discard.key_for_click(mouse)
+        } else if self.editing {
+            None
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        for input in &mut self.inputs {
+            input.update(delta_time);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let title = match self.current_student() {
+            Some(student) => format!(
+                "📝 {} - {} ({}/{})",
+                self.assignment.name,
+                student.github_username,
+                self.current + 1,
+                self.students.len()
+            ),
+            None => format!("📝 {}", self.assignment.name),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(5),     // Repository status and rubric
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(45),
+                Constraint::Percentage(55),
+            ])
+            .split(chunks[0]);
+
+        self.render_repo_status(frame, panes[0], state, theme);
+        self.render_rubric(frame, panes[1], theme);
+
+        let key = |actions: &[Action]| Span::styled(keymap::label(actions), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD));
+        let text = |text: &'static str| Span::styled(text, Style::default().fg(theme.text_secondary));
+        let help_text = if self.editing {
+            Line::from(vec![
+                key(&[Action::Next]),
+                text(" Next Field  "),
+                key(&[Action::Select]),
+                text(" Save & Next Student  "),
+                key(&[Action::PreviousStudent, Action::NextStudent]),
+                text(" Save & Previous/Next Student  "),
+                key(&[Action::Back]),
+                text(" Stop Editing"),
+            ])
+        } else {
+            Line::from(vec![
+                key(&[Action::Up, Action::Down]),
+                text(" Choose Field  "),
+                key(&[Action::Select]),
+                text(" Edit  "),
+                key(&[Action::PreviousStudent, Action::NextStudent]),
+                text(" Save & Previous/Next Student  "),
+                key(&[Action::Back]),
+                text(" Back"),
+            ])
+        };
+        let help_paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[1]);
+
+        self.confirm_discard.render(frame, area, theme);
+    }
+}
@@ -0,0 +1,467 @@
+use std::borrow::Cow;
+
+use crate::{
+    app::{keymap, Action},
+    ui::components::menu::{MenuItem, MenuPresets},
+};
+
+use super::{class_management::ClassManagementScreen, class_selection::ClassSelectionScreen, repo_management::RepoManagementScreen, ScreenTypeVariant};
+
+/// The keys for a help entry: actions follow the keymap, hotkeys and items come from the
+/// screen's menu, and the keys of text forms are fixed
+#[derive(Debug, Clone)]
+pub enum HelpKeys {
+    Bound(&'static [Action]),
+    Hotkey(char),
+    /// A menu item without a hotkey, picked with Select
+    Item(String),
+    Fixed(&'static str),
+}
+
+impl HelpKeys {
+    pub fn label(&self) -> String {
+        match self {
+            HelpKeys::Bound(actions) => keymap::all_labels(actions),
+            HelpKeys::Hotkey(key) => key.to_string(),
+            HelpKeys::Item(title) => title.clone(),
+            HelpKeys::Fixed(keys) => keys.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HelpEntry {
+    pub keys: HelpKeys,
+    pub description: Cow<'static, str>,
+    /// What the action really does, for ones whose name doesn't say, e.g. which git commands run
+    pub detail: Option<Cow<'static, str>>,
+}
+
+impl HelpEntry {
+    fn explained(mut self, detail: &'static str) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+fn bound(actions: &'static [Action], description: &'static str) -> HelpEntry {
+    HelpEntry { keys: HelpKeys::Bound(actions), description: description.into(), detail: None }
+}
+
+fn fixed(keys: &'static str, description: &'static str) -> HelpEntry {
+    HelpEntry { keys: HelpKeys::Fixed(keys), description: description.into(), detail: None }
+}
+
+/// Every menu item that has a hotkey, under its title
+fn hotkey_entries(items: &[MenuItem]) -> Vec<HelpEntry> {
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(HelpEntry {
+                keys: HelpKeys::Hotkey(item.hotkey?),
+                description: item.title.clone().into(),
+                detail: item.detail.clone().map(Cow::from),
+            })
+        })
+        .collect()
+}
+
+/// The menu items that explain themselves, for menus without hotkeys
+fn item_entries(items: &[MenuItem]) -> Vec<HelpEntry> {
+    items
+        .iter()
+        .filter_map(|item| {
+            Some(HelpEntry {
+                keys: HelpKeys::Item(item.title.clone()),
+                description: item.description.clone().unwrap_or_else(|| item.title.clone()).into(),
+                detail: Some(item.detail.clone()?.into()),
+            })
+        })
+        .collect()
+}
+
+/// Entries under a heading, e.g. one mode of a screen
+#[derive(Debug, Clone)]
+pub struct HelpSection {
+    pub title: &'static str,
+    pub entries: Vec<HelpEntry>,
+}
+
+fn section(title: &'static str, entries: Vec<HelpEntry>) -> HelpSection {
+    HelpSection { title, entries }
+}
+
+const NAVIGATE: &[Action] = &[Action::Up, Action::Down];
+const SCROLL_WEEKS: &[Action] = &[Action::Left, Action::Right];
+const SORTING: &[Action] = &[Action::Sort, Action::ReverseSort];
+const MARK_MANY: &[Action] = &[Action::SelectAll, Action::SelectNone, Action::InvertSelection];
+
+fn form_entries(save: &'static str) -> Vec<HelpEntry> {
+    vec![
+        fixed("Tab/Shift+Tab", "Next or previous field"),
+        fixed("Enter", save),
+        fixed("Esc", "Cancel"),
+    ]
+}
+
+fn table_filter_entries() -> Vec<HelpEntry> {
+    vec![
+        bound(SORTING, "Next sort column / reverse the order"),
+        bound(&[Action::Filter], "Filter by name or GitHub username; Enter keeps it, Esc clears it"),
+        bound(&[Action::OnlyInactive], "Only students without activity"),
+        bound(&[Action::OnlyErrors], "Only students whose activity couldn't be fetched"),
+    ]
+}
+
+/// Everything a screen responds to, grouped by the mode it applies in. Keys that work
+/// everywhere are listed last.
+pub fn for_screen(variant: &ScreenTypeVariant) -> Vec<HelpSection> {
+    let mut sections = match variant {
+        ScreenTypeVariant::MainMenu => vec![section(
+            "Main menu",
+            [
+                vec![bound(NAVIGATE, "Move through the menu"), bound(&[Action::Select], "Open the selected item")],
+                hotkey_entries(MenuPresets::main_menu().items()),
+            ]
+            .concat(),
+        )],
+        ScreenTypeVariant::ClassSelection => vec![section(
+            "Classes",
+            [
+                vec![bound(NAVIGATE, "Move through the classes"), bound(&[Action::Select], "Open the class dashboard")],
+                hotkey_entries(&ClassSelectionScreen::action_items()),
+                vec![bound(&[Action::Refresh], "Reload the class list"), bound(&[Action::Back], "Back")],
+            ]
+            .concat(),
+        )],
+        ScreenTypeVariant::CreateClass => vec![section(
+            "New class",
+            vec![
+                fixed("Enter", "Create the class with the typed name"),
+                fixed("Esc", "Cancel"),
+            ],
+        )],
+        ScreenTypeVariant::ClassManagement => vec![section(
+            "Class menu",
+            [
+                vec![bound(NAVIGATE, "Move through the menu"), bound(&[Action::Select], "Open the selected item")],
+                hotkey_entries(&ClassManagementScreen::menu_items()),
+                vec![bound(&[Action::Back], "Back")],
+            ]
+            .concat(),
+        )],
+        ScreenTypeVariant::StudentManagement => vec![section(
+            "Students",
+            vec![
+                bound(NAVIGATE, "Move through the menu"),
+                bound(&[Action::Select], "Add students, delete a student or open notes"),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::AddStudents => vec![section(
+            "Add students",
+            vec![
+                fixed("Enter", "Add the typed GitHub usernames").explained(
+                    "Separate several usernames with commas.",
+                ),
+                fixed("Esc", "Back"),
+            ],
+        )],
+        ScreenTypeVariant::DeleteStudent | ScreenTypeVariant::RemoveStudent => vec![section(
+            "Delete a student",
+            vec![
+                bound(NAVIGATE, "Move through the students"),
+                bound(&[Action::ToggleSelection], "Mark or unmark the highlighted student"),
+                bound(MARK_MANY, "Mark everyone, no one, or invert the marks"),
+                bound(&[Action::Select], "Delete the marked students, or the highlighted one if none are marked").explained(
+                    "Asks once for all of them, then reports how each delete went. Their local clones are left on disk.",
+                ),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::RepositoryManagement => vec![
+            section(
+                "Repository menu",
+                vec![
+                    bound(NAVIGATE, "Move through the menu"),
+                    bound(&[Action::Select], "Clone all, pick a student or check every site").explained(
+                        "Clone All clones each student's <username>.github.io repository into the class folder. Existing clones are left alone.",
+                    ),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section(
+                "Student list",
+                vec![
+                    bound(NAVIGATE, "Move through the students"),
+                    bound(&[Action::Select], "Show actions for the student"),
+                    bound(&[Action::ToggleSelection], "Mark or unmark the student for a batch action"),
+                    bound(MARK_MANY, "Mark everyone, no one, or invert the marks"),
+                    bound(&[Action::BatchActions], "Batch actions on the marked students"),
+                    bound(&[Action::Details], "Student details"),
+                    bound(&[Action::Shell], "Suspend scv and open a shell in their clone"),
+                    bound(&[Action::CheckSite], "Check their site's health"),
+                    bound(&[Action::Back], "Back to the repository menu"),
+                ],
+            ),
+            section(
+                "Batch actions",
+                [
+                    vec![
+                        bound(NAVIGATE, "Move through the actions"),
+                        bound(&[Action::Select], "Run the action on every marked student").explained(
+                            "One confirmation covers the whole batch; afterwards each student's result is listed. A failure doesn't stop the rest.",
+                        ),
+                    ],
+                    item_entries(&RepoManagementScreen::batch_menu_items()),
+                    vec![bound(&[Action::Back], "Back to the student list")],
+                ]
+                .concat(),
+            ),
+            section(
+                "Student actions",
+                [
+                    vec![bound(NAVIGATE, "Move through the actions"), bound(&[Action::Select], "Run the selected action")],
+                    item_entries(&RepoManagementScreen::student_menu_items()),
+                    vec![
+                        bound(&[Action::Details], "Student details"),
+                        bound(&[Action::Shell], "Suspend scv and open a shell in their clone"),
+                        bound(&[Action::CheckSite], "Check their site's health").explained(
+                            "Looks for broken links, missing alt text and other GitHub Pages problems.",
+                        ),
+                        bound(&[Action::Back], "Back to the student list"),
+                    ],
+                ]
+                .concat(),
+            ),
+        ],
+        ScreenTypeVariant::GitHubActivity => vec![section(
+            "GitHub activity",
+            vec![
+                bound(NAVIGATE, "Move through the menu"),
+                bound(&[Action::Select], "Open the week view or latest activity"),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::WeekView => vec![
+            section(
+                "Week view",
+                vec![
+                    bound(NAVIGATE, "Move through the students"),
+                    bound(SCROLL_WEEKS, "Previous or next week"),
+                    bound(&[Action::ThisWeek], "Back to this week"),
+                    bound(&[Action::CustomRange], "Show a custom date range"),
+                    bound(&[Action::EarlierDays, Action::LaterDays], "Scroll the days when a range is too wide to fit"),
+                    bound(&[Action::ToggleWeekends], "Show or hide weekends"),
+                    bound(&[Action::Select], "Student details"),
+                    bound(&[Action::Refresh], "Fetch activity again from GitHub"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section("Sorting and filtering", table_filter_entries()),
+        ],
+        ScreenTypeVariant::LatestActivity => vec![
+            section(
+                "Latest activity",
+                vec![
+                    bound(NAVIGATE, "Move through the students"),
+                    bound(&[Action::Select], "Student details"),
+                    bound(&[Action::Refresh], "Fetch activity again from GitHub"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section("Sorting and filtering", table_filter_entries()),
+        ],
+        ScreenTypeVariant::FileBrowser => vec![
+            section(
+                "File tree",
+                vec![
+                    bound(NAVIGATE, "Move through the files"),
+                    bound(&[Action::Select], "Open a file or expand a folder"),
+                    bound(&[Action::Right, Action::Left], "Expand or collapse a folder"),
+                    bound(&[Action::Next], "Switch to the open file"),
+                    bound(&[Action::Refresh], "Reload the tree from disk"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section(
+                "File viewer",
+                vec![
+                    bound(NAVIGATE, "Scroll a line"),
+                    bound(&[Action::PageUp, Action::PageDown], "Scroll a page"),
+                    bound(&[Action::Top, Action::Bottom], "Jump to the top or bottom"),
+                    bound(&[Action::Next, Action::Back, Action::Left], "Back to the file tree"),
+                ],
+            ),
+        ],
+        ScreenTypeVariant::SitePreview => vec![section(
+            "Site preview",
+            vec![
+                bound(NAVIGATE, "Choose the working copy or a commit"),
+                bound(&[Action::Select], "Serve the selection locally").explained(
+                    "Commits are read straight from git, so the clone's checkout is never touched.",
+                ),
+                bound(&[Action::OpenInBrowser], "Open the preview in your browser"),
+                bound(&[Action::LiveReload], "Turn live reload on or off"),
+                bound(&[Action::StopServer], "Stop the server"),
+                bound(&[Action::Refresh], "Reload the commit list"),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::TestRunner => vec![
+            section(
+                "Results",
+                vec![
+                    bound(NAVIGATE, "Move through the students"),
+                    bound(&[Action::Select], "Show the selected student's output"),
+                    bound(&[Action::RunTests], "Run the test command in every clone; Back cancels a run in progress"),
+                    bound(&[Action::Edit], "Edit the test command, timeout and concurrency"),
+                    bound(&[Action::Export], "Export the results as CSV"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section(
+                "Output",
+                vec![
+                    bound(NAVIGATE, "Scroll a line"),
+                    bound(&[Action::PageUp, Action::PageDown], "Scroll a page"),
+                    bound(&[Action::Back, Action::Select], "Back to the results"),
+                ],
+            ),
+            section("Test settings", form_entries("Save the settings")),
+        ],
+        ScreenTypeVariant::Assignments => vec![
+            section(
+                "Assignments",
+                vec![
+                    bound(NAVIGATE, "Move through the assignments"),
+                    bound(&[Action::Select, Action::CheckSubmissions], "Check who submitted before the deadline"),
+                    bound(&[Action::Snapshot], "Snapshot every clone at the deadline").explained(
+                        "Tags each student's last commit before the due date as deadline/<id>-<assignment> and checks it out as a detached HEAD. An existing tag on a different commit is left alone and reported.",
+                    ),
+                    bound(&[Action::RestoreLatest], "Return every clone to its latest commit").explained(
+                        "Fetches, checks out the default branch and fast-forwards it to origin, leaving any snapshot. Clones with unpushed commits are reported instead.",
+                    ),
+                    bound(&[Action::Grade], "Grade the assignment"),
+                    bound(&[Action::EditRubric], "Edit the rubric"),
+                    bound(&[Action::New], "New assignment"),
+                    bound(&[Action::Delete], "Delete the assignment").explained("Student repositories are not affected."),
+                    bound(&[Action::Export], "Export the gradebook as CSV"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section("Assignment and rubric forms", form_entries("Save")),
+        ],
+        ScreenTypeVariant::Grading => vec![
+            section(
+                "Choosing a field",
+                vec![
+                    bound(NAVIGATE, "Previous or next field"),
+                    bound(&[Action::Previous, Action::Next], "Previous or next field"),
+                    bound(&[Action::Select], "Edit the field"),
+                    bound(&[Action::PreviousStudent, Action::NextStudent], "Save any changes and go to the previous or next student"),
+                    bound(&[Action::Back], "Back").explained("Asks first if the grade has unsaved changes."),
+                ],
+            ),
+            section(
+                "Editing a field",
+                vec![
+                    bound(&[Action::Previous, Action::Next], "Edit the previous or next field"),
+                    bound(&[Action::Select], "Save the grade and go to the next student"),
+                    bound(&[Action::PreviousStudent, Action::NextStudent], "Save the grade and go to the previous or next student"),
+                    bound(&[Action::Back], "Stop editing, keeping what was typed"),
+                ],
+            ),
+        ],
+        ScreenTypeVariant::StudentNotes => vec![
+            section(
+                "Notes",
+                vec![
+                    bound(NAVIGATE, "Move through students or notes"),
+                    bound(&[Action::Next, Action::Left, Action::Right], "Switch between students and notes"),
+                    bound(&[Action::Select], "Open the student's notes, or edit the selected note"),
+                    bound(&[Action::New], "New note"),
+                    bound(&[Action::Edit], "Edit the selected note"),
+                    bound(&[Action::Delete], "Delete the selected note"),
+                    bound(&[Action::Filter], "Search every note"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section(
+                "Editing and search",
+                vec![fixed("Enter", "Save the note or run the search"), fixed("Esc", "Cancel")],
+            ),
+        ],
+        ScreenTypeVariant::StudentDetail => vec![section(
+            "Student details",
+            vec![
+                bound(&[Action::OpenNotes], "Notes"),
+                bound(&[Action::OpenFiles], "Browse their files"),
+                bound(&[Action::OpenPreview], "Preview their site"),
+                bound(&[Action::OpenHeatmap], "Activity heatmap"),
+                bound(&[Action::OpenInBrowser], "Open their site in your browser"),
+                bound(&[Action::Refresh], "Reload"),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::SchoolCalendar => vec![
+            section(
+                "Calendar",
+                vec![
+                    bound(NAVIGATE, "Move through the entries"),
+                    bound(&[Action::New], "Add a holiday or break"),
+                    bound(&[Action::AddSchoolDay], "Add a make-up school day"),
+                    bound(&[Action::Import], "Import days off from an .ics file, choosing which events to keep"),
+                    bound(&[Action::Delete], "Delete the selected entry"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section("Entry form", form_entries("Save the entry")),
+        ],
+        ScreenTypeVariant::ClassDashboard => vec![
+            section(
+                "Dashboard",
+                vec![
+                    bound(NAVIGATE, "Move through the students"),
+                    bound(&[Action::Select], "Student details"),
+                    bound(&[Action::OpenWeekView], "Week view"),
+                    bound(&[Action::OpenHeatmap], "Activity heatmap"),
+                    bound(&[Action::OpenClassMenu], "Class menu"),
+                    bound(&[Action::EditThresholds], "Alert thresholds and metric settings"),
+                    bound(&[Action::Refresh], "Fetch every student's commit history again"),
+                    bound(&[Action::Back], "Back"),
+                ],
+            ),
+            section("Settings form", form_entries("Save the settings")),
+        ],
+        ScreenTypeVariant::ActivityHeatmap => vec![section(
+            "Activity heatmap",
+            vec![
+                bound(SCROLL_WEEKS, "Move the cursor a day"),
+                bound(NAVIGATE, "Move the cursor a week"),
+                bound(&[Action::ThisWeek, Action::Top], "Jump to today"),
+                bound(&[Action::Next, Action::Previous], "Next or previous student, or the whole class"),
+                bound(&[Action::UpdateHistory], "Update the commit cache").explained(
+                    "Reads commit times from local clones, or from GitHub when there is no clone, and stores them for the heatmap and metrics.",
+                ),
+                bound(&[Action::Select], "Student details"),
+                bound(&[Action::Back], "Back"),
+            ],
+        )],
+        ScreenTypeVariant::Settings | ScreenTypeVariant::ConfirmDeleteClass => vec![section(
+            "Navigation",
+            vec![bound(NAVIGATE, "Move"), bound(&[Action::Select], "Select"), bound(&[Action::Back], "Back")],
+        )],
+    };
+
+    sections.push(section(
+        "Everywhere",
+        vec![
+            bound(&[Action::CommandPalette], "Command palette: jump to any class, student or action"),
+            bound(&[Action::Help], "Show or hide this help"),
+            bound(&[Action::Quit], "Quit, except while typing"),
+            fixed("Ctrl+C", "Quit"),
+        ],
+    ));
+    sections
+}
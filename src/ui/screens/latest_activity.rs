@@ -1,5 +1,5 @@
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
@@ -7,47 +7,123 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState},
     Frame,
 };
-use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+use std::{cmp::Ordering, collections::HashMap, future::Future, pin::Pin, time::Duration};
 use chrono::{DateTime, Utc};
 
-use crate::app::{AppEvent, AppState};
+use crate::app::{keymap, Action, AppEvent, AppState, Scope};
 use crate::data::github::GitHubClient;
-use crate::data::models::Student;
+use crate::data::models::{Class, Student};
+use crate::utils::time;
 use crate::ui::{
     animations::AnimationState,
-    screens::{Screen, ScreenType, ScreenTypeVariant},
+    components::table_filter::{SortColumn, TableFilter},
+    mouse::MouseAction,
+    screens::{scroll_key, table_mouse_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
     themes::Theme,
 };
 
+/// Columns the latest activity table can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LatestSort {
+    Name,
+    GitHubUsername,
+    LastCommit,
+    Errors,
+}
+
+impl SortColumn for LatestSort {
+    const ALL: &'static [Self] = &[
+        LatestSort::Name,
+        LatestSort::GitHubUsername,
+        LatestSort::LastCommit,
+        LatestSort::Errors,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            LatestSort::Name => "Name",
+            LatestSort::GitHubUsername => "GitHub username",
+            LatestSort::LastCommit => "Last commit",
+            LatestSort::Errors => "Errors",
+        }
+    }
+}
+
 pub struct LatestActivityScreen {
+    class: Class,
     students: Vec<Student>,
-    latest_activity_data: HashMap<String, Option<DateTime<Utc>>>,
+    /// Latest commit per GitHub username, or why it couldn't be fetched
+    latest_activity_data: HashMap<String, Result<Option<DateTime<Utc>>, String>>,
     table_state: TableState,
+    /// The table's area inside its block when last drawn, for mouse hit-testing
+    table_area: Rect,
+    filter: TableFilter<LatestSort>,
     is_loading: bool,
     error_message: Option<String>,
 }
 
 impl LatestActivityScreen {
-    pub fn new(students: Vec<Student>) -> Self {
+    pub fn new(class: Class, students: Vec<Student>) -> Self {
         let mut table_state = TableState::default();
         if !students.is_empty() {
             table_state.select(Some(0));
         }
 
         Self {
+            class,
             students,
             latest_activity_data: HashMap::new(),
             table_state,
+            table_area: Rect::default(),
+            filter: TableFilter::new(LatestSort::Name),
             is_loading: false,
             error_message: None,
         }
     }
 
-    pub fn render(&mut self, f: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect) {
+    /// Indices into `students` that pass the filter, in sort order
+    fn visible_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self
+            .students
+            .iter()
+            .enumerate()
+            .filter(|(_, student)| {
+                let activity = self.latest_activity_data.get(&student.github_username);
+                (!self.filter.only_inactive || matches!(activity, Some(Ok(None))))
+                    && (!self.filter.only_errors || matches!(activity, Some(Err(_))))
+                    && self.filter.score(&[&student.username, &student.github_username]).is_some()
+            })
+            .map(|(index, _)| index)
+            .collect();
+        rows.sort_by(|a, b| self.filter.order(self.compare_rows(&self.students[*a], &self.students[*b])));
+        rows
+    }
+
+    fn compare_rows(&self, first: &Student, second: &Student) -> Ordering {
+        let activity = |student: &Student| self.latest_activity_data.get(&student.github_username);
+        let latest = |student: &Student| activity(student).and_then(|result| result.as_ref().ok().copied().flatten());
+        let failed = |student: &Student| matches!(activity(student), Some(Err(_)));
+        let by_name = first.username.to_lowercase().cmp(&second.username.to_lowercase());
+        let ordering = match self.filter.sort {
+            LatestSort::Name => Ordering::Equal,
+            LatestSort::GitHubUsername => first.github_username.to_lowercase().cmp(&second.github_username.to_lowercase()),
+            LatestSort::LastCommit => latest(first).cmp(&latest(second)),
+            LatestSort::Errors => failed(first).cmp(&failed(second)),
+        };
+        ordering.then(by_name)
+    }
+
+    fn selected_student(&self) -> Option<&Student> {
+        let selected = self.table_state.selected()?;
+        self.visible_rows().get(selected).and_then(|index| self.students.get(*index))
+    }
+
+    pub fn render(&mut self, f: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3),
+                Constraint::Length(self.filter.height()),
                 Constraint::Min(0),
                 Constraint::Length(3),
             ])
@@ -59,20 +135,34 @@ impl LatestActivityScreen {
             .style(Style::default().fg(Color::Cyan));
         f.render_widget(title, chunks[0]);
 
+        let visible = self.visible_rows();
+        self.filter.render(f, chunks[1], theme, visible.len(), self.students.len());
+
         // Main content area
         if self.is_loading {
-            self.render_loading(f, chunks[1]);
+            self.render_loading(f, chunks[2]);
         } else if let Some(error) = &self.error_message {
-            self.render_error(f, chunks[1], error);
+            self.render_error(f, chunks[2], error);
         } else {
-            self.render_table(f, chunks[1]);
+            self.render_table(f, chunks[2], &visible);
         }
 
         // Instructions
-        let instructions = Paragraph::new("↑/↓: Navigate  r: Refresh timestamps  q: Back")
+        let instructions = Paragraph::new(format!(
+            "{}: Navigate  {}: Student details  {}: Sort/reverse  {}: Filter  {}: No activity only  {}: Errors only  {}: Refresh timestamps  {}: Back  {}: Help",
+            keymap::label(&[Action::Up, Action::Down]),
+            keymap::label(&[Action::Select]),
+            keymap::label(&[Action::Sort, Action::ReverseSort]),
+            keymap::label(&[Action::Filter]),
+            keymap::label(&[Action::OnlyInactive]),
+            keymap::label(&[Action::OnlyErrors]),
+            keymap::label(&[Action::Refresh]),
+            keymap::label(&[Action::Back]),
+            keymap::label(&[Action::Help]),
+        ))
             .block(Block::default().borders(Borders::ALL))
             .style(Style::default().fg(Color::Gray));
-        f.render_widget(instructions, chunks[2]);
+        f.render_widget(instructions, chunks[3]);
     }
 
     fn render_loading(&self, f: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect) {
@@ -89,21 +179,19 @@ impl LatestActivityScreen {
         f.render_widget(error_text, area);
     }
 
-    fn render_table(&mut self, f: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect) {
+    fn render_table(&mut self, f: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, visible: &[usize]) {
         let header = Row::new(vec!["Student", "GitHub Username", "Last Commit"])
             .style(Style::default().fg(Color::Yellow))
             .height(1);
 
-        let rows: Vec<Row> = self.students.iter().map(|student| {
+        let rows: Vec<Row> = visible.iter().map(|index| {
+            let student = &self.students[*index];
             let github_username = &student.github_username;
-            let latest_activity = if let Some(activity) = self.latest_activity_data.get(github_username) {
-                if let Some(datetime) = activity {
-                    format_time_ago(*datetime)
-                } else {
-                    "No commits found".to_string()
-                }
-            } else {
-                "Loading...".to_string()
+            let latest_activity = match self.latest_activity_data.get(github_username) {
+                Some(Ok(Some(datetime))) => time::format_time_ago(*datetime),
+                Some(Ok(None)) => "No commits found".to_string(),
+                Some(Err(e)) => format!("Error: {}", e),
+                None => "Loading...".to_string(),
             };
 
             Row::new(vec![
@@ -113,6 +201,8 @@ impl LatestActivityScreen {
             ])
         }).collect();
 
+        let block = Block::default().borders(Borders::ALL);
+        self.table_area = block.inner(area);
         let table = Table::new(rows)
         .widths(&[
             Constraint::Length(20),
@@ -120,15 +210,20 @@ impl LatestActivityScreen {
             Constraint::Min(25),
         ])
         .header(header)
-        .block(Block::default().borders(Borders::ALL))
+        .block(block)
         .highlight_style(Style::default().fg(Color::Black).bg(Color::White));
 
         f.render_stateful_widget(table, area, &mut self.table_state);
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<AppEvent>> {
-        match key.code {
-            KeyCode::Up => {
+        if self.filter.handle_key_event(key) {
+            self.table_state.select(if self.visible_rows().is_empty() { None } else { Some(0) });
+            return Ok(None);
+        }
+
+        match keymap::action_for(Scope::LatestActivity.actions(), &key) {
+            Some(Action::Up) => {
                 if let Some(selected) = self.table_state.selected() {
                     if selected > 0 {
                         self.table_state.select(Some(selected - 1));
@@ -136,20 +231,23 @@ impl LatestActivityScreen {
                 }
                 Ok(None)
             }
-            KeyCode::Down => {
+            Some(Action::Down) => {
                 if let Some(selected) = self.table_state.selected() {
-                    if selected < self.students.len().saturating_sub(1) {
+                    if selected < self.visible_rows().len().saturating_sub(1) {
                         self.table_state.select(Some(selected + 1));
                     }
-                } else if !self.students.is_empty() {
+                } else if !self.visible_rows().is_empty() {
                     self.table_state.select(Some(0));
                 }
                 Ok(None)
             }
-            KeyCode::Char('r') => {
+            Some(Action::Select) => {
+                Ok(self.selected_student().map(|student| AppEvent::ShowStudentDetail(student.clone())))
+            }
+            Some(Action::Refresh) => {
                 Ok(Some(AppEvent::RefreshLatestActivity))
             }
-            KeyCode::Char('q') => {
+            Some(Action::Back) => {
                 Ok(Some(AppEvent::GoBack))
             }
             _ => Ok(None),
@@ -164,15 +262,11 @@ impl LatestActivityScreen {
 
         for student in &self.students {
             let github_username = &student.github_username;
-            match github_client.get_latest_activity(github_username).await {
-                Ok(latest_activity) => {
-                    activity_data.insert(github_username.clone(), latest_activity);
-                }
-                Err(e) => {
-                    eprintln!("Error fetching latest activity for {}: {}", github_username, e);
-                    activity_data.insert(github_username.clone(), None);
-                }
-            }
+            let latest_activity = github_client
+                .get_latest_activity(github_username)
+                .await
+                .map_err(|e| e.to_string());
+            activity_data.insert(github_username.clone(), latest_activity);
         }
 
         self.latest_activity_data = activity_data;
@@ -191,8 +285,13 @@ impl Screen for LatestActivityScreen {
         self
     }
 
+    fn captures_text_input(&self) -> bool {
+        self.filter.is_editing()
+    }
+
     fn screen_type(&self) -> ScreenType {
         ScreenType::new(ScreenTypeVariant::LatestActivity)
+            .with_context(ScreenContext::Class(self.class.clone()))
     }
 
     fn handle_key_event<'a>(
@@ -204,11 +303,30 @@ impl Screen for LatestActivityScreen {
         Box::pin(async move { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // Clicks only pick rows while the table has the keyboard
+        let rows = self.visible_rows().len();
+        let key = if !self.captures_text_input() {
+            table_mouse_key(&mut self.table_state, self.table_area, rows, mouse, keymap::key_event(Action::Select))
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => Screen::handle_key_event(self, key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
     fn update<'a>(
         &'a mut self,
-        _delta_time: Duration,
+        delta_time: Duration,
         _state: &'a mut AppState,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        self.filter.update(delta_time);
         Box::pin(async { Ok(()) })
     }
 
@@ -218,65 +336,8 @@ impl Screen for LatestActivityScreen {
         area: Rect,
         _state: &AppState,
         _animation_state: &AnimationState,
-        _theme: &Theme,
+        theme: &Theme,
     ) {
-        self.render(frame, area);
+        self.render(frame, area, theme);
     }
 }
-
-fn format_time_ago(datetime: DateTime<Utc>) -> String {
-    let now = Utc::now();
-    let duration = now.signed_duration_since(datetime);
-    
-    let seconds = duration.num_seconds();
-    let minutes = duration.num_minutes();
-    let hours = duration.num_hours();
-    let days = duration.num_days();
-    
-    if seconds < 60 {
-        if seconds <= 1 {
-            "just now".to_string()
-        } else {
-            format!("{} seconds ago", seconds)
-        }
-    } else if minutes < 60 {
-        if minutes == 1 {
-            "1 minute ago".to_string()
-        } else {
-            format!("{} minutes ago", minutes)
-        }
-    } else if hours < 24 {
-        if hours == 1 {
-            "1 hour ago".to_string()
-        } else {
-            format!("{} hours ago", hours)
-        }
-    } else if days < 7 {
-        if days == 1 {
-            "1 day ago".to_string()
-        } else {
-            format!("{} days ago", days)
-        }
-    } else if days < 30 {
-        let weeks = days / 7;
-        if weeks == 1 {
-            "1 week ago".to_string()
-        } else {
-            format!("{} weeks ago", weeks)
-        }
-    } else if days < 365 {
-        let months = days / 30;
-        if months == 1 {
-            "1 month ago".to_string()
-        } else {
-            format!("{} months ago", months)
-        }
-    } else {
-        let years = days / 365;
-        if years == 1 {
-            "1 year ago".to_string()
-        } else {
-            format!("{} years ago", years)
-        }
-    }
-}
\ No newline at end of file
@@ -4,9 +4,10 @@ use ratatui::{Frame, layout::Rect};
 use std::{future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     ui::{
         animations::AnimationState,
+        mouse::MouseAction,
         components::menu::{AnimatedMenu, MenuBuilder, MenuItem, MenuPresets},
         screens::{Screen, ScreenType, ScreenTypeVariant},
         themes::Theme,
@@ -26,6 +27,17 @@ impl MainMenuScreen {
             menu,
         }
     }
+
+    /// What the selected item opens
+    fn activate(&self) -> Option<AppEvent> {
+        match self.menu.selected_item()?.title.as_str() {
+            "Manage Classes" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::ClassSelection))),
+            "Create Class" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass))),
+            "Settings" => Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::Settings))),
+            "Quit" => Some(AppEvent::Quit),
+            _ => None,
+        }
+    }
 }
 
 impl Screen for MainMenuScreen {
@@ -35,53 +47,34 @@ impl Screen for MainMenuScreen {
 
     fn handle_key_event(&mut self, key: KeyEvent, _state: &AppState) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + '_>> {
         let result = match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            _ if keymap::is(Action::Up, &key) => {
                 self.menu.select_previous();
                 Ok(None)
             },
-            KeyCode::Down | KeyCode::Char('j') => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.menu.select_next();
                 Ok(None)
             },
-            KeyCode::Enter | KeyCode::Char(' ') => {
-                if let Some(item) = self.menu.selected_item() {
-                    match item.title.as_str() {
-                        "Manage Classes" => {
-                            Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::ClassSelection))))
-                        },
-                        "Create Class" => {
-                            Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass))))
-                        },
-                        "Settings" => {
-                            Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::Settings))))
-                        },
-                        "Quit" => {
-                            Ok(Some(AppEvent::Quit))
-                        },
-                        _ => Ok(None),
-                    }
-                } else {
-                    Ok(None)
-                }
-            },
-            // Hotkeys
-            KeyCode::Char('m') => {
-                Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::ClassSelection))))
-            },
-            KeyCode::Char('c') => {
-                Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::CreateClass))))
-            },
-            KeyCode::Char('s') => {
-                Ok(Some(AppEvent::NavigateToScreen(ScreenType::new(ScreenTypeVariant::Settings))))
-            },
-            KeyCode::Char('q') => {
-                Ok(Some(AppEvent::Quit))
-            },
+            _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => Ok(self.activate()),
+            // Hotkeys, as defined on the menu items
+            KeyCode::Char(c) if self.menu.select_hotkey(c) => Ok(self.activate()),
             _ => Ok(None),
         };
         Box::pin(async move { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // A double click activates the item like Select
+        if let Some(key) = self.menu.handle_mouse_event(mouse).then(|| keymap::key_event(Action::Select)).flatten() {
+            return self.handle_key_event(key, state);
+        }
+        Box::pin(async { Ok(None) })
+    }
+
     fn update(&mut self, delta_time: Duration, _state: &mut AppState) -> Pin<Box<dyn Future<Output = Result<()>> + Send + '_>> {
         self.menu.update(delta_time, &AnimationState::new());
         Box::pin(async move { Ok(()) })
@@ -1,24 +1,35 @@
+pub mod activity_heatmap;
 pub mod add_students;
+pub mod assignments;
+pub mod calendar;
+pub mod class_dashboard;
 pub mod class_management;
 pub mod class_selection;
 pub mod create_class;
 pub mod delete_student;
+pub mod file_browser;
 pub mod main_menu;
+pub mod notes;
+pub mod student_detail;
 pub mod student_management;
+pub mod test_runner;
 pub mod github_activity;
+pub mod grading;
+pub mod help;
 pub mod repo_management;
+pub mod site_preview;
 pub mod week_view;
 pub mod latest_activity;
 
 use anyhow::Result;
 use crossterm::event::KeyEvent;
-use ratatui::{Frame, layout::Rect};
-use std::{future::Future, pin::Pin, time::Duration};
+use ratatui::{Frame, layout::Rect, widgets::TableState};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
-    data::{Class, Database, Student},
-    ui::{animations::AnimationState, themes::Theme},
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Assignment, Class, Database, Student},
+    ui::{animations::AnimationState, layout, mouse::MouseAction, themes::Theme},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -43,6 +54,12 @@ impl ScreenType {
     pub fn context(&self) -> Option<&ScreenContext> {
         self.context.as_ref()
     }
+
+    pub fn update_class(&mut self, class: &Class) {
+        if let Some(context) = &mut self.context {
+            context.update_class(class);
+        }
+    }
 }
 
 impl From<ScreenTypeVariant> for ScreenType {
@@ -67,6 +84,16 @@ pub enum ScreenTypeVariant {
     Settings,
     ConfirmDeleteClass,
     DeleteStudent,
+    FileBrowser,
+    SitePreview,
+    TestRunner,
+    Assignments,
+    Grading,
+    StudentNotes,
+    StudentDetail,
+    SchoolCalendar,
+    ClassDashboard,
+    ActivityHeatmap,
 }
 
 impl std::fmt::Display for ScreenTypeVariant {
@@ -86,6 +113,16 @@ impl std::fmt::Display for ScreenTypeVariant {
             ScreenTypeVariant::Settings => write!(f, "Settings"),
             ScreenTypeVariant::ConfirmDeleteClass => write!(f, "Confirm Delete Class"),
             ScreenTypeVariant::DeleteStudent => write!(f, "Delete Student"),
+            ScreenTypeVariant::FileBrowser => write!(f, "File Browser"),
+            ScreenTypeVariant::SitePreview => write!(f, "Site Preview"),
+            ScreenTypeVariant::TestRunner => write!(f, "Test Runner"),
+            ScreenTypeVariant::Assignments => write!(f, "Assignments"),
+            ScreenTypeVariant::Grading => write!(f, "Grading"),
+            ScreenTypeVariant::StudentNotes => write!(f, "Student Notes"),
+            ScreenTypeVariant::StudentDetail => write!(f, "Student Detail"),
+            ScreenTypeVariant::SchoolCalendar => write!(f, "School Calendar"),
+            ScreenTypeVariant::ClassDashboard => write!(f, "Class Dashboard"),
+            ScreenTypeVariant::ActivityHeatmap => write!(f, "Activity Heatmap"),
         }
     }
 }
@@ -113,6 +150,54 @@ pub trait Screen {
     fn render(&mut self, frame: &mut ratatui::Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, state: &AppState, animation_state: &AnimationState, theme: &Theme);
     
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    
+    /// Whether the screen is currently taking typed text, so global single-letter keys like `q` are passed through
+    fn captures_text_input(&self) -> bool {
+        false
+    }
+
+    /// Whether to keep this screen while others are opened on top of it, rather than rebuilding it on the
+    /// way back. Worth it for screens with state the user set up or data that is slow to fetch.
+    fn keep_when_covered(&self) -> bool {
+        false
+    }
+
+    /// Clicks select, double clicks activate and the wheel scrolls. By default the wheel
+    /// sends the keys bound to up and down, which is all a plain list needs.
+    fn handle_mouse_event<'a>(&'a mut self, mouse: MouseAction, state: &'a AppState) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        match scroll_key(mouse) {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+}
+
+/// The key the mouse wheel stands for: whatever up or down is bound to
+pub fn scroll_key(mouse: MouseAction) -> Option<KeyEvent> {
+    match mouse {
+        MouseAction::ScrollUp { .. } => keymap::key_event(Action::Up),
+        MouseAction::ScrollDown { .. } => keymap::key_event(Action::Down),
+        _ => None,
+    }
+}
+
+/// Mouse handling for a table drawn in `inner`, its area inside the block: a click selects
+/// the row under it, and the key returned is `activate` for a double click on a row or up
+/// and down for the wheel.
+pub fn table_mouse_key(
+    table_state: &mut TableState,
+    inner: Rect,
+    rows: usize,
+    mouse: MouseAction,
+    activate: Option<KeyEvent>,
+) -> Option<KeyEvent> {
+    if !matches!(mouse, MouseAction::Click { .. } | MouseAction::DoubleClick { .. }) {
+        return scroll_key(mouse);
+    }
+    let (column, row) = mouse.position();
+    let index = layout::table_row_at(inner, column, row, table_state.offset()).filter(|index| *index < rows)?;
+    table_state.select(Some(index));
+    if matches!(mouse, MouseAction::DoubleClick { .. }) { activate } else { None }
 }
 
 // Create a screen with the given type and optional context
@@ -157,7 +242,13 @@ pub async fn create_screen(screen_type: ScreenType) -> Result<Box<dyn Screen>> {
             if let Some(ScreenContext::Class(class)) = screen_type.context() {
                 let db = Database::init().await?;
                 let students = db.get_students_for_class(class.id).await?;
-                return Ok(Box::new(repo_management::RepoManagementScreen::new(class.clone(), students)));
+                let site_checks = db.get_site_checks_for_class(class.id).await?;
+                let tags = db.get_tags_for_class(class.id).await?;
+                return Ok(Box::new(
+                    repo_management::RepoManagementScreen::new(class.clone(), students)
+                        .with_site_checks(site_checks)
+                        .with_tags(tags)
+                ));
             }
             Err(anyhow::anyhow!("RepositoryManagement screen requires class context"))
         },
@@ -165,7 +256,11 @@ pub async fn create_screen(screen_type: ScreenType) -> Result<Box<dyn Screen>> {
             if let Some(ScreenContext::Class(class)) = screen_type.context() {
                 let db = Database::init().await?;
                 let students = db.get_students_for_class(class.id).await?;
-                return Ok(Box::new(week_view::WeekViewScreen::new(class.clone(), students)));
+                let calendar = db.get_calendar(class.id).await?;
+                let metric_settings = db.get_metric_settings(class.id).await?;
+                let cache = db.get_commit_cache(class.id).await?;
+                let metrics = crate::data::metrics::for_class(&cache, &calendar, &metric_settings, crate::utils::time::today());
+                return Ok(Box::new(week_view::WeekViewScreen::new(class.clone(), students, calendar, metrics)));
             }
             Err(anyhow::anyhow!("WeekView screen requires class context"))
         },
@@ -173,10 +268,151 @@ pub async fn create_screen(screen_type: ScreenType) -> Result<Box<dyn Screen>> {
             if let Some(ScreenContext::Class(class)) = screen_type.context() {
                 let db = Database::init().await?;
                 let students = db.get_students_for_class(class.id).await?;
-                return Ok(Box::new(latest_activity::LatestActivityScreen::new(students)));
+                return Ok(Box::new(latest_activity::LatestActivityScreen::new(class.clone(), students)));
             }
             Err(anyhow::anyhow!("LatestActivity screen requires class context"))
         },
+        ScreenTypeVariant::FileBrowser => {
+            if let Some(ScreenContext::ClassAndStudent(class, student)) = screen_type.context() {
+                return Ok(Box::new(file_browser::FileBrowserScreen::new(class.clone(), student.clone())));
+            }
+            Err(anyhow::anyhow!("FileBrowser screen requires class and student context"))
+        },
+        ScreenTypeVariant::SitePreview => {
+            if let Some(ScreenContext::ClassAndStudent(class, student)) = screen_type.context() {
+                return Ok(Box::new(site_preview::SitePreviewScreen::new(class.clone(), student.clone())));
+            }
+            Err(anyhow::anyhow!("SitePreview screen requires class and student context"))
+        },
+        ScreenTypeVariant::TestRunner => {
+            if let Some(ScreenContext::Class(class)) = screen_type.context() {
+                let db = Database::init().await?;
+                let students = db.get_students_for_class(class.id).await?;
+                let config = db.get_runner_config(class.id).await?;
+                let results = db.get_latest_test_results(class.id).await?;
+                return Ok(Box::new(test_runner::TestRunnerScreen::new(class.clone(), students, config, results)));
+            }
+            Err(anyhow::anyhow!("TestRunner screen requires class context"))
+        },
+        ScreenTypeVariant::Assignments => {
+            if let Some(ScreenContext::Class(class)) = screen_type.context() {
+                let db = Database::init().await?;
+                let students = db.get_students_for_class(class.id).await?;
+                let assignments = db.get_assignments_for_class(class.id).await?;
+                let snapshots = db.get_snapshots_for_class(class.id).await?;
+                let mut rubrics = HashMap::new();
+                for assignment in &assignments {
+                    rubrics.insert(assignment.id, db.get_rubric(assignment.id).await?);
+                }
+                return Ok(Box::new(assignments::AssignmentsScreen::new(class.clone(), students, assignments, snapshots, rubrics)));
+            }
+            Err(anyhow::anyhow!("Assignments screen requires class context"))
+        },
+        ScreenTypeVariant::Grading => {
+            if let Some(ScreenContext::ClassAndAssignment(class, assignment)) = screen_type.context() {
+                let db = Database::init().await?;
+                let students = db.get_students_for_class(class.id).await?;
+                let rubric = db.get_rubric(assignment.id).await?;
+                let grades = db.get_grades_for_assignment(assignment.id).await?;
+                let context = grading::GradingContext {
+                    site_checks: db.get_site_checks_for_class(class.id).await?,
+                    snapshots: db
+                        .get_snapshots_for_class(class.id)
+                        .await?
+                        .into_iter()
+                        .filter(|snapshot| snapshot.assignment_id == assignment.id)
+                        .map(|snapshot| (snapshot.student_id, snapshot))
+                        .collect(),
+                    test_results: db
+                        .get_latest_test_results(class.id)
+                        .await?
+                        .into_iter()
+                        .map(|result| (result.student_id, result))
+                        .collect(),
+                };
+                return Ok(Box::new(grading::GradingScreen::new(
+                    class.clone(),
+                    assignment.clone(),
+                    students,
+                    rubric,
+                    grades,
+                    context,
+                )));
+            }
+            Err(anyhow::anyhow!("Grading screen requires class and assignment context"))
+        },
+        ScreenTypeVariant::StudentNotes => {
+            let (class, student) = match screen_type.context() {
+                Some(ScreenContext::Class(class)) => (class, None),
+                Some(ScreenContext::ClassAndStudent(class, student)) => (class, Some(student.clone())),
+                _ => return Err(anyhow::anyhow!("StudentNotes screen requires class context")),
+            };
+            let db = Database::init().await?;
+            let students = db.get_students_for_class(class.id).await?;
+            let notes = db.get_notes_for_class(class.id).await?;
+            Ok(Box::new(notes::NotesScreen::new(class.clone(), students, notes, student)))
+        },
+        ScreenTypeVariant::StudentDetail => {
+            if let Some(ScreenContext::ClassAndStudent(class, student)) = screen_type.context() {
+                let db = Database::init().await?;
+                let mut grades = Vec::new();
+                for assignment in db.get_assignments_for_class(class.id).await? {
+                    let rubric = db.get_rubric(assignment.id).await?;
+                    let grade = db.get_grades_for_assignment(assignment.id).await?.remove(&student.id);
+                    grades.push(student_detail::AssignmentGrade { assignment, rubric, grade });
+                }
+                let record = student_detail::StudentRecord {
+                    notes: db
+                        .get_notes_for_class(class.id)
+                        .await?
+                        .into_iter()
+                        .filter(|note| note.student_id == student.id)
+                        .collect(),
+                    grades,
+                    site_check: db.get_site_checks_for_class(class.id).await?.remove(&student.id),
+                    test_result: db
+                        .get_latest_test_results(class.id)
+                        .await?
+                        .into_iter()
+                        .find(|result| result.student_id == student.id),
+                };
+                return Ok(Box::new(student_detail::StudentDetailScreen::new(class.clone(), student.clone(), record)));
+            }
+            Err(anyhow::anyhow!("StudentDetail screen requires class and student context"))
+        },
+        ScreenTypeVariant::SchoolCalendar => {
+            if let Some(ScreenContext::Class(class)) = screen_type.context() {
+                let db = Database::init().await?;
+                let calendar = db.get_calendar(class.id).await?;
+                return Ok(Box::new(calendar::CalendarScreen::new(class.clone(), calendar)));
+            }
+            Err(anyhow::anyhow!("SchoolCalendar screen requires class context"))
+        },
+        ScreenTypeVariant::ClassDashboard => {
+            if let Some(ScreenContext::Class(class)) = screen_type.context() {
+                let db = Database::init().await?;
+                let students = db.get_students_for_class(class.id).await?;
+                let calendar = db.get_calendar(class.id).await?;
+                let thresholds = db.get_alert_thresholds(class.id).await?;
+                let metric_settings = db.get_metric_settings(class.id).await?;
+                let cache = db.get_commit_cache(class.id).await?;
+                return Ok(Box::new(class_dashboard::ClassDashboardScreen::new(
+                    class.clone(), students, calendar, thresholds, metric_settings, cache,
+                )));
+            }
+            Err(anyhow::anyhow!("ClassDashboard screen requires class context"))
+        },
+        ScreenTypeVariant::ActivityHeatmap => {
+            let (class, student) = match screen_type.context() {
+                Some(ScreenContext::Class(class)) => (class, None),
+                Some(ScreenContext::ClassAndStudent(class, student)) => (class, Some(student.clone())),
+                _ => return Err(anyhow::anyhow!("ActivityHeatmap screen requires class context")),
+            };
+            let db = Database::init().await?;
+            let students = db.get_students_for_class(class.id).await?;
+            let cache = db.get_commit_cache(class.id).await?;
+            Ok(Box::new(activity_heatmap::ActivityHeatmapScreen::new(class.clone(), students, cache, student)))
+        },
         _ => anyhow::bail!("Screen type not implemented: {:?}", screen_type.variant()),
     }
 }
@@ -187,4 +423,31 @@ pub enum ScreenContext {
     Class(Class),
     Student(Student),
     ClassAndStudent(Class, Student),
+    ClassAndAssignment(Class, Assignment),
+}
+
+impl ScreenContext {
+    /// The class this context belongs to, if any
+    pub fn class(&self) -> Option<&Class> {
+        match self {
+            ScreenContext::Class(class)
+            | ScreenContext::ClassAndStudent(class, _)
+            | ScreenContext::ClassAndAssignment(class, _) => Some(class),
+            ScreenContext::Student(_) => None,
+        }
+    }
+
+    /// Swap in the updated copy of a class this context holds, e.g. after a rename
+    pub fn update_class(&mut self, updated: &Class) {
+        match self {
+            ScreenContext::Class(class)
+            | ScreenContext::ClassAndStudent(class, _)
+            | ScreenContext::ClassAndAssignment(class, _)
+                if class.id == updated.id =>
+            {
+                *class = updated.clone();
+            }
+            _ => {}
+        }
+    }
 }
\ No newline at end of file
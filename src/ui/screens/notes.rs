@@ -0,0 +1,531 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::{future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Class, Note, Student},
+    ui::{
+        animations::AnimationState,
+        mouse::MouseAction,
+        components::{input::AnimatedInput, ConfirmationDialog},
+        screens::{scroll_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+const DATE_FORMAT: &str = "%b %d %Y %H:%M";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Focus {
+    Students,
+    Notes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Browse,
+    Search,
+    /// Writing a new note (`None`) or editing an existing one
+    Edit(Option<i64>),
+}
+
+pub struct NotesScreen {
+    class: Class,
+    /// Student the screen was opened for, kept so going back returns to the same view
+    opened_for: Option<Student>,
+    students: Vec<Student>,
+    /// Every note in the class, newest first
+    notes: Vec<Note>,
+    selected_student: usize,
+    selected_note: usize,
+    focus: Focus,
+    mode: Mode,
+    search: AnimatedInput,
+    editor: AnimatedInput,
+    error: Option<String>,
+    confirm_delete: ConfirmationDialog,
+}
+
+impl NotesScreen {
+    pub fn new(class: Class, students: Vec<Student>, notes: Vec<Note>, opened_for: Option<Student>) -> Self {
+        let selected_student = opened_for
+            .as_ref()
+            .and_then(|student| students.iter().position(|s| s.id == student.id))
+            .unwrap_or(0);
+        let mut search = AnimatedInput::new("Search notes");
+        search.set_placeholder("Type to filter students by their notes");
+
+        Self {
+            class,
+            opened_for,
+            students,
+            notes,
+            selected_student,
+            selected_note: 0,
+            focus: Focus::Students,
+            mode: Mode::Browse,
+            search,
+            editor: AnimatedInput::new("Note"),
+            error: None,
+            confirm_delete: ConfirmationDialog::new("Delete Note", ""),
+        }
+    }
+
+    fn query(&self) -> &str {
+        self.search.value().trim()
+    }
+
+    /// Students shown in the list: everyone, or only those with notes matching the search
+    fn visible_students(&self) -> Vec<&Student> {
+        let query = self.query();
+        self.students
+            .iter()
+            .filter(|student| {
+                query.is_empty()
+                    || self.notes.iter().any(|note| note.student_id == student.id && note.matches(query))
+            })
+            .collect()
+    }
+
+    fn current_student(&self) -> Option<&Student> {
+        self.visible_students().get(self.selected_student).copied()
+    }
+
+    /// Notes for the selected student, narrowed by the search
+    fn visible_notes(&self) -> Vec<&Note> {
+        let Some(student_id) = self.current_student().map(|student| student.id) else {
+            return Vec::new();
+        };
+        let query = self.query();
+        self.notes
+            .iter()
+            .filter(|note| note.student_id == student_id && (query.is_empty() || note.matches(query)))
+            .collect()
+    }
+
+    fn current_note(&self) -> Option<&Note> {
+        self.visible_notes().get(self.selected_note).copied()
+    }
+
+    fn clamp_selection(&mut self) {
+        self.selected_student = self.selected_student.min(self.visible_students().len().saturating_sub(1));
+        self.selected_note = self.selected_note.min(self.visible_notes().len().saturating_sub(1));
+    }
+
+    fn start_editing(&mut self, note: Option<(i64, String)>) {
+        if self.current_student().is_none() {
+            return;
+        }
+        let (id, body) = match note {
+            Some((id, body)) => (Some(id), body),
+            None => (None, String::new()),
+        };
+        self.editor.set_value(body);
+        self.editor.focus();
+        self.error = None;
+        self.mode = Mode::Edit(id);
+    }
+
+    /// Save the editor's text; a failure keeps the editor open with the error shown
+    async fn save_note(&mut self, state: &AppState, id: Option<i64>) {
+        let body = self.editor.value().trim().to_string();
+        if body.is_empty() {
+            self.error = Some("Note cannot be empty".to_string());
+            return;
+        }
+
+        match id {
+            Some(id) => {
+                let updated_at = match state.database.update_note(id, &body).await {
+                    Ok(updated_at) => updated_at,
+                    Err(e) => {
+                        self.error = Some(format!("Failed to save note: {}", e));
+                        return;
+                    }
+                };
+                if let Some(note) = self.notes.iter_mut().find(|note| note.id == id) {
+                    note.body = body;
+                    note.updated_at = updated_at;
+                }
+            }
+            None => {
+                let Some(student_id) = self.current_student().map(|student| student.id) else {
+                    return;
+                };
+                let note = match state.database.add_note(student_id, &body).await {
+                    Ok(note) => note,
+                    Err(e) => {
+                        self.error = Some(format!("Failed to save note: {}", e));
+                        return;
+                    }
+                };
+                self.notes.insert(0, note);
+                self.selected_note = 0;
+                // A new note may not match the current search, so show it by clearing the search
+                self.search.set_value(String::new());
+                if let Some(position) = self.students.iter().position(|s| s.id == student_id) {
+                    self.selected_student = position;
+                }
+            }
+        }
+
+        self.editor.unfocus();
+        self.mode = Mode::Browse;
+        self.focus = Focus::Notes;
+    }
+
+    fn render_students(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let border_style = if self.focus == Focus::Students {
+            theme.border_focused_style()
+        } else {
+            theme.border_style()
+        };
+        let block = Block::default().borders(Borders::ALL).title("Students").border_style(border_style);
+
+        let students = self.visible_students();
+        if students.is_empty() {
+            let message = if self.query().is_empty() { "No students in this class" } else { "No notes match" };
+            let paragraph = Paragraph::new(message)
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = students.iter().map(|student| {
+            let count = self.notes.iter().filter(|note| note.student_id == student.id).count();
+            ListItem::new(Line::from(vec![
+                Span::styled(student.github_username.clone(), Style::default().fg(theme.text)),
+                Span::styled(
+                    if count > 0 { format!(" ({})", count) } else { String::new() },
+                    Style::default().fg(theme.text_secondary),
+                ),
+            ]))
+        }).collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background));
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected_student));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    fn render_notes(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let border_style = if self.focus == Focus::Notes {
+            theme.border_focused_style()
+        } else {
+            theme.border_style()
+        };
+        let title = self
+            .current_student()
+            .map(|student| format!("Notes — {}", student.github_username))
+            .unwrap_or_else(|| "Notes".to_string());
+        let block = Block::default().borders(Borders::ALL).title(title).border_style(border_style);
+
+        let notes = self.visible_notes();
+        if notes.is_empty() {
+            let paragraph = Paragraph::new(format!("No notes yet. Press {} to write one.", keymap::label(&[Action::New])))
+                .block(block)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let mut lines = Vec::new();
+        for (i, note) in notes.iter().enumerate() {
+            let selected = self.focus == Focus::Notes && i == self.selected_note;
+            let mut stamp = time::format(note.created_at, DATE_FORMAT);
+            if note.updated_at != note.created_at {
+                stamp.push_str(&format!(" · edited {}", time::format(note.updated_at, DATE_FORMAT)));
+            }
+            lines.push(Line::from(vec![
+                Span::styled(if selected { "▶ " } else { "  " }, Style::default().fg(theme.primary)),
+                Span::styled(stamp, Style::default().fg(theme.text_secondary)),
+            ]));
+            let body_style = if selected {
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            lines.push(Line::from(Span::styled(format!("  {}", note.body), body_style)));
+            lines.push(Line::from(""));
+        }
+
+        let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_editor(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(70, 30, area);
+        frame.render_widget(Clear, popup_area);
+
+        let title = match (self.mode, self.current_student()) {
+            (Mode::Edit(Some(_)), Some(student)) => format!("✏️ Edit Note - {}", student.github_username),
+            (_, Some(student)) => format!("📝 New Note - {}", student.github_username),
+            _ => String::new(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Note
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(&self.editor, chunks[0]);
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[1]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[3]);
+    }
+}
+
+impl Screen for NotesScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.mode != Mode::Browse
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        let context = match &self.opened_for {
+            Some(student) => ScreenContext::ClassAndStudent(self.class.clone(), student.clone()),
+            None => ScreenContext::Class(self.class.clone()),
+        };
+        ScreenType::new(ScreenTypeVariant::StudentNotes).with_context(context)
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.confirm_delete.is_visible() {
+                if let Some(true) = self.confirm_delete.handle_key_event(key) {
+                    if let Some(id) = self.current_note().map(|note| note.id) {
+                        match state.database.delete_note(id).await {
+                            Ok(_) => {
+                                self.notes.retain(|note| note.id != id);
+                                self.clamp_selection();
+                            }
+                            Err(e) => self.error = Some(format!("Failed to delete note: {}", e)),
+                        }
+                    }
+                }
+                return Ok(None);
+            }
+
+            if self.mode == Mode::Browse {
+                self.error = None;
+            }
+            match self.mode {
+                Mode::Browse => match key.code {
+                    _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                    _ if keymap::is(Action::Next, &key) || keymap::is(Action::Left, &key) || keymap::is(Action::Right, &key) => {
+                        self.focus = if self.focus == Focus::Students { Focus::Notes } else { Focus::Students };
+                    }
+                    _ if keymap::is(Action::Up, &key) => match self.focus {
+                        Focus::Students => {
+                            self.selected_student = self.selected_student.saturating_sub(1);
+                            self.selected_note = 0;
+                        }
+                        Focus::Notes => self.selected_note = self.selected_note.saturating_sub(1),
+                    },
+                    _ if keymap::is(Action::Down, &key) => match self.focus {
+                        Focus::Students => {
+                            if self.selected_student + 1 < self.visible_students().len() {
+                                self.selected_student += 1;
+                                self.selected_note = 0;
+                            }
+                        }
+                        Focus::Notes => {
+                            if self.selected_note + 1 < self.visible_notes().len() {
+                                self.selected_note += 1;
+                            }
+                        }
+                    },
+                    _ if keymap::is(Action::Filter, &key) => {
+                        self.search.focus();
+                        self.mode = Mode::Search;
+                    }
+                    _ if keymap::is(Action::New, &key) => self.start_editing(None),
+                    _ if (keymap::is(Action::Select, &key) || keymap::is(Action::Edit, &key)) && self.focus == Focus::Notes => {
+                        let note = self.current_note().map(|note| (note.id, note.body.clone()));
+                        if note.is_some() {
+                            self.start_editing(note);
+                        }
+                    }
+                    _ if keymap::is(Action::Select, &key) => self.focus = Focus::Notes,
+                    _ if keymap::is(Action::Delete, &key) && self.focus == Focus::Notes && self.current_note().is_some() => {
+                        self.confirm_delete = ConfirmationDialog::new("Delete Note", "Delete this note? This cannot be undone.")
+                            .with_yes_text("Delete")
+                            .with_no_text("Cancel");
+                        self.confirm_delete.show();
+                    }
+                    _ => {}
+                },
+                Mode::Search => match key.code {
+                    KeyCode::Esc => {
+                        self.search.set_value(String::new());
+                        self.search.unfocus();
+                        self.mode = Mode::Browse;
+                        self.clamp_selection();
+                    }
+                    KeyCode::Enter => {
+                        self.search.unfocus();
+                        self.mode = Mode::Browse;
+                    }
+                    _ => {
+                        self.search.handle_key_event(key);
+                        self.selected_student = 0;
+                        self.selected_note = 0;
+                    }
+                },
+                Mode::Edit(id) => match key.code {
+                    KeyCode::Esc => {
+                        self.editor.unfocus();
+                        self.mode = Mode::Browse;
+                    }
+                    KeyCode::Enter => self.save_note(state, id).await,
+                    _ => {
+                        self.editor.handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let key = if self.confirm_delete.is_visible() {
+            self.confirm_delete.key_for_click(mouse)
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        self.search.update(delta_time);
+        self.editor.update(delta_time);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🗒️ Student Notes - {}", self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),  // Search
+                Constraint::Min(5),     // Students and notes
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(&self.search, chunks[0]);
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(70),
+            ])
+            .split(chunks[1]);
+
+        self.render_students(frame, panes[0], theme);
+        self.render_notes(frame, panes[1], theme);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Next]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Switch Pane  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Filter]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Search  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::New]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" New  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Edit]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Edit  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Delete]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Delete  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+        ]);
+        // A failed delete replaces the help until the next key press
+        let help_paragraph = match (&self.mode, &self.error) {
+            (Mode::Browse, Some(error)) => Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error))),
+            _ => Paragraph::new(help_text),
+        };
+        let help_paragraph = help_paragraph
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[2]);
+
+        if matches!(self.mode, Mode::Edit(_)) {
+            self.render_editor(frame, area, theme);
+        }
+        self.confirm_delete.render(frame, area, theme);
+    }
+}
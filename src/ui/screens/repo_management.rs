@@ -5,22 +5,34 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
-use std::{future::Future, pin::Pin, time::Duration};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState, BatchAction},
     data::{Class, Student},
     git::GitManager,
+    site_check::{Severity, SiteCheck},
     ui::{
         animations::AnimationState,
-        components::menu::{AnimatedMenu, MenuBuilder, MenuItem},
-        screens::{Screen, ScreenType, ScreenTypeVariant},
+        layout,
+        mouse::MouseAction,
+        components::{
+            input::AnimatedInput,
+            menu::{AnimatedMenu, MenuBuilder, MenuItem},
+            selection::StudentSelection,
+            ConfirmationDialog,
+        },
+        screens::{Screen, ScreenContext, ScreenType, ScreenTypeVariant},
         themes::Theme,
     },
+    utils::{launcher::OpenAction, time},
 };
 
+/// Classes to choose from, with the batch action to run on the one chosen
+type ClassTargets = (fn(Class) -> BatchAction, Vec<Class>);
+
 pub struct RepoManagementScreen {
     class: Class,
     students: Vec<Student>,
@@ -28,6 +40,19 @@ pub struct RepoManagementScreen {
     selected_index: usize,
     show_actions: bool,
     show_main_menu: bool,
+    site_checks: HashMap<i64, SiteCheck>,
+    /// Where the student list was last drawn and how far it was scrolled, for mouse hit-testing
+    student_list_area: Rect,
+    student_list_scroll: usize,
+    /// Tags on each student, by student id
+    tags: HashMap<i64, Vec<String>>,
+    selection: StudentSelection,
+    show_batch_menu: bool,
+    /// Classes listed by the batch menu while picking where to move or copy the selection
+    class_targets: Option<ClassTargets>,
+    tag_input: Option<AnimatedInput>,
+    confirm_batch: ConfirmationDialog,
+    pending_batch: Option<BatchAction>,
 }
 
 impl RepoManagementScreen {
@@ -35,7 +60,8 @@ impl RepoManagementScreen {
         let menu = MenuBuilder::new()
             .title(format!("Repository Management - {}", class.name))
             .item(MenuItem::new("Clone All Repositories").with_description("Clone all student repositories").with_icon("📥"))
-            .item(MenuItem::new("Individual Student Actions").with_description("Select individual student for actions").with_icon("👤"))
+            .item(MenuItem::new("Individual Student Actions").with_description("Select students for individual or batch actions").with_icon("👤"))
+            .item(MenuItem::new("Check All Sites").with_description("Run site health checks on every cloned repo").with_icon("🩺"))
             .item(MenuItem::new("Back").with_description("Return to class management").with_icon("↩️"))
             .build();
 
@@ -46,22 +72,233 @@ impl RepoManagementScreen {
             selected_index: 0,
             show_actions: false,
             show_main_menu: true,
+            site_checks: HashMap::new(),
+            student_list_area: Rect::default(),
+            student_list_scroll: 0,
+            tags: HashMap::new(),
+            selection: StudentSelection::default(),
+            show_batch_menu: false,
+            class_targets: None,
+            tag_input: None,
+            confirm_batch: ConfirmationDialog::new("Batch Action", ""),
+            pending_batch: None,
         }
     }
 
+    pub fn with_site_checks(mut self, site_checks: HashMap<i64, SiteCheck>) -> Self {
+        self.site_checks = site_checks;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: HashMap<i64, Vec<String>>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn set_site_check(&mut self, check: SiteCheck) {
+        self.site_checks.insert(check.student_id, check);
+    }
+
+    /// Swap in a reloaded student list, e.g. after a batch action deleted or moved some
+    pub fn set_students(&mut self, students: Vec<Student>, tags: HashMap<i64, Vec<String>>) {
+        self.selection.retain(&students);
+        self.selected_index = self.selected_index.min(students.len().saturating_sub(1));
+        self.students = students;
+        self.tags = tags;
+    }
+
     fn get_selected_student(&self) -> Option<&Student> {
         self.students.get(self.selected_index)
     }
 
     fn update_menu_for_student_username(&mut self, github_username: &str) {
-        self.menu = MenuBuilder::new()
-            .title(format!("Repository Actions for {}", github_username))
-            .item(MenuItem::new("Clone Repo").with_description("Clone GitHub Pages repo").with_icon("📥"))
-            .item(MenuItem::new("Pull Repo").with_description("Pull latest changes from remote").with_icon("🔄"))
-            .item(MenuItem::new("Clean Repo").with_description("Reset local changes to match remote").with_icon("🧹"))
-            .item(MenuItem::new("Open in Terminal").with_description("Open terminal at repo location").with_icon("🖥️"))
-            .item(MenuItem::new("Back").with_description("Return to student selection").with_icon("↩️"))
+        let mut builder = MenuBuilder::new().title(format!("Repository Actions for {}", github_username));
+        for item in Self::student_menu_items() {
+            builder = builder.item(item);
+        }
+        self.menu = builder.build();
+    }
+
+    /// What can be done with one student's repository
+    pub(crate) fn student_menu_items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Student Details").with_description("Profile, activity, grades and notes").with_icon("👤"),
+            MenuItem::new("Clone Repo")
+                .with_description("Clone GitHub Pages repo")
+                .with_icon("📥")
+                .with_detail("git clone https://github.com/<username>/<username>.github.io"),
+            MenuItem::new("Pull Repo")
+                .with_description("Pull latest changes from remote")
+                .with_icon("🔄")
+                .with_detail("git pull origin main"),
+            MenuItem::new("Clean Repo")
+                .with_description("Reset local changes to match remote")
+                .with_icon("🧹")
+                .with_detail("git reset --hard HEAD, then git clean -fd: edits, staged changes and untracked files in the clone are deleted for good."),
+            MenuItem::new("Open in Terminal").with_description("Open terminal at repo location").with_icon("🖥️"),
+            MenuItem::new("Open in Editor").with_description("Open the repo in your configured editor").with_icon("📝"),
+            MenuItem::new("Open File Manager").with_description("Show the repo in your file manager").with_icon("🗂️"),
+            MenuItem::new("Open Site in Browser").with_description("Open the student's GitHub Pages site").with_icon("🌐"),
+            MenuItem::new("Shell in Repo").with_description("Suspend scv and open your shell in the repo").with_icon("💲"),
+            MenuItem::new("Browse Files").with_description("Browse and view files in the local clone").with_icon("📂"),
+            MenuItem::new("Preview Site").with_description("Serve the site locally at any commit").with_icon("🔭"),
+            MenuItem::new("Check Site Health").with_description("Look for broken links, missing alt text and other Pages problems").with_icon("🩺"),
+            MenuItem::new("Back").with_description("Return to student selection").with_icon("↩️"),
+        ]
+    }
+
+    fn open_batch_menu(&mut self) {
+        let mut builder = MenuBuilder::new().title(format!("Batch Actions for {} Students", self.selection.len()));
+        for item in Self::batch_menu_items() {
+            builder = builder.item(item);
+        }
+        self.menu = builder.build();
+        self.show_batch_menu = true;
+        self.class_targets = None;
+    }
+
+    /// What can be done with every marked student at once
+    pub(crate) fn batch_menu_items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("Clone Repos").with_description("Clone each selected student's repo").with_icon("📥"),
+            MenuItem::new("Pull Repos").with_description("Pull latest changes into each clone").with_icon("🔄"),
+            MenuItem::new("Clean Repos").with_description("Reset local changes in each clone").with_icon("🧹"),
+            MenuItem::new("Tag Students")
+                .with_description("Add a tag to each selected student")
+                .with_icon("🏷️")
+                .with_detail("The tag is free-form and shows as #tag in the student list."),
+            MenuItem::new("Move to Another Class")
+                .with_description("Move the students and their clones")
+                .with_icon("📦")
+                .with_detail("Their notes stay with them, and clones move from the old class folder to the new one."),
+            MenuItem::new("Copy to Another Class")
+                .with_description("Add the students to another class as well")
+                .with_icon("📋")
+                .with_detail("Only the roster entry is copied; clone their repos again in the other class."),
+            MenuItem::new("Delete Students").with_description("Remove the students from this class").with_icon("🗑️"),
+            MenuItem::new("Back").with_description("Return to student selection").with_icon("↩️"),
+        ]
+    }
+
+    fn open_class_targets(&mut self, verb: &str, action: fn(Class) -> BatchAction, classes: Vec<Class>) {
+        let mut menu = MenuBuilder::new().title(format!("{} {} Students To", verb, self.selection.len()));
+        for class in &classes {
+            menu = menu.item(MenuItem::new(class.name.clone()).with_icon("🏫"));
+        }
+        self.menu = menu
+            .item(MenuItem::new("Back").with_description("Return to batch actions").with_icon("↩️"))
             .build();
+        self.class_targets = Some((action, classes));
+    }
+
+    fn close_batch_menu(&mut self) {
+        self.show_batch_menu = false;
+        self.class_targets = None;
+    }
+
+    /// Ask once for the whole selection before running `action` on it
+    fn confirm(&mut self, action: BatchAction) {
+        let students = self.selection.students(&self.students);
+        self.confirm_batch = ConfirmationDialog::new(&action.label(), &action.confirmation(&students));
+        self.confirm_batch.show();
+        self.pending_batch = Some(action);
+    }
+
+    fn tag_spans(&self, student: &Student, theme: &Theme) -> Vec<Span<'static>> {
+        self.tags
+            .get(&student.id)
+            .into_iter()
+            .flatten()
+            .map(|tag| Span::styled(format!(" #{}", tag), Style::default().fg(theme.accent)))
+            .collect()
+    }
+
+    fn render_tag_input(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let Some(input) = &self.tag_input else {
+            return;
+        };
+
+        let popup_area = layout::center_rect(50, 25, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🏷️ Tag {} Students", self.selection.len()))
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Tag
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(input, chunks[0]);
+
+        let help_text = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Apply  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[2]);
+    }
+
+    fn site_status_span(&self, student: &Student, theme: &Theme) -> Span<'static> {
+        match self.site_checks.get(&student.id).map(|check| check.report.status()) {
+            Some(Severity::Pass) => Span::styled(" [✓ Pass]", Style::default().fg(theme.success)),
+            Some(Severity::Warn) => Span::styled(" [⚠ Warn]", Style::default().fg(theme.warning)),
+            Some(Severity::Fail) => Span::styled(" [✗ Fail]", Style::default().fg(theme.error)),
+            None => Span::styled(" [– Unchecked]", Style::default().fg(theme.text_secondary)),
+        }
+    }
+
+    fn render_site_findings(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::TOP)
+            .title("Site Health")
+            .border_style(theme.border_style());
+
+        let check = self.get_selected_student().and_then(|student| self.site_checks.get(&student.id));
+        let Some(check) = check else {
+            let paragraph = Paragraph::new(format!("Not checked yet. Press {} to check this site.", keymap::label(&[Action::CheckSite])))
+                .block(block)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!(
+                "{} — checked {}",
+                check.report.summary(),
+                time::format(check.checked_at, "%b %d %H:%M")
+            ),
+            Style::default().fg(theme.text_secondary),
+        ))];
+
+        lines.extend(check.report.findings.iter().map(|finding| {
+            let (icon, color) = match finding.severity {
+                Severity::Fail => ("✗ ", theme.error),
+                Severity::Warn => ("⚠ ", theme.warning),
+                Severity::Pass => ("✓ ", theme.success),
+            };
+            let mut spans = vec![Span::styled(icon, Style::default().fg(color))];
+            if let Some(location) = finding.location() {
+                spans.push(Span::styled(format!("{} ", location), Style::default().fg(theme.primary)));
+            }
+            spans.push(Span::styled(finding.message.clone(), Style::default().fg(theme.text)));
+            Line::from(spans)
+        }));
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
     }
 }
 
@@ -76,18 +313,49 @@ impl Screen for RepoManagementScreen {
         key: KeyEvent,
         state: &'a AppState,
     ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        if self.confirm_batch.is_visible() {
+            let result = match self.confirm_batch.handle_key_event(key) {
+                Some(true) => {
+                    self.close_batch_menu();
+                    let students = self.selection.students(&self.students);
+                    Ok(self.pending_batch.take().map(|action| AppEvent::RunBatch(action, students)))
+                }
+                Some(false) => {
+                    self.pending_batch = None;
+                    Ok(None)
+                }
+                None => Ok(None),
+            };
+            return Box::pin(async { result });
+        }
+
+        if let Some(input) = &mut self.tag_input {
+            match key.code {
+                KeyCode::Esc => self.tag_input = None,
+                KeyCode::Enter => {
+                    let tag = input.value().trim().to_string();
+                    if !tag.is_empty() {
+                        self.tag_input = None;
+                        self.confirm(BatchAction::Tag(tag));
+                    }
+                }
+                _ => input.handle_key_event(key),
+            }
+            return Box::pin(async { Ok(None) });
+        }
+
         let result = if self.show_main_menu {
             // Handle main menu
             match key.code {
-                KeyCode::Up | KeyCode::Char('k') => {
+                _ if keymap::is(Action::Up, &key) => {
                     self.menu.select_previous();
                     Ok(None)
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
+                _ if keymap::is(Action::Down, &key) => {
                     self.menu.select_next();
                     Ok(None)
                 }
-                KeyCode::Enter | KeyCode::Char(' ') => {
+                _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => {
                     if let Some(item) = self.menu.selected_item() {
                         match item.title.as_str() {
                             "Clone All Repositories" => Ok(Some(AppEvent::CloneAllRepos)),
@@ -95,6 +363,7 @@ impl Screen for RepoManagementScreen {
                                 self.show_main_menu = false;
                                 Ok(None)
                             }
+                            "Check All Sites" => Ok(Some(AppEvent::CheckAllSites)),
                             "Back" => Ok(Some(AppEvent::GoBack)),
                             _ => Ok(None),
                         }
@@ -102,28 +371,114 @@ impl Screen for RepoManagementScreen {
                         Ok(None)
                     }
                 }
-                KeyCode::Esc => Ok(Some(AppEvent::GoBack)),
+                _ if keymap::is(Action::Back, &key) => Ok(Some(AppEvent::GoBack)),
+                _ => Ok(None),
+            }
+        } else if self.show_batch_menu {
+            // Handle batch actions menu, or the class list when moving
+            match key.code {
+                _ if keymap::is(Action::Up, &key) => {
+                    self.menu.select_previous();
+                    Ok(None)
+                }
+                _ if keymap::is(Action::Down, &key) => {
+                    self.menu.select_next();
+                    Ok(None)
+                }
+                _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => {
+                    if let Some((action, classes)) = &self.class_targets {
+                        // Anything past the classes is Back
+                        match classes.get(self.menu.selected_index()).cloned() {
+                            Some(class) => self.confirm(action(class)),
+                            None => self.open_batch_menu(),
+                        }
+                        return Box::pin(async { Ok(None) });
+                    }
+
+                    let title = self.menu.selected_item().map(|item| item.title.clone()).unwrap_or_default();
+                    match title.as_str() {
+                        "Clone Repos" => self.confirm(BatchAction::Clone),
+                        "Pull Repos" => self.confirm(BatchAction::Pull),
+                        "Clean Repos" => self.confirm(BatchAction::Clean),
+                        "Delete Students" => self.confirm(BatchAction::Delete),
+                        "Tag Students" => {
+                            let mut input = AnimatedInput::new("Tag");
+                            input.set_placeholder("e.g. needs-help");
+                            input.focus();
+                            self.tag_input = Some(input);
+                        }
+                        "Move to Another Class" | "Copy to Another Class" => {
+                            let (verb, action): (&str, fn(Class) -> BatchAction) = if title.starts_with("Move") {
+                                ("Move", BatchAction::Move)
+                            } else {
+                                ("Copy", BatchAction::Copy)
+                            };
+                            return Box::pin(async move {
+                                let classes: Vec<Class> = state
+                                    .database
+                                    .get_classes()
+                                    .await?
+                                    .into_iter()
+                                    .filter(|class| class.id != self.class.id)
+                                    .collect();
+                                if classes.is_empty() {
+                                    return Ok(Some(AppEvent::ShowError(format!(
+                                        "There are no other classes to {} students to",
+                                        verb.to_lowercase()
+                                    ))));
+                                }
+                                self.open_class_targets(verb, action, classes);
+                                Ok(None)
+                            });
+                        }
+                        "Back" => self.close_batch_menu(),
+                        _ => {}
+                    }
+                    Ok(None)
+                }
+                _ if keymap::is(Action::Back, &key) => {
+                    if self.class_targets.is_some() {
+                        self.open_batch_menu();
+                    } else {
+                        self.close_batch_menu();
+                    }
+                    Ok(None)
+                }
                 _ => Ok(None),
             }
         } else if self.show_actions {
             // Handle actions menu
             match key.code {
-                KeyCode::Up | KeyCode::Char('k') => {
+                _ if keymap::is(Action::Up, &key) => {
                     self.menu.select_previous();
                     Ok(None)
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
+                _ if keymap::is(Action::Down, &key) => {
                     self.menu.select_next();
                     Ok(None)
                 }
-                KeyCode::Enter | KeyCode::Char(' ') => {
+                _ if keymap::is(Action::Select, &key) || key.code == KeyCode::Char(' ') => {
                     if let Some(selected_student) = self.get_selected_student() {
                         if let Some(item) = self.menu.selected_item() {
                             match item.title.as_str() {
+                                "Student Details" => Ok(Some(AppEvent::ShowStudentDetail(selected_student.clone()))),
                                 "Clone Repo" => Ok(Some(AppEvent::CloneRepo(selected_student.github_username.clone()))),
                                 "Pull Repo" => Ok(Some(AppEvent::PullRepo(selected_student.github_username.clone()))),
                                 "Clean Repo" => Ok(Some(AppEvent::CleanRepo(selected_student.github_username.clone()))),
-                                "Open in Terminal" => Ok(Some(AppEvent::OpenInTerminal(selected_student.github_username.clone()))),
+                                "Open in Terminal" => Ok(Some(AppEvent::OpenWith(OpenAction::Terminal, selected_student.github_username.clone()))),
+                                "Open in Editor" => Ok(Some(AppEvent::OpenWith(OpenAction::Editor, selected_student.github_username.clone()))),
+                                "Open File Manager" => Ok(Some(AppEvent::OpenWith(OpenAction::FileManager, selected_student.github_username.clone()))),
+                                "Open Site in Browser" => Ok(Some(AppEvent::OpenWith(OpenAction::Browser, selected_student.github_username.clone()))),
+                                "Shell in Repo" => Ok(Some(AppEvent::SuspendToShell(selected_student.github_username.clone()))),
+                                "Browse Files" => Ok(Some(AppEvent::NavigateToScreen(
+                                    ScreenType::new(ScreenTypeVariant::FileBrowser)
+                                        .with_context(ScreenContext::ClassAndStudent(self.class.clone(), selected_student.clone()))
+                                ))),
+                                "Check Site Health" => Ok(Some(AppEvent::CheckSite(selected_student.github_username.clone()))),
+                                "Preview Site" => Ok(Some(AppEvent::NavigateToScreen(
+                                    ScreenType::new(ScreenTypeVariant::SitePreview)
+                                        .with_context(ScreenContext::ClassAndStudent(self.class.clone(), selected_student.clone()))
+                                ))),
                                 "Back" => {
                                     self.show_actions = false;
                                     Ok(None)
@@ -137,7 +492,16 @@ impl Screen for RepoManagementScreen {
                         Ok(None)
                     }
                 }
-                KeyCode::Esc => {
+                _ if keymap::is(Action::Details, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::ShowStudentDetail(student.clone())))
+                }
+                _ if keymap::is(Action::Shell, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::SuspendToShell(student.github_username.clone())))
+                }
+                _ if keymap::is(Action::CheckSite, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::CheckSite(student.github_username.clone())))
+                }
+                _ if keymap::is(Action::Back, &key) => {
                     self.show_actions = false;
                     Ok(None)
                 }
@@ -146,19 +510,32 @@ impl Screen for RepoManagementScreen {
         } else {
             // Handle student selection
             match key.code {
-                KeyCode::Up | KeyCode::Char('k') => {
+                _ if keymap::is(Action::Up, &key) => {
                     if self.selected_index > 0 {
                         self.selected_index -= 1;
                     }
                     Ok(None)
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
+                _ if keymap::is(Action::Down, &key) => {
                     if self.selected_index + 1 < self.students.len() {
                         self.selected_index += 1;
                     }
                     Ok(None)
                 }
-                KeyCode::Enter | KeyCode::Char(' ') => {
+                _ if self.selection.handle_key_event(key, &self.students, self.students.get(self.selected_index)) => Ok(None),
+                _ if keymap::is(Action::BatchActions, &key) => {
+                    if self.selection.is_empty() {
+                        Ok(Some(AppEvent::ShowError(format!(
+                            "Mark students with {} first, or {} to mark everyone",
+                            keymap::label(&[Action::ToggleSelection]),
+                            keymap::label(&[Action::SelectAll])
+                        ))))
+                    } else {
+                        self.open_batch_menu();
+                        Ok(None)
+                    }
+                }
+                _ if keymap::is(Action::Select, &key) => {
                     if let Some(selected_student) = self.get_selected_student() {
                         let github_username = selected_student.github_username.clone();
                         // Switch to actions menu
@@ -169,7 +546,16 @@ impl Screen for RepoManagementScreen {
                         Ok(None)
                     }
                 }
-                KeyCode::Esc => {
+                _ if keymap::is(Action::Details, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::ShowStudentDetail(student.clone())))
+                }
+                _ if keymap::is(Action::Shell, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::SuspendToShell(student.github_username.clone())))
+                }
+                _ if keymap::is(Action::CheckSite, &key) => {
+                    Ok(self.get_selected_student().map(|student| AppEvent::CheckSite(student.github_username.clone())))
+                }
+                _ if keymap::is(Action::Back, &key) => {
                     self.show_main_menu = true;
                     Ok(None)
                 }
@@ -179,12 +565,68 @@ impl Screen for RepoManagementScreen {
         Box::pin(async { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        if self.confirm_batch.is_visible() {
+            return match self.confirm_batch.key_for_click(mouse) {
+                Some(key) => self.handle_key_event(key, state),
+                None => Box::pin(async { Ok(None) }),
+            };
+        }
+        if self.tag_input.is_some() {
+            return Box::pin(async { Ok(None) });
+        }
+
+        let activate = if self.show_main_menu || self.show_actions || self.show_batch_menu {
+            self.menu.handle_mouse_event(mouse)
+        } else {
+            let (column, row) = mouse.position();
+            match mouse {
+                MouseAction::ScrollUp { .. } => {
+                    self.selected_index = self.selected_index.saturating_sub(1);
+                    false
+                }
+                MouseAction::ScrollDown { .. } => {
+                    if self.selected_index + 1 < self.students.len() {
+                        self.selected_index += 1;
+                    }
+                    false
+                }
+                MouseAction::Click { .. } | MouseAction::DoubleClick { .. } => {
+                    match layout::list_row_at(self.student_list_area, column, row, self.student_list_scroll) {
+                        Some(index) if index < self.students.len() => {
+                            self.selected_index = index;
+                            matches!(mouse, MouseAction::DoubleClick { .. })
+                        }
+                        _ => false,
+                    }
+                }
+            }
+        };
+
+        // A double click activates the item like Select
+        if let Some(key) = activate.then(|| keymap::key_event(Action::Select)).flatten() {
+            return self.handle_key_event(key, state);
+        }
+        Box::pin(async { Ok(None) })
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.tag_input.is_some()
+    }
+
     fn update<'a>(
         &'a mut self,
         delta_time: Duration,
         _state: &'a mut AppState,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
         self.menu.update(delta_time, &AnimationState::new());
+        if let Some(input) = &mut self.tag_input {
+            input.update(delta_time);
+        }
         Box::pin(async { Ok(()) })
     }
 
@@ -196,26 +638,40 @@ impl Screen for RepoManagementScreen {
         animation_state: &AnimationState,
         theme: &Theme,
     ) {
-        if self.show_main_menu || self.show_actions {
-            // Render main menu or actions menu
+        if self.show_main_menu || self.show_actions || self.show_batch_menu {
+            // Render main menu, actions menu or batch menu
             frame.render_widget(&mut self.menu, area);
         } else {
             // Render student selection
+            let marked = if self.selection.is_empty() {
+                String::new()
+            } else {
+                format!(" ({} marked)", self.selection.len())
+            };
             let block = Block::default()
                 .borders(Borders::ALL)
-                .title(format!("Select Student for Repository Actions - {}", self.class.name));
+                .title(format!("Select Student for Repository Actions - {}{}", self.class.name, marked));
             let inner_area = block.inner(area);
             frame.render_widget(block, area);
             
             // Check if we have students
             if self.students.is_empty() {
-                let no_students_text = Paragraph::new("No students found in this class.\n\nPress ESC to go back.")
+                let no_students_text = Paragraph::new(format!("No students found in this class.\n\nPress {} to go back.", keymap::label(&[Action::Back])))
                     .alignment(Alignment::Center)
                     .style(Style::default().fg(theme.text_secondary));
                 frame.render_widget(no_students_text, inner_area);
                 return;
             }
             
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(3),     // Student list
+                    Constraint::Length(8),  // Site check findings
+                    Constraint::Length(3),  // Help text
+                ])
+                .split(inner_area);
+            
             let student_list: Vec<Line> = self.students.iter().enumerate().map(|(i, student)| {
                 let style = if i == self.selected_index {
                     Style::default().fg(theme.highlight).add_modifier(Modifier::BOLD)
@@ -232,8 +688,9 @@ impl Screen for RepoManagementScreen {
                 
                 let prefix = if i == self.selected_index { "▶ " } else { "  " };
                 
-                Line::from(vec![
+                let mut spans = vec![
                     Span::styled(prefix, style),
+                    Span::styled(self.selection.marker(student), Style::default().fg(theme.accent)),
                     Span::styled(
                         format!("{} ({})", student.github_username, student.username),
                         style
@@ -246,36 +703,60 @@ impl Screen for RepoManagementScreen {
                             Style::default().fg(theme.text_secondary)
                         }
                     ),
-                ])
+                    self.site_status_span(student, theme),
+                ];
+                spans.extend(self.tag_spans(student, theme));
+                Line::from(spans)
             }).collect();
             
+            // Keep the selected student in view
+            let visible_rows = chunks[0].height as usize;
+            let scroll = self.selected_index.saturating_sub(visible_rows.saturating_sub(1));
             let student_paragraph = Paragraph::new(student_list)
-                .alignment(Alignment::Left);
-            frame.render_widget(student_paragraph, inner_area);
+                .alignment(Alignment::Left)
+                .scroll((scroll as u16, 0));
+            frame.render_widget(student_paragraph, chunks[0]);
+            self.student_list_area = chunks[0];
+            self.student_list_scroll = scroll;
             
-            // Show help text
-            let help_area = Rect {
-                x: inner_area.x,
-                y: inner_area.y + inner_area.height.saturating_sub(2),
-                width: inner_area.width,
-                height: 2,
-            };
+            self.render_site_findings(frame, chunks[1], theme);
             
             let help_text = vec![
                 Line::from(vec![
-                    Span::styled("↑/↓ or j/k", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                     Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
-                    Span::styled("Enter", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                     Span::styled(" Select  ", Style::default().fg(theme.text_secondary)),
-                    Span::styled("ESC", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(keymap::label(&[Action::Details]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(" Details  ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(keymap::label(&[Action::Shell]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(" Shell  ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(keymap::label(&[Action::CheckSite]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(" Check Site  ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                     Span::styled(" Back", Style::default().fg(theme.text_secondary)),
-                ])
+                ]),
+                Line::from(vec![
+                    Span::styled(keymap::label(&[Action::ToggleSelection]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(" Mark  ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(
+                        keymap::label(&[Action::SelectAll, Action::SelectNone, Action::InvertSelection]),
+                        Style::default().fg(theme.primary).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(" All/None/Invert  ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(keymap::label(&[Action::BatchActions]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(" Batch Actions", Style::default().fg(theme.text_secondary)),
+                ]),
             ];
             
             let help_paragraph = Paragraph::new(help_text)
-                .alignment(Alignment::Center);
-            frame.render_widget(help_paragraph, help_area);
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::TOP));
+            frame.render_widget(help_paragraph, chunks[2]);
         }
+
+        self.render_tag_input(frame, area, theme);
+        self.confirm_batch.render(frame, area, theme);
     }
 
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
@@ -0,0 +1,267 @@
+use anyhow::Result;
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::{future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Class, Student},
+    git::LocalCommit,
+    preview::{short_sha, PreviewServer, SiteSource},
+    ui::{
+        animations::AnimationState,
+        screens::{Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+/// Number of commits offered for previewing
+const COMMIT_LIMIT: usize = 50;
+
+pub struct SitePreviewScreen {
+    class: Class,
+    student: Student,
+    commits: Vec<LocalCommit>,
+    /// 0 is the working tree, `n` is `commits[n - 1]`
+    selected: usize,
+    server: Option<PreviewServer>,
+    live_reload: bool,
+    needs_load: bool,
+    error: Option<String>,
+}
+
+impl SitePreviewScreen {
+    pub fn new(class: Class, student: Student) -> Self {
+        Self {
+            class,
+            student,
+            commits: Vec::new(),
+            selected: 0,
+            server: None,
+            live_reload: true,
+            needs_load: true,
+            error: None,
+        }
+    }
+
+    fn selected_source(&self, state: &AppState) -> SiteSource {
+        let repo = state.git_manager.get_repo_path(&self.student.github_username, &self.class.name);
+        match self.selected.checked_sub(1).and_then(|index| self.commits.get(index)) {
+            Some(commit) => SiteSource::Commit { repo, sha: commit.sha.clone() },
+            None => SiteSource::WorkingTree(repo),
+        }
+    }
+
+    async fn serve(&mut self, source: SiteSource, state: &AppState) {
+        // Release the port before binding again so the URL stays the same
+        if let Some(server) = self.server.take() {
+            server.stop().await;
+        }
+
+        match PreviewServer::start(source, state.preview_port, self.live_reload).await {
+            Ok(server) => {
+                self.server = Some(server);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    fn render_status(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let key_style = Style::default().fg(theme.text_secondary);
+        let live_reload = if self.live_reload { "on" } else { "off" };
+
+        let lines = match (&self.server, &self.error) {
+            (_, Some(error)) => vec![Line::from(Span::styled(error.clone(), Style::default().fg(theme.error)))],
+            (Some(server), None) => vec![
+                Line::from(vec![
+                    Span::styled("● Serving ", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+                    Span::styled(server.source().label(), Style::default().fg(theme.text)),
+                    Span::styled(" at ", key_style),
+                    Span::styled(server.url(), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                ]),
+                Line::from(Span::styled(format!("Live reload: {}", live_reload), key_style)),
+            ],
+            (None, None) => vec![
+                Line::from(Span::styled("○ Not serving", Style::default().fg(theme.text_secondary))),
+                Line::from(Span::styled(format!("Live reload: {}", live_reload), key_style)),
+            ],
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Server").border_style(theme.border_style()));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_commits(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let serving_sha = self.server.as_ref().map(|server| match server.source() {
+            SiteSource::Commit { sha, .. } => sha.as_str(),
+            SiteSource::WorkingTree(_) => "",
+        });
+        let marker = |serving: bool| if serving { "● " } else { "  " };
+
+        let mut items = vec![ListItem::new(Line::from(vec![
+            Span::styled(marker(serving_sha == Some("")), Style::default().fg(theme.success)),
+            Span::styled("Working tree", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled("  current files, reloads on save", Style::default().fg(theme.text_secondary)),
+        ]))];
+
+        items.extend(self.commits.iter().map(|commit| {
+            ListItem::new(Line::from(vec![
+                Span::styled(marker(serving_sha == Some(commit.sha.as_str())), Style::default().fg(theme.success)),
+                Span::styled(short_sha(&commit.sha).to_string(), Style::default().fg(theme.warning)),
+                Span::styled(
+                    format!("  {}  ", time::format(commit.date, "%b %d %H:%M")),
+                    Style::default().fg(theme.text_secondary),
+                ),
+                Span::styled(commit.summary.clone(), Style::default().fg(theme.text)),
+                Span::styled(format!("  ({})", commit.author), Style::default().fg(theme.text_secondary)),
+            ]))
+        }));
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Choose what to serve").border_style(theme.border_focused_style()))
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background));
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+impl Screen for SitePreviewScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::SitePreview)
+            .with_context(ScreenContext::ClassAndStudent(self.class.clone(), self.student.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            match key.code {
+                _ if keymap::is(Action::Up, &key) => {
+                    self.selected = self.selected.saturating_sub(1);
+                }
+                _ if keymap::is(Action::Down, &key) && self.selected < self.commits.len() => {
+                    self.selected += 1;
+                }
+                _ if keymap::is(Action::Select, &key) => {
+                    let source = self.selected_source(state);
+                    self.serve(source, state).await;
+                }
+                _ if keymap::is(Action::OpenInBrowser, &key) => {
+                    if let Some(server) = &self.server {
+                        return Ok(Some(AppEvent::OpenUrl(server.url())));
+                    }
+                    let source = self.selected_source(state);
+                    self.serve(source, state).await;
+                    return Ok(self.server.as_ref().map(|server| AppEvent::OpenUrl(server.url())));
+                }
+                _ if keymap::is(Action::LiveReload, &key) => {
+                    self.live_reload = !self.live_reload;
+                    if let Some(source) = self.server.as_ref().map(|server| server.source().clone()) {
+                        self.serve(source, state).await;
+                    }
+                }
+                _ if keymap::is(Action::StopServer, &key) => {
+                    if let Some(server) = self.server.take() {
+                        server.stop().await;
+                    }
+                }
+                _ if keymap::is(Action::Refresh, &key) => self.needs_load = true,
+                _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                _ => {}
+            }
+            Ok(None)
+        })
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        _delta_time: Duration,
+        state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.needs_load {
+                self.needs_load = false;
+                match state.git_manager.recent_commits(&self.student.github_username, &self.class.name, COMMIT_LIMIT).await {
+                    Ok(commits) => {
+                        self.commits = commits;
+                        self.error = None;
+                    }
+                    Err(e) => {
+                        self.commits.clear();
+                        self.error = Some(e.to_string());
+                    }
+                }
+                self.selected = self.selected.min(self.commits.len());
+            }
+            Ok(())
+        })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🔭 Site Preview - {}", self.student.github_username))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(4),  // Server status
+                Constraint::Min(5),     // Commit list
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        self.render_status(frame, chunks[0], theme);
+        self.render_commits(frame, chunks[1], theme);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Serve  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenInBrowser]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Open in Browser  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::LiveReload]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Live Reload  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::StopServer]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Stop  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Refresh]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Refresh  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+        ]);
+
+        let help_paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[2]);
+    }
+}
@@ -0,0 +1,389 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{assignments::{format_points, CommitSource}, Assignment, Class, Grade, Note, RubricCriterion, Student},
+    git::LocalCommit,
+    preview::short_sha,
+    runner::{RunStatus, TestResult},
+    site_check::{Severity, SiteCheck},
+    ui::{
+        animations::AnimationState,
+        components::heatmap::{count_by_day, ActivityHeatmap},
+        screens::{Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::{launcher::OpenAction, time},
+};
+
+/// Weeks of history shown in the heatmap
+pub const HEATMAP_WEEKS: usize = 12;
+/// Commits listed under "Latest Commits"
+pub const RECENT_COMMITS: usize = 8;
+
+/// An assignment with its rubric and this student's grade, if graded
+pub struct AssignmentGrade {
+    pub assignment: Assignment,
+    pub rubric: Vec<RubricCriterion>,
+    pub grade: Option<Grade>,
+}
+
+/// What is stored in the database about a student
+pub struct StudentRecord {
+    pub notes: Vec<Note>,
+    pub grades: Vec<AssignmentGrade>,
+    pub site_check: Option<SiteCheck>,
+    pub test_result: Option<TestResult>,
+}
+
+/// Commit history read from the local clone or GitHub after the screen opens
+enum Activity {
+    Loading,
+    Loaded {
+        daily_commits: HashMap<NaiveDate, u32>,
+        recent_commits: Vec<LocalCommit>,
+        source: CommitSource,
+    },
+    Failed(String),
+}
+
+pub struct StudentDetailScreen {
+    class: Class,
+    student: Student,
+    record: StudentRecord,
+    activity: Activity,
+}
+
+impl StudentDetailScreen {
+    pub fn new(class: Class, student: Student, record: StudentRecord) -> Self {
+        Self {
+            class,
+            student,
+            record,
+            activity: Activity::Loading,
+        }
+    }
+
+    pub fn set_activity(&mut self, commit_times: &[DateTime<Utc>], recent_commits: Vec<LocalCommit>, source: CommitSource) {
+        self.activity = Activity::Loaded {
+            daily_commits: count_by_day(commit_times),
+            recent_commits,
+            source,
+        };
+    }
+
+    pub fn set_activity_error(&mut self, error: String) {
+        self.activity = Activity::Failed(error);
+    }
+
+    fn render_profile(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let label = |text: &str| Span::styled(format!("{:<10}", text), Style::default().fg(theme.text_secondary));
+        let lines = vec![
+            Line::from(vec![
+                label("Name"),
+                Span::styled(self.student.username.clone(), Style::default().fg(theme.text).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(vec![label("GitHub"), Span::styled(format!("@{}", self.student.github_username), Style::default().fg(theme.primary))]),
+            Line::from(vec![label("Class"), Span::styled(self.class.name.clone(), Style::default().fg(theme.text))]),
+            Line::from(vec![
+                label("Added"),
+                Span::styled(
+                    time::format(self.student.created_at, "%b %d %Y"),
+                    Style::default().fg(theme.text),
+                ),
+            ]),
+        ];
+
+        let block = Block::default().borders(Borders::ALL).title("Profile").border_style(theme.border_style());
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn render_repo_status(
+        &self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        state: &AppState,
+        theme: &Theme,
+    ) {
+        let label = |text: &str| Span::styled(format!("{:<10}", text), Style::default().fg(theme.text_secondary));
+        let repo_path = state.git_manager.get_repo_path(&self.student.github_username, &self.class.name);
+
+        let mut lines = vec![Line::from(vec![
+            label("Clone"),
+            if repo_path.exists() {
+                Span::styled(repo_path.display().to_string(), Style::default().fg(theme.text))
+            } else {
+                Span::styled("not cloned", Style::default().fg(theme.error))
+            },
+        ])];
+
+        let last_commit = match &self.activity {
+            Activity::Loaded { recent_commits, source, .. } => match recent_commits.first() {
+                Some(commit) => Span::styled(
+                    format!("{} ({})", time::format(commit.date, "%b %d %H:%M"), source.label()),
+                    Style::default().fg(theme.text),
+                ),
+                None => Span::styled("no commits", Style::default().fg(theme.warning)),
+            },
+            Activity::Loading => Span::styled("loading...", Style::default().fg(theme.text_secondary)),
+            Activity::Failed(_) => Span::styled("unavailable", Style::default().fg(theme.error)),
+        };
+        lines.push(Line::from(vec![label("Last push"), last_commit]));
+
+        lines.push(Line::from(vec![
+            label("Site"),
+            match &self.record.site_check {
+                Some(check) => {
+                    let color = match check.report.status() {
+                        Severity::Pass => theme.success,
+                        Severity::Warn => theme.warning,
+                        Severity::Fail => theme.error,
+                    };
+                    Span::styled(check.report.summary(), Style::default().fg(color))
+                }
+                None => Span::styled("not checked", Style::default().fg(theme.text_secondary)),
+            },
+        ]));
+
+        lines.push(Line::from(vec![
+            label("Tests"),
+            match &self.record.test_result {
+                Some(result) => {
+                    let color = if result.status == RunStatus::Passed { theme.success } else { theme.error };
+                    Span::styled(format!("{} {}", result.status.label(), result.summary()), Style::default().fg(color))
+                }
+                None => Span::styled("not run", Style::default().fg(theme.text_secondary)),
+            },
+        ]));
+
+        let block = Block::default().borders(Borders::ALL).title("Repository").border_style(theme.border_style());
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn render_activity(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Activity — last {} weeks", HEATMAP_WEEKS))
+            .border_style(theme.border_style());
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        match &self.activity {
+            Activity::Loaded { daily_commits, .. } => {
+                let heatmap = ActivityHeatmap::new(daily_commits, time::today(), theme).weeks(HEATMAP_WEEKS);
+                frame.render_widget(heatmap, inner_area);
+            }
+            Activity::Loading => {
+                let paragraph = Paragraph::new("Loading commit history...").style(Style::default().fg(theme.text_secondary));
+                frame.render_widget(paragraph, inner_area);
+            }
+            Activity::Failed(error) => {
+                let paragraph = Paragraph::new(format!("Could not load commits: {}", error))
+                    .style(Style::default().fg(theme.error))
+                    .wrap(Wrap { trim: true });
+                frame.render_widget(paragraph, inner_area);
+            }
+        }
+    }
+
+    fn render_commits(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default().borders(Borders::ALL).title("Latest Commits").border_style(theme.border_style());
+
+        let lines: Vec<Line> = match &self.activity {
+            Activity::Loaded { recent_commits, .. } if !recent_commits.is_empty() => recent_commits
+                .iter()
+                .map(|commit| {
+                    Line::from(vec![
+                        Span::styled(format!("{} ", short_sha(&commit.sha)), Style::default().fg(theme.primary)),
+                        Span::styled(
+                            format!("{} ", time::format(commit.date, "%b %d")),
+                            Style::default().fg(theme.text_secondary),
+                        ),
+                        Span::styled(commit.summary.clone(), Style::default().fg(theme.text)),
+                    ])
+                })
+                .collect(),
+            Activity::Loaded { .. } => vec![Line::from(Span::styled("No commits yet", Style::default().fg(theme.text_secondary)))],
+            _ => Vec::new(),
+        };
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn render_grades(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default().borders(Borders::ALL).title("Grades").border_style(theme.border_style());
+
+        let lines: Vec<Line> = if self.record.grades.is_empty() {
+            vec![Line::from(Span::styled("No assignments", Style::default().fg(theme.text_secondary)))]
+        } else {
+            self.record
+                .grades
+                .iter()
+                .map(|entry| {
+                    let max: f64 = entry.rubric.iter().map(|criterion| criterion.max_points).sum();
+                    let score = match &entry.grade {
+                        Some(grade) => Span::styled(
+                            format!("{} / {}", format_points(grade.total()), format_points(max)),
+                            Style::default().fg(theme.success),
+                        ),
+                        None => Span::styled("not graded", Style::default().fg(theme.text_secondary)),
+                    };
+                    Line::from(vec![
+                        Span::styled(format!("{}: ", entry.assignment.name), Style::default().fg(theme.text)),
+                        score,
+                    ])
+                })
+                .collect()
+        };
+
+        frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: true }), area);
+    }
+
+    fn render_notes(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Notes ({})", self.record.notes.len()))
+            .border_style(theme.border_style());
+
+        let mut lines = Vec::new();
+        if self.record.notes.is_empty() {
+            lines.push(Line::from(Span::styled(format!("No notes. Press {} to add one.", keymap::label(&[Action::OpenNotes])), Style::default().fg(theme.text_secondary))));
+        }
+        for note in &self.record.notes {
+            lines.push(Line::from(Span::styled(
+                time::format(note.created_at, "%b %d %Y"),
+                Style::default().fg(theme.text_secondary),
+            )));
+            lines.push(Line::from(Span::styled(note.body.clone(), Style::default().fg(theme.text))));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block).wrap(Wrap { trim: true }), area);
+    }
+}
+
+impl Screen for StudentDetailScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::StudentDetail)
+            .with_context(ScreenContext::ClassAndStudent(self.class.clone(), self.student.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        _state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        let context = ScreenContext::ClassAndStudent(self.class.clone(), self.student.clone());
+        let result = match key.code {
+            _ if keymap::is(Action::Back, &key) => Ok(Some(AppEvent::GoBack)),
+            _ if keymap::is(Action::Refresh, &key) => Ok(Some(AppEvent::RefreshData)),
+            _ if keymap::is(Action::OpenNotes, &key) => Ok(Some(AppEvent::NavigateToScreen(
+                ScreenType::new(ScreenTypeVariant::StudentNotes).with_context(context),
+            ))),
+            _ if keymap::is(Action::OpenFiles, &key) => Ok(Some(AppEvent::NavigateToScreen(
+                ScreenType::new(ScreenTypeVariant::FileBrowser).with_context(context),
+            ))),
+            _ if keymap::is(Action::OpenPreview, &key) => Ok(Some(AppEvent::NavigateToScreen(
+                ScreenType::new(ScreenTypeVariant::SitePreview).with_context(context),
+            ))),
+            _ if keymap::is(Action::OpenHeatmap, &key) => Ok(Some(AppEvent::NavigateToScreen(
+                ScreenType::new(ScreenTypeVariant::ActivityHeatmap).with_context(context),
+            ))),
+            _ if keymap::is(Action::OpenInBrowser, &key) => Ok(Some(AppEvent::OpenWith(OpenAction::Browser, self.student.github_username.clone()))),
+            _ => Ok(None),
+        };
+        Box::pin(async { result })
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        _delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("👤 {}", self.student.github_username))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(6),  // Profile and repository
+                Constraint::Length(11), // Heatmap
+                Constraint::Min(5),     // Commits, grades and notes
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        let top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(chunks[0]);
+        self.render_profile(frame, top[0], theme);
+        self.render_repo_status(frame, top[1], state, theme);
+
+        self.render_activity(frame, chunks[1], theme);
+
+        let bottom = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(45),
+                Constraint::Percentage(25),
+                Constraint::Percentage(30),
+            ])
+            .split(chunks[2]);
+        self.render_commits(frame, bottom[0], theme);
+        self.render_grades(frame, bottom[1], theme);
+        self.render_notes(frame, bottom[2], theme);
+
+        let help_text = Line::from(vec![
+            Span::styled(keymap::label(&[Action::OpenNotes]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Notes  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenFiles]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Files  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenPreview]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Preview  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenHeatmap]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Heatmap  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::OpenInBrowser]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Open Site  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Refresh]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Refresh  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Back  ", Style::default().fg(theme.text_secondary)),
+            Span::styled(keymap::label(&[Action::Help]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Help", Style::default().fg(theme.text_secondary)),
+        ]);
+        let help_paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[3]);
+    }
+}
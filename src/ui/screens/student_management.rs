@@ -1,5 +1,5 @@
 use anyhow::Result;
-use crossterm::event::{KeyEvent, KeyCode};
+use crossterm::event::KeyEvent;
 use ratatui::{
     Frame, backend::Backend, 
     layout::{Alignment, Rect},
@@ -10,7 +10,7 @@ use ratatui::{
 use std::{future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
+    app::{keymap, Action, AppEvent, AppState},
     data::Class,
     ui::{animations::AnimationState, themes::Theme},
 };
@@ -44,6 +44,7 @@ impl StudentManagementScreen {
         let menu_options = vec![
             MenuOption::new("Add Student(s)", "Add new students to this class", "add"),
             MenuOption::new("Delete Student", "Remove a student from this class", "delete"),
+            MenuOption::new("Student Notes", "Read, write and search private notes", "notes"),
             MenuOption::new("Back", "Return to class management menu", "back"),
         ];
 
@@ -63,16 +64,16 @@ impl Screen for StudentManagementScreen {
 
     fn handle_key_event(&mut self, key: KeyEvent, state: &AppState) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + '_>> {
         match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => Box::pin(async move { Ok(Some(AppEvent::GoBack)) }),
-            KeyCode::Char('k') | KeyCode::Up => {
+            _ if keymap::is(Action::Back, &key) => Box::pin(async move { Ok(Some(AppEvent::GoBack)) }),
+            _ if keymap::is(Action::Up, &key) => {
                 self.selected_index = (self.selected_index + self.menu_options.len() - 1) % self.menu_options.len();
                 Box::pin(async move { Ok(None) })
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            _ if keymap::is(Action::Down, &key) => {
                 self.selected_index = (self.selected_index + 1) % self.menu_options.len();
                 Box::pin(async move { Ok(None) })
             }
-            KeyCode::Enter => {
+            _ if keymap::is(Action::Select, &key) => {
                 match self.menu_options[self.selected_index].action.as_str() {
                     "add" => Box::pin(async move {
                         Ok(Some(AppEvent::NavigateToScreen(
@@ -86,6 +87,12 @@ impl Screen for StudentManagementScreen {
                                 .with_context(ScreenContext::Class(self.class.clone()))
                         )))
                     }),
+                    "notes" => Box::pin(async move {
+                        Ok(Some(AppEvent::NavigateToScreen(
+                            ScreenType::new(ScreenTypeVariant::StudentNotes)
+                                .with_context(ScreenContext::Class(self.class.clone()))
+                        )))
+                    }),
                     "back" => Box::pin(async move { Ok(Some(AppEvent::GoBack)) }),
                     _ => Box::pin(async move { Ok(None) }),
                 }
@@ -144,10 +151,10 @@ impl Screen for StudentManagementScreen {
 
         // Render help text
         let help_text = Line::from(vec![
-            Span::styled("↑/k up • ", Style::default().fg(theme.text_secondary)),
-            Span::styled("↓/j down • ", Style::default().fg(theme.text_secondary)),
-            Span::styled("enter select • ", Style::default().fg(theme.text_secondary)),
-            Span::styled("q quit", Style::default().fg(theme.text_secondary)),
+            Span::styled(format!("{} up • ", keymap::label(&[Action::Up])), Style::default().fg(theme.text_secondary)),
+            Span::styled(format!("{} down • ", keymap::label(&[Action::Down])), Style::default().fg(theme.text_secondary)),
+            Span::styled(format!("{} select • ", keymap::label(&[Action::Select])), Style::default().fg(theme.text_secondary)),
+            Span::styled(format!("{} back", keymap::label(&[Action::Back])), Style::default().fg(theme.text_secondary)),
         ]);
 
         let help_paragraph = Paragraph::new(help_text)
@@ -0,0 +1,433 @@
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use std::{collections::HashMap, future::Future, pin::Pin, time::Duration};
+
+use crate::{
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Class, Student},
+    runner::{RunStatus, RunnerConfig, TestResult},
+    ui::{
+        animations::AnimationState,
+        mouse::MouseAction,
+        components::input::AnimatedInput,
+        screens::{scroll_key, table_mouse_key, Screen, ScreenContext, ScreenType, ScreenTypeVariant},
+        themes::Theme,
+    },
+    utils::time,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Results,
+    Output,
+    EditConfig,
+}
+
+pub struct TestRunnerScreen {
+    class: Class,
+    students: Vec<Student>,
+    config: RunnerConfig,
+    results: HashMap<i64, TestResult>,
+    table_state: TableState,
+    /// The table's area inside its block when last drawn, for mouse hit-testing
+    table_area: Rect,
+    mode: Mode,
+    output_scroll: usize,
+    inputs: Vec<AnimatedInput>,
+    focused_input: usize,
+    error: Option<String>,
+}
+
+impl TestRunnerScreen {
+    pub fn new(class: Class, students: Vec<Student>, config: RunnerConfig, results: Vec<TestResult>) -> Self {
+        let mut table_state = TableState::default();
+        if !students.is_empty() {
+            table_state.select(Some(0));
+        }
+
+        let mut screen = Self {
+            class,
+            students,
+            config,
+            results: HashMap::new(),
+            table_state,
+            table_area: Rect::default(),
+            mode: Mode::Results,
+            output_scroll: 0,
+            inputs: vec![
+                AnimatedInput::new("Command (runs in each repo)"),
+                AnimatedInput::new("Timeout (seconds)"),
+                AnimatedInput::new("Concurrency (repos at once)"),
+            ],
+            focused_input: 0,
+            error: None,
+        };
+        screen.set_results(results);
+        screen
+    }
+
+    pub fn set_results(&mut self, results: Vec<TestResult>) {
+        for result in results {
+            self.results.insert(result.student_id, result);
+        }
+    }
+
+    fn selected_result(&self) -> Option<&TestResult> {
+        let student = self.students.get(self.table_state.selected()?)?;
+        self.results.get(&student.id)
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        if self.students.is_empty() {
+            return;
+        }
+        let selected = self.table_state.selected().unwrap_or(0);
+        let next = if forward {
+            (selected + 1).min(self.students.len() - 1)
+        } else {
+            selected.saturating_sub(1)
+        };
+        self.table_state.select(Some(next));
+    }
+
+    fn start_editing(&mut self) {
+        self.inputs[0].set_value(self.config.command.clone());
+        self.inputs[1].set_value(self.config.timeout_secs.to_string());
+        self.inputs[2].set_value(self.config.concurrency.to_string());
+        self.focus_input(0);
+        self.error = None;
+        self.mode = Mode::EditConfig;
+    }
+
+    fn focus_input(&mut self, index: usize) {
+        for input in &mut self.inputs {
+            input.unfocus();
+        }
+        self.focused_input = index;
+        self.inputs[index].focus();
+    }
+
+    fn parse_config(&self) -> Result<RunnerConfig, String> {
+        let command = self.inputs[0].value().trim().to_string();
+        if command.is_empty() {
+            return Err("Command cannot be empty".to_string());
+        }
+        let timeout_secs = self.inputs[1].value().trim().parse::<u64>()
+            .ok()
+            .filter(|t| *t > 0)
+            .ok_or_else(|| "Timeout must be a whole number of seconds".to_string())?;
+        let concurrency = self.inputs[2].value().trim().parse::<usize>()
+            .ok()
+            .filter(|c| *c > 0)
+            .ok_or_else(|| "Concurrency must be at least 1".to_string())?;
+
+        Ok(RunnerConfig { command, timeout_secs, concurrency })
+    }
+
+    fn status_style(status: RunStatus, theme: &Theme) -> Style {
+        match status {
+            RunStatus::Passed => Style::default().fg(theme.success),
+            RunStatus::Failed | RunStatus::Error => Style::default().fg(theme.error),
+            RunStatus::TimedOut => Style::default().fg(theme.warning),
+            RunStatus::NotCloned => Style::default().fg(theme.text_secondary),
+        }
+    }
+
+    fn render_results(&mut self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let header = Row::new(
+            ["Student", "Status", "Exit", "Time", "Ran", "Output"]
+                .into_iter()
+                .map(|title| Cell::from(title).style(Style::default().add_modifier(Modifier::BOLD))),
+        );
+
+        let rows: Vec<Row> = self.students.iter().map(|student| {
+            match self.results.get(&student.id) {
+                Some(result) => Row::new(vec![
+                    Cell::from(student.github_username.clone()),
+                    Cell::from(result.status.label()).style(Self::status_style(result.status, theme)),
+                    Cell::from(result.exit_code.map(|code| code.to_string()).unwrap_or_else(|| "-".to_string())),
+                    Cell::from(format!("{:.1}s", result.duration_ms as f64 / 1000.0)),
+                    Cell::from(time::format(result.ran_at, "%b %d %H:%M")),
+                    Cell::from(result.summary().to_string()).style(Style::default().fg(theme.text_secondary)),
+                ]),
+                None => Row::new(vec![
+                    Cell::from(student.github_username.clone()),
+                    Cell::from("not run").style(Style::default().fg(theme.text_secondary)),
+                ]),
+            }
+        }).collect();
+
+        let block = Block::default().borders(Borders::TOP).title("Latest Results").border_style(theme.border_style());
+        self.table_area = block.inner(area);
+        let table = Table::new(rows)
+            .header(header)
+            .block(block)
+            .style(Style::default().fg(theme.text))
+            .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
+            .highlight_symbol("▶ ")
+            .widths(&[
+                Constraint::Length(20), // Student
+                Constraint::Length(11), // Status
+                Constraint::Length(5),  // Exit code
+                Constraint::Length(8),  // Duration
+                Constraint::Length(13), // Ran at
+                Constraint::Min(10),    // Output summary
+            ]);
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn render_output(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let Some(result) = self.selected_result() else {
+            let paragraph = Paragraph::new(format!("No results for this student yet. Press {} to run the tests.", keymap::label(&[Action::RunTests])))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme.text_secondary));
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled("── stdout ──", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD))),
+        ];
+        lines.extend(result.stdout.lines().map(|line| Line::from(line.to_string())));
+        lines.push(Line::from(Span::styled("── stderr ──", Style::default().fg(theme.error).add_modifier(Modifier::BOLD))));
+        lines.extend(result.stderr.lines().map(|line| Line::from(Span::styled(line.to_string(), Style::default().fg(theme.warning)))));
+
+        let title = format!(
+            "{} — {} (exit {})",
+            result.github_username,
+            result.status.label(),
+            result.exit_code.map(|code| code.to_string()).unwrap_or_else(|| "-".to_string())
+        );
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title).border_style(theme.border_focused_style()))
+            .scroll((self.output_scroll as u16, 0));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_config_form(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(60, 60, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("⚙️ Grading Command")
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Command
+                Constraint::Length(3), // Timeout
+                Constraint::Length(3), // Concurrency
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        for (input, chunk) in self.inputs.iter().zip(chunks.iter()) {
+            frame.render_widget(input, *chunk);
+        }
+
+        if let Some(error) = &self.error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[3]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Tab", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(" Next Field  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Save  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[5]);
+    }
+}
+
+impl Screen for TestRunnerScreen {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn captures_text_input(&self) -> bool {
+        self.mode == Mode::EditConfig
+    }
+
+    fn screen_type(&self) -> ScreenType {
+        ScreenType::new(ScreenTypeVariant::TestRunner)
+            .with_context(ScreenContext::Class(self.class.clone()))
+    }
+
+    fn handle_key_event<'a>(
+        &'a mut self,
+        key: KeyEvent,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        Box::pin(async move {
+            match self.mode {
+                Mode::Results => match key.code {
+                    _ if keymap::is(Action::Up, &key) => self.move_selection(false),
+                    _ if keymap::is(Action::Down, &key) => self.move_selection(true),
+                    _ if keymap::is(Action::Select, &key) && self.selected_result().is_some() => {
+                        self.output_scroll = 0;
+                        self.mode = Mode::Output;
+                    }
+                    _ if keymap::is(Action::RunTests, &key) => return Ok(Some(AppEvent::RunTests)),
+                    _ if keymap::is(Action::Edit, &key) => self.start_editing(),
+                    _ if keymap::is(Action::Export, &key) => return Ok(Some(AppEvent::ExportTestResults)),
+                    _ if keymap::is(Action::Back, &key) => return Ok(Some(AppEvent::GoBack)),
+                    _ => {}
+                },
+                Mode::Output => match key.code {
+                    _ if keymap::is(Action::Up, &key) => self.output_scroll = self.output_scroll.saturating_sub(1),
+                    _ if keymap::is(Action::Down, &key) => self.output_scroll += 1,
+                    _ if keymap::is(Action::PageUp, &key) => self.output_scroll = self.output_scroll.saturating_sub(20),
+                    _ if keymap::is(Action::PageDown, &key) => self.output_scroll += 20,
+                    _ if keymap::is(Action::Back, &key) || keymap::is(Action::Select, &key) => self.mode = Mode::Results,
+                    _ => {}
+                },
+                Mode::EditConfig => match key.code {
+                    KeyCode::Esc => self.mode = Mode::Results,
+                    KeyCode::Tab | KeyCode::Down => self.focus_input((self.focused_input + 1) % self.inputs.len()),
+                    KeyCode::BackTab | KeyCode::Up => {
+                        self.focus_input((self.focused_input + self.inputs.len() - 1) % self.inputs.len())
+                    }
+                    KeyCode::Enter => match self.parse_config() {
+                        Ok(config) => match state.database.save_runner_config(self.class.id, &config).await {
+                            Ok(()) => {
+                                self.config = config;
+                                self.mode = Mode::Results;
+                            }
+                            Err(e) => self.error = Some(format!("Failed to save settings: {}", e)),
+                        },
+                        Err(e) => self.error = Some(e),
+                    },
+                    _ => {
+                        self.inputs[self.focused_input].handle_key_event(key);
+                        self.error = None;
+                    }
+                },
+            }
+            Ok(None)
+        })
+    }
+
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // Clicks only pick rows while the table has the keyboard
+        let rows = self.students.len();
+        let key = if self.mode == Mode::Results {
+            table_mouse_key(&mut self.table_state, self.table_area, rows, mouse, keymap::key_event(Action::Select))
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
+    fn update<'a>(
+        &'a mut self,
+        delta_time: Duration,
+        _state: &'a mut AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        for input in &mut self.inputs {
+            input.update(delta_time);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn render(
+        &mut self,
+        frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+        area: Rect,
+        _state: &AppState,
+        _animation_state: &AnimationState,
+        theme: &Theme,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🧪 Test Runner - {}", self.class.name))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),  // Command settings
+                Constraint::Min(5),     // Results or output
+                Constraint::Length(2),  // Help text
+            ])
+            .split(inner_area);
+
+        let settings = Line::from(vec![
+            Span::styled("Command: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(self.config.command.clone(), Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("   Timeout: {}s   Concurrency: {}", self.config.timeout_secs, self.config.concurrency),
+                Style::default().fg(theme.text_secondary),
+            ),
+        ]);
+        frame.render_widget(Paragraph::new(settings).alignment(Alignment::Center), chunks[0]);
+
+        if self.mode == Mode::Output {
+            self.render_output(frame, chunks[1], theme);
+        } else {
+            self.render_results(frame, chunks[1], theme);
+        }
+
+        let help_text = match self.mode {
+            Mode::Output => Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Scroll  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::PageUp, Action::PageDown]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Page  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Results", Style::default().fg(theme.text_secondary)),
+            ]),
+            _ => Line::from(vec![
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Output  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::RunTests]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Run All  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Edit]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Edit Command  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Export]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Export CSV  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+            ]),
+        };
+        let help_paragraph = Paragraph::new(help_text)
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(help_paragraph, chunks[2]);
+
+        if self.mode == Mode::EditConfig {
+            self.render_config_form(frame, area, theme);
+        }
+    }
+}
@@ -1,45 +1,189 @@
 use anyhow::Result;
+use chrono::NaiveDate;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
 };
-use std::{future::Future, pin::Pin, time::Duration};
+use std::{cmp::Ordering, collections::HashMap, future::Future, pin::Pin, time::Duration};
 
 use crate::{
-    app::{AppEvent, AppState},
-    data::{Class, Student, github::{WeekActivity, GitHubClient, format_weekday, get_current_weekdays}},
+    app::{keymap, Action, AppEvent, AppState},
+    data::{Class, Student, calendar::SchoolCalendar, github::{DateRange, WeekActivity, GitHubClient}, metrics::StudentMetrics},
     ui::{
         animations::AnimationState,
-        screens::{Screen, ScreenType, ScreenTypeVariant, ScreenContext},
-        themes::Theme,
+        mouse::MouseAction,
+        components::{input::AnimatedInput, table_filter::{SortColumn, TableFilter}},
+        screens::{scroll_key, table_mouse_key, Screen, ScreenType, ScreenTypeVariant, ScreenContext},
+        themes::{ActivityLevel, Theme},
     },
+    utils::time,
 };
 
+/// Columns the week view can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WeekSort {
+    Name,
+    Total,
+    LastCommit,
+    Streak,
+    Consistency,
+    Errors,
+}
+
+impl SortColumn for WeekSort {
+    const ALL: &'static [Self] = &[
+        WeekSort::Name,
+        WeekSort::Total,
+        WeekSort::LastCommit,
+        WeekSort::Streak,
+        WeekSort::Consistency,
+        WeekSort::Errors,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            WeekSort::Name => "Name",
+            WeekSort::Total => "Total commits",
+            WeekSort::LastCommit => "Last commit",
+            WeekSort::Streak => "Streak",
+            WeekSort::Consistency => "Consistency",
+            WeekSort::Errors => "Errors",
+        }
+    }
+}
+
 pub struct WeekViewScreen {
     class: Class,
     students: Vec<Student>,
     activities: Vec<WeekActivity>,
+    /// Non-school days are shown as neutral rather than as days without commits
+    calendar: SchoolCalendar,
+    /// Streaks and consistency per student id, from the stored commit history
+    metrics: HashMap<i64, StudentMetrics>,
+    range: DateRange,
     loading: bool,
     error: Option<String>,
     table_state: TableState,
+    /// The table's area inside its block when last drawn, for mouse hit-testing
+    table_area: Rect,
+    filter: TableFilter<WeekSort>,
+    /// Custom range being typed, shown as a popup while focused
+    range_input: AnimatedInput,
+    picking_range: bool,
+    range_error: Option<String>,
+    /// First day column shown when the range is too wide for the terminal
+    day_offset: usize,
 }
 
+/// Width of a day column plus the gap after it
+const DAY_COLUMN_WIDTH: u16 = 8;
+/// Width of the highlight symbol and every column besides the days, with their gaps
+const FIXED_COLUMNS_WIDTH: u16 = 2 + 20 + 8 + 10 + 12 + 12 + 5;
+
 impl WeekViewScreen {
-    pub fn new(class: Class, students: Vec<Student>) -> Self {
+    pub fn new(class: Class, students: Vec<Student>, calendar: SchoolCalendar, metrics: HashMap<i64, StudentMetrics>) -> Self {
         let mut table_state = TableState::default();
         table_state.select(Some(0));
+        let mut range_input = AnimatedInput::new("Date range");
+        range_input.set_placeholder("YYYY-MM-DD..YYYY-MM-DD");
         
         Self {
             class,
             students,
             activities: Vec::new(),
+            calendar,
+            metrics,
+            range: DateRange::this_week(false),
             loading: false,
             error: None,
             table_state,
+            table_area: Rect::default(),
+            filter: TableFilter::new(WeekSort::Name),
+            range_input,
+            picking_range: false,
+            range_error: None,
+            day_offset: usize::MAX,
+        }
+    }
+
+    /// Switch to another range; the caller reloads activity through `RefreshData`
+    fn set_range(&mut self, range: DateRange) -> Option<AppEvent> {
+        if range == self.range {
+            return None;
+        }
+        self.range = range;
+        // Start on the latest days, which are clamped into view when drawn
+        self.day_offset = usize::MAX;
+        Some(AppEvent::RefreshData)
+    }
+
+    /// Indices into `activities` (and `students`) that pass the filter, in sort order
+    fn visible_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self
+            .activities
+            .iter()
+            .enumerate()
+            .filter(|(_, activity)| {
+                (!self.filter.only_inactive || (activity.error.is_none() && activity.total_commits == 0))
+                    && (!self.filter.only_errors || activity.error.is_some())
+                    && self.filter.score(&[&activity.student_username, &activity.student_github_username]).is_some()
+            })
+            .map(|(index, _)| index)
+            .collect();
+        rows.sort_by(|a, b| self.filter.order(self.compare_rows(*a, *b)));
+        rows
+    }
+
+    fn compare_rows(&self, a: usize, b: usize) -> Ordering {
+        let (first, second) = (&self.activities[a], &self.activities[b]);
+        let metrics = |index: usize| self.students.get(index).and_then(|student| self.metrics.get(&student.id));
+        let by_name = first.student_username.to_lowercase().cmp(&second.student_username.to_lowercase());
+        let ordering = match self.filter.sort {
+            WeekSort::Name => Ordering::Equal,
+            WeekSort::Total => first.total_commits.cmp(&second.total_commits),
+            WeekSort::LastCommit => first.latest_commit.cmp(&second.latest_commit),
+            WeekSort::Streak => metrics(a).map(|m| m.current_streak).cmp(&metrics(b).map(|m| m.current_streak)),
+            WeekSort::Consistency => metrics(a).map(|m| m.consistency).cmp(&metrics(b).map(|m| m.consistency)),
+            WeekSort::Errors => first.error.is_some().cmp(&second.error.is_some()),
+        };
+        ordering.then(by_name)
+    }
+
+    /// The student on the selected row, through the current sort and filter
+    fn selected_student(&self) -> Option<&Student> {
+        let selected = self.table_state.selected()?;
+        self.visible_rows().get(selected).and_then(|index| self.students.get(*index))
+    }
+
+    fn handle_range_input(&mut self, key: KeyEvent) -> Option<AppEvent> {
+        match key.code {
+            KeyCode::Esc => {
+                self.range_input.unfocus();
+                self.picking_range = false;
+                self.range_error = None;
+                None
+            }
+            KeyCode::Enter => match DateRange::parse(self.range_input.value(), self.range.include_weekends) {
+                Ok(range) => {
+                    self.range_input.unfocus();
+                    self.picking_range = false;
+                    self.range_error = None;
+                    self.set_range(range)
+                }
+                Err(e) => {
+                    self.range_error = Some(e.to_string());
+                    None
+                }
+            },
+            _ => {
+                self.range_input.handle_key_event(key);
+                self.range_error = None;
+                None
+            }
         }
     }
 
@@ -51,7 +195,7 @@ impl WeekViewScreen {
         let mut activities = Vec::new();
         
         for student in &self.students {
-            match github_client.get_week_activity(&student.github_username).await {
+            match github_client.get_week_activity(&student.github_username, &self.range).await {
                 Ok(activity) => {
                     activities.push(activity);
                 }
@@ -59,7 +203,7 @@ impl WeekViewScreen {
                     activities.push(WeekActivity {
                         student_username: student.username.clone(),
                         student_github_username: student.github_username.clone(),
-                        daily_commits: std::collections::HashMap::new(),
+                        daily_commits: HashMap::new(),
                         total_commits: 0,
                         latest_commit: None,
                         error: Some(e.to_string()),
@@ -72,29 +216,37 @@ impl WeekViewScreen {
         self.loading = false;
     }
 
-    fn create_table_rows(&self) -> Vec<Row> {
-        Self::create_table_rows_static(&self.activities)
-    }
-
-    fn create_table_rows_static(activities: &[WeekActivity]) -> Vec<Row> {
-        let weekdays = get_current_weekdays();
+    fn create_table_rows_static<'a>(
+        activities: &[(&'a WeekActivity, Option<&StudentMetrics>)],
+        days: &[NaiveDate],
+        calendar: &SchoolCalendar,
+        theme: &Theme,
+    ) -> Vec<Row<'a>> {
         let mut rows = Vec::new();
         
-        for activity in activities {
+        for (activity, metrics) in activities {
             let mut cells = vec![
                 Cell::from(activity.student_username.clone()),
             ];
             
-            // Add cells for each weekday
-            for weekday in &weekdays {
-                let symbol = if let Some(_error) = &activity.error {
-                    "❌"
-                } else if *activity.daily_commits.get(weekday).unwrap_or(&false) {
-                    "✅"
-                } else {
-                    "❌"
-                };
-                cells.push(Cell::from(symbol));
+            // Add cells for each day shown
+            for day in days {
+                if activity.error.is_some() {
+                    cells.push(Cell::from("❌"));
+                    continue;
+                }
+                
+                // Shade the day by how many commits were made, GitHub contribution style
+                let count = activity.daily_commits.get(day).copied().unwrap_or(0);
+                if count == 0 && !calendar.is_school_day(*day) {
+                    cells.push(Cell::from(Span::styled("·", Style::default().fg(theme.text_secondary))));
+                    continue;
+                }
+                let color = theme.activity_color(ActivityLevel::from_commit_count(count));
+                cells.push(Cell::from(Line::from(vec![
+                    Span::styled("■ ", Style::default().fg(color)),
+                    Span::styled(count.to_string(), Style::default().fg(if count == 0 { theme.text_secondary } else { theme.text })),
+                ])));
             }
             
             // Add total commits cell
@@ -104,6 +256,24 @@ impl WeekViewScreen {
                 activity.total_commits.to_string()
             };
             cells.push(Cell::from(total_text));
+            cells.push(Cell::from(
+                activity
+                    .latest_commit
+                    .map(|at| time::format(at, "%a %H:%M"))
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+            
+            // Streak and consistency come from the stored history, not this range
+            match metrics {
+                Some(metrics) => {
+                    cells.push(Cell::from(format!("{} (best {})", metrics.current_streak, metrics.longest_streak)));
+                    cells.push(Cell::from(format!("{}%", metrics.consistency)));
+                }
+                None => {
+                    cells.push(Cell::from(Span::styled("-", Style::default().fg(theme.text_secondary))));
+                    cells.push(Cell::from(Span::styled("-", Style::default().fg(theme.text_secondary))));
+                }
+            }
             
             rows.push(Row::new(cells));
         }
@@ -111,18 +281,95 @@ impl WeekViewScreen {
         rows
     }
 
-    fn create_table_header() -> Row<'static> {
-        let weekdays = get_current_weekdays();
+    fn create_table_header(days: &[NaiveDate], calendar: &SchoolCalendar, theme: &Theme) -> Row<'static> {
+        let today = time::today();
         let mut header_cells = vec![Cell::from("Student").style(Style::default().add_modifier(Modifier::BOLD))];
         
-        for weekday in &weekdays {
-            header_cells.push(Cell::from(format_weekday(*weekday)).style(Style::default().add_modifier(Modifier::BOLD)));
+        for &day in days {
+            let style = if day == today {
+                Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)
+            } else if !calendar.is_school_day(day) {
+                Style::default().fg(theme.text_secondary)
+            } else {
+                Style::default().add_modifier(Modifier::BOLD)
+            };
+            header_cells.push(Cell::from(day.format("%a %-d").to_string()).style(style));
         }
         
         header_cells.push(Cell::from("Total").style(Style::default().add_modifier(Modifier::BOLD)));
+        header_cells.push(Cell::from("Last").style(Style::default().add_modifier(Modifier::BOLD)));
+        header_cells.push(Cell::from("Streak").style(Style::default().add_modifier(Modifier::BOLD)));
+        header_cells.push(Cell::from("Consistency").style(Style::default().add_modifier(Modifier::BOLD)));
         
         Row::new(header_cells)
     }
+
+    /// Every metric for the selected student, under the table
+    fn selected_metrics_line(&self, theme: &Theme) -> Line<'static> {
+        let Some(student) = self.selected_student() else {
+            return Line::from("");
+        };
+        let Some(metrics) = self.metrics.get(&student.id) else {
+            return Line::from(Span::styled(
+                format!("{}: no stored commit history yet, update it from the heatmap", student.username),
+                Style::default().fg(theme.text_secondary),
+            ));
+        };
+        Line::from(vec![
+            Span::styled(student.username.clone(), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!(
+                    ": streak {} school days (longest {}) · {:.1} commits per active day · longest gap {} school days · {}% consistent",
+                    metrics.current_streak,
+                    metrics.longest_streak,
+                    metrics.avg_commits_per_active_day,
+                    metrics.longest_gap,
+                    metrics.consistency
+                ),
+                Style::default().fg(theme.text_secondary),
+            ),
+        ])
+    }
+
+    fn render_range_picker(&self, frame: &mut Frame<ratatui::backend::CrosstermBackend<std::io::Stdout>>, area: Rect, theme: &Theme) {
+        let popup_area = crate::ui::layout::center_rect(50, 30, area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("📅 Custom Range")
+            .title_alignment(Alignment::Center)
+            .border_style(theme.border_focused_style())
+            .style(Style::default().bg(theme.background).fg(theme.text));
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Range
+                Constraint::Length(2), // Error message
+                Constraint::Min(0),    // Spacing
+                Constraint::Length(1), // Help text
+            ])
+            .split(inner_area);
+
+        frame.render_widget(&self.range_input, chunks[0]);
+
+        if let Some(error) = &self.range_error {
+            let error_text = Paragraph::new(Span::styled(error.clone(), Style::default().fg(theme.error)))
+                .alignment(Alignment::Center);
+            frame.render_widget(error_text, chunks[1]);
+        }
+
+        let help_text = Line::from(vec![
+            Span::styled("Enter", Style::default().fg(theme.success).add_modifier(Modifier::BOLD)),
+            Span::styled(" Apply  ", Style::default().fg(theme.text_secondary)),
+            Span::styled("Esc", Style::default().fg(theme.warning).add_modifier(Modifier::BOLD)),
+            Span::styled(" Cancel", Style::default().fg(theme.text_secondary)),
+        ]);
+        frame.render_widget(Paragraph::new(help_text).alignment(Alignment::Center), chunks[3]);
+    }
 }
 
 impl Screen for WeekViewScreen {
@@ -130,6 +377,14 @@ impl Screen for WeekViewScreen {
         self
     }
 
+    fn captures_text_input(&self) -> bool {
+        self.picking_range || self.filter.is_editing()
+    }
+
+    fn keep_when_covered(&self) -> bool {
+        true
+    }
+
     fn screen_type(&self) -> ScreenType {
         ScreenType::new(ScreenTypeVariant::WeekView)
             .with_context(ScreenContext::Class(self.class.clone()))
@@ -140,26 +395,71 @@ impl Screen for WeekViewScreen {
         key: KeyEvent,
         _state: &'a AppState,
     ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        if self.picking_range {
+            let event = self.handle_range_input(key);
+            return Box::pin(async { Ok(event) });
+        }
+
+        if self.filter.handle_key_event(key) {
+            self.table_state.select(if self.visible_rows().is_empty() { None } else { Some(0) });
+            return Box::pin(async { Ok(None) });
+        }
+
         let result = match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+            _ if keymap::is(Action::Up, &key) => {
                 let selected = self.table_state.selected().unwrap_or(0);
                 if selected > 0 {
                     self.table_state.select(Some(selected - 1));
                 }
                 Ok(None)
             },
-            KeyCode::Down | KeyCode::Char('j') => {
+            _ if keymap::is(Action::Down, &key) => {
                 let selected = self.table_state.selected().unwrap_or(0);
-                if selected + 1 < self.activities.len() {
+                if selected + 1 < self.visible_rows().len() {
                     self.table_state.select(Some(selected + 1));
                 }
                 Ok(None)
             },
-            KeyCode::Char('r') => {
+            _ if keymap::is(Action::Select, &key) => {
+                Ok(self.selected_student().map(|student| AppEvent::ShowStudentDetail(student.clone())))
+            },
+            _ if keymap::is(Action::Left, &key) => {
+                Ok(self.set_range(self.range.shift_weeks(-1)))
+            },
+            _ if keymap::is(Action::Right, &key) => {
+                // Stop at the current week; there's nothing to show in the future
+                let next = self.range.shift_weeks(1);
+                if next.start <= time::today() {
+                    Ok(self.set_range(next))
+                } else {
+                    Ok(None)
+                }
+            },
+            _ if keymap::is(Action::ThisWeek, &key) => {
+                Ok(self.set_range(DateRange::this_week(self.range.include_weekends)))
+            },
+            _ if keymap::is(Action::ToggleWeekends, &key) => {
+                Ok(self.set_range(DateRange { include_weekends: !self.range.include_weekends, ..self.range }))
+            },
+            _ if keymap::is(Action::CustomRange, &key) => {
+                self.range_input.set_value(format!("{}..{}", self.range.start, self.range.end));
+                self.range_input.focus();
+                self.picking_range = true;
+                Ok(None)
+            },
+            _ if keymap::is(Action::EarlierDays, &key) => {
+                self.day_offset = self.day_offset.min(self.range.days().len()).saturating_sub(1);
+                Ok(None)
+            },
+            _ if keymap::is(Action::LaterDays, &key) => {
+                self.day_offset = self.day_offset.saturating_add(1);
+                Ok(None)
+            },
+            _ if keymap::is(Action::Refresh, &key) => {
                 // Refresh data
                 Ok(Some(AppEvent::RefreshData))
             },
-            KeyCode::Esc => {
+            _ if keymap::is(Action::Back, &key) => {
                 Ok(Some(AppEvent::GoBack))
             },
             _ => Ok(None),
@@ -168,11 +468,31 @@ impl Screen for WeekViewScreen {
         Box::pin(async { result })
     }
 
+    fn handle_mouse_event<'a>(
+        &'a mut self,
+        mouse: MouseAction,
+        state: &'a AppState,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AppEvent>>> + Send + 'a>> {
+        // Clicks only pick rows while the table has the keyboard
+        let rows = self.visible_rows().len();
+        let key = if !self.captures_text_input() {
+            table_mouse_key(&mut self.table_state, self.table_area, rows, mouse, keymap::key_event(Action::Select))
+        } else {
+            scroll_key(mouse)
+        };
+        match key {
+            Some(key) => self.handle_key_event(key, state),
+            None => Box::pin(async { Ok(None) }),
+        }
+    }
+
     fn update<'a>(
         &'a mut self,
-        _delta_time: Duration,
+        delta_time: Duration,
         _state: &'a mut AppState,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        self.range_input.update(delta_time);
+        self.filter.update(delta_time);
         Box::pin(async { Ok(()) })
     }
 
@@ -186,7 +506,7 @@ impl Screen for WeekViewScreen {
     ) {
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(format!("Week View - {} (Past 5 Weekdays)", self.class.name))
+            .title(format!("Week View - {} ({}, {})", self.class.name, self.range.label(), time::timezone_name()))
             .title_alignment(Alignment::Center)
             .style(Style::default().bg(theme.background).fg(theme.text));
 
@@ -221,16 +541,40 @@ impl Screen for WeekViewScreen {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(self.filter.height()), // Sort and filter
                 Constraint::Min(5),     // Table area
-                Constraint::Length(3),  // Help text
+                Constraint::Length(1),  // Selected student's metrics
+                Constraint::Length(5),  // Help text
             ])
             .split(inner_area);
 
+        // A month of days is wider than most terminals, so show the days that fit and scroll the rest
+        let all_days = self.range.days();
+        let visible_days = (chunks[1].width.saturating_sub(FIXED_COLUMNS_WIDTH) / DAY_COLUMN_WIDTH).max(1) as usize;
+        self.day_offset = self.day_offset.min(all_days.len().saturating_sub(visible_days));
+        let days = &all_days[self.day_offset..all_days.len().min(self.day_offset + visible_days)];
+
+        // Student name, one column per day, then the total
+        let mut widths = vec![Constraint::Length(20)];
+        widths.extend(days.iter().map(|_| Constraint::Length(7)));
+        widths.push(Constraint::Length(8));
+        widths.push(Constraint::Length(10));
+        widths.push(Constraint::Length(12));
+        widths.push(Constraint::Length(12));
+
         // Create table rendering separately to avoid borrow checker issues
-        let activities = &self.activities;
+        let visible = self.visible_rows();
+        self.filter.render(frame, chunks[0], theme, visible.len(), self.activities.len());
+        let activities: Vec<(&WeekActivity, Option<&StudentMetrics>)> = visible
+            .iter()
+            .map(|index| {
+                let metrics = self.students.get(*index).and_then(|student| self.metrics.get(&student.id));
+                (&self.activities[*index], metrics)
+            })
+            .collect();
         let table = {
-            let header = Self::create_table_header();
-            let rows = Self::create_table_rows_static(activities);
+            let header = Self::create_table_header(days, &self.calendar, theme);
+            let rows = Self::create_table_rows_static(&activities, days, &self.calendar, theme);
             
             Table::new(rows)
                 .header(header)
@@ -238,35 +582,69 @@ impl Screen for WeekViewScreen {
                 .style(Style::default().fg(theme.text))
                 .highlight_style(Style::default().bg(theme.highlight).fg(theme.background))
                 .highlight_symbol("▶ ")
-                .widths(&[
-                    Constraint::Length(20), // Student name
-                    Constraint::Length(5),  // Mon
-                    Constraint::Length(5),  // Tue
-                    Constraint::Length(5),  // Wed
-                    Constraint::Length(5),  // Thu
-                    Constraint::Length(5),  // Fri
-                    Constraint::Length(8),  // Total
-                ])
+                .widths(&widths)
         };
 
         // Render the table using the state
-        frame.render_stateful_widget(table, chunks[0], &mut self.table_state);
+        frame.render_stateful_widget(table, chunks[1], &mut self.table_state);
+        self.table_area = chunks[1];
+        frame.render_widget(Paragraph::new(self.selected_metrics_line(theme)).alignment(Alignment::Center), chunks[2]);
 
         // Help text
         let help_text = vec![
             Line::from(vec![
-                Span::styled("↑/↓", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Up, Action::Down]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                 Span::styled(" Navigate  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("r", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(keymap::label(&[Action::Left, Action::Right]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Week  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::ThisWeek]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" This week  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::CustomRange]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Custom range  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::ToggleWeekends]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(if self.range.include_weekends { " Hide weekends  " } else { " Show weekends  " }, Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Select]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Details  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Refresh]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
                 Span::styled(" Refresh  ", Style::default().fg(theme.text_secondary)),
-                Span::styled("ESC", Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
-                Span::styled(" Back", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Back]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Back  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Help]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Help", Style::default().fg(theme.text_secondary)),
+            ]),
+            Line::from(vec![
+                Span::styled(keymap::label(&[Action::Sort, Action::ReverseSort]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Sort/reverse  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::Filter]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Filter  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::OnlyInactive]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" No activity only  ", Style::default().fg(theme.text_secondary)),
+                Span::styled(keymap::label(&[Action::OnlyErrors]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                Span::styled(" Errors only", Style::default().fg(theme.text_secondary)),
             ]),
+            Line::from(if days.len() < all_days.len() {
+                vec![
+                    Span::styled(keymap::label(&[Action::EarlierDays, Action::LaterDays]), Style::default().fg(theme.primary).add_modifier(Modifier::BOLD)),
+                    Span::styled(
+                        format!(" Scroll days ({}-{} of {} shown)", self.day_offset + 1, self.day_offset + days.len(), all_days.len()),
+                        Style::default().fg(theme.text_secondary),
+                    ),
+                ]
+            } else {
+                Vec::new()
+            }),
             Line::from(vec![
-                Span::styled("✅", Style::default().fg(theme.success)),
-                Span::styled(" Committed  ", Style::default().fg(theme.text_secondary)),
+                Span::styled("Less ", Style::default().fg(theme.text_secondary)),
+                Span::styled("■", Style::default().fg(theme.activity_color(ActivityLevel::None))),
+                Span::styled("■", Style::default().fg(theme.activity_color(ActivityLevel::Low))),
+                Span::styled("■", Style::default().fg(theme.activity_color(ActivityLevel::Medium))),
+                Span::styled("■", Style::default().fg(theme.activity_color(ActivityLevel::High))),
+                Span::styled("■", Style::default().fg(theme.activity_color(ActivityLevel::Max))),
+                Span::styled(" More  ", Style::default().fg(theme.text_secondary)),
+                Span::styled("·", Style::default().fg(theme.text_secondary)),
+                Span::styled(" No school  ", Style::default().fg(theme.text_secondary)),
                 Span::styled("❌", Style::default().fg(theme.error)),
-                Span::styled(" No commits", Style::default().fg(theme.text_secondary)),
+                Span::styled(" Fetch failed", Style::default().fg(theme.text_secondary)),
             ]),
         ];
 
@@ -274,6 +652,10 @@ impl Screen for WeekViewScreen {
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::TOP));
 
-        frame.render_widget(help_paragraph, chunks[1]);
+        frame.render_widget(help_paragraph, chunks[3]);
+
+        if self.picking_range {
+            self.render_range_picker(frame, area, theme);
+        }
     }
 }
\ No newline at end of file
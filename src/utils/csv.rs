@@ -0,0 +1,47 @@
+use anyhow::{Context, Result};
+use dirs::home_dir;
+use std::path::{Path, PathBuf};
+
+use crate::utils::time;
+
+/// Directory that CSV exports are written to, `~/.scv-rust/exports`
+pub fn exports_dir() -> Result<PathBuf> {
+    let home = home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    let dir = home.join(".scv-rust").join("exports");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// A timestamped export path such as `Period-3-test-results-20240101-093000.csv`
+pub fn export_path(class_name: &str, kind: &str) -> Result<PathBuf> {
+    let safe_name: String = class_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let timestamp = time::format(chrono::Utc::now(), "%Y%m%d-%H%M%S");
+    Ok(exports_dir()?.join(format!("{}-{}-{}.csv", safe_name, kind, timestamp)))
+}
+
+pub fn write_csv(path: &Path, headers: &[&str], rows: &[Vec<String>]) -> Result<()> {
+    let mut contents = String::new();
+    push_row(&mut contents, headers.iter().copied());
+    for row in rows {
+        push_row(&mut contents, row.iter().map(String::as_str));
+    }
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn push_row<'a>(contents: &mut String, fields: impl Iterator<Item = &'a str>) {
+    let row: Vec<String> = fields.map(escape_field).collect();
+    contents.push_str(&row.join(","));
+    contents.push_str("\r\n");
+}
+
+/// Quote a field if it contains a delimiter, quote or line break (RFC 4180)
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
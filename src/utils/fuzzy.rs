@@ -0,0 +1,47 @@
+/// Score how well `query` matches `candidate`, ignoring case. Substrings score highest,
+/// earlier ones first; otherwise the query's letters must appear in order and fewer gaps
+/// between them score higher. `None` when there is no match.
+pub fn score(query: &str, candidate: &str) -> Option<u32> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let candidate = candidate.to_lowercase();
+
+    if let Some(position) = candidate.find(&query) {
+        return Some(1000u32.saturating_sub(position as u32));
+    }
+
+    let mut remaining = candidate.chars().enumerate();
+    let mut score = 500u32;
+    let mut last: Option<usize> = None;
+    for wanted in query.chars() {
+        let (index, _) = remaining.by_ref().find(|(_, c)| *c == wanted)?;
+        if let Some(last) = last {
+            score = score.saturating_sub((index - last - 1) as u32);
+        }
+        last = Some(index);
+    }
+    Some(score.max(1))
+}
+
+/// The best score of `query` against any of `candidates`
+pub fn best_score(query: &str, candidates: &[&str]) -> Option<u32> {
+    candidates.iter().filter_map(|candidate| score(query, candidate)).max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substring_beats_fuzzy_match() {
+        assert_eq!(score("", "anything"), Some(0));
+        assert!(score("ada", "Ada Lovelace").unwrap() > score("ada", "a_dam").unwrap());
+        assert!(score("alv", "ada lovelace").is_some());
+        assert!(score("adl", "adal").unwrap() > score("adl", "a-----d-----l").unwrap());
+        assert_eq!(score("xyz", "ada"), None);
+        assert_eq!(best_score("grace", &["Grace Hopper", "ghopper"]), score("grace", "grace hopper"));
+        assert_eq!(best_score("zz", &["ada", "grace"]), None);
+    }
+}
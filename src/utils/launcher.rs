@@ -0,0 +1,177 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// External tools a student's repository can be opened with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenAction {
+    Terminal,
+    Editor,
+    FileManager,
+    Browser,
+}
+
+impl OpenAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OpenAction::Terminal => "terminal",
+            OpenAction::Editor => "editor",
+            OpenAction::FileManager => "file manager",
+            OpenAction::Browser => "browser",
+        }
+    }
+
+    /// Whether the action needs a local clone to exist
+    pub fn needs_repo(&self) -> bool {
+        !matches!(self, OpenAction::Browser)
+    }
+}
+
+/// Build a command from a template such as `code {path}` or `xdg-open {url}`.
+///
+/// The template is split into words (single and double quotes group words),
+/// then `{path}`, `{url}` and `$VAR`/`${VAR}` references are substituted in each word,
+/// so paths containing spaces never need quoting.
+pub fn build_command(template: &str, path: &Path, url: &str) -> Result<Command> {
+    let path_text = path.display().to_string();
+    let mut words = split_words(template)?
+        .into_iter()
+        .map(|word| expand_env(&word.replace("{path}", &path_text).replace("{url}", url)))
+        .filter(|word| !word.is_empty());
+
+    let program = words
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Command template '{}' expands to an empty command", template))?;
+
+    let mut command = Command::new(program);
+    command.args(words);
+    if path.is_dir() {
+        command.current_dir(path);
+    }
+    Ok(command)
+}
+
+/// Start a command in the background without attaching it to the TUI's terminal
+pub fn spawn_detached(mut command: Command) -> Result<()> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {:?}", command.get_program()))?;
+    Ok(())
+}
+
+/// The user's interactive shell, falling back to the platform default
+pub fn user_shell() -> String {
+    if cfg!(target_os = "windows") {
+        std::env::var("COMSPEC").unwrap_or_else(|_| "cmd".to_string())
+    } else {
+        std::env::var("SHELL")
+            .ok()
+            .filter(|shell| !shell.is_empty())
+            .unwrap_or_else(|| "/bin/sh".to_string())
+    }
+}
+
+fn split_words(template: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in template.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err(anyhow::anyhow!("Unterminated quote in command template '{}'", template));
+    }
+    if in_word {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+/// Expand `$VAR` and `${VAR}` references; unset variables expand to nothing
+fn expand_env(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] != '$' || i + 1 >= chars.len() {
+            result.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let (name, next) = if chars[i + 1] == '{' {
+            match chars[i + 2..].iter().position(|c| *c == '}') {
+                Some(len) => (chars[i + 2..i + 2 + len].iter().collect::<String>(), i + 3 + len),
+                None => {
+                    result.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+            }
+        } else {
+            let len = chars[i + 1..]
+                .iter()
+                .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+                .count();
+            (chars[i + 1..i + 1 + len].iter().collect::<String>(), i + 1 + len)
+        };
+
+        if name.is_empty() {
+            result.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        result.push_str(&std::env::var(&name).unwrap_or_default());
+        i = next;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_command_substitutes_placeholders() -> Result<()> {
+        std::env::set_var("SCV_TEST_EDITOR", "nvim");
+        let path = Path::new("/tmp/Period 3/jdoe");
+
+        let command = build_command("$SCV_TEST_EDITOR --cmd 'set nu' {path}", path, "")?;
+        assert_eq!(command.get_program(), "nvim");
+        let args: Vec<_> = command.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+        assert_eq!(args, vec!["--cmd", "set nu", "/tmp/Period 3/jdoe"]);
+
+        let command = build_command("xdg-open {url}", path, "https://jdoe.github.io")?;
+        let args: Vec<_> = command.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+        assert_eq!(args, vec!["https://jdoe.github.io"]);
+
+        assert!(build_command("$SCV_TEST_UNSET_VARIABLE", path, "").is_err());
+        Ok(())
+    }
+}
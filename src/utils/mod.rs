@@ -1,3 +1,7 @@
+pub mod csv;
+pub mod fuzzy;
+pub mod launcher;
 pub mod terminal;
+pub mod time;
 
 pub use terminal::*;
\ No newline at end of file
@@ -0,0 +1,145 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::sync::RwLock;
+
+/// School timezone from the config; `None` uses this computer's timezone
+static SCHOOL_TIMEZONE: RwLock<Option<Tz>> = RwLock::new(None);
+
+/// Parse an IANA timezone name such as `America/New_York`
+pub fn parse_timezone(name: &str) -> Result<Tz> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| anyhow!("Unknown timezone '{}', expected a name like America/New_York", name.trim()))
+}
+
+pub fn set_school_timezone(timezone: Option<Tz>) {
+    *SCHOOL_TIMEZONE.write().unwrap_or_else(|e| e.into_inner()) = timezone;
+}
+
+fn school_timezone() -> Option<Tz> {
+    *SCHOOL_TIMEZONE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Name of the timezone days and times are shown in
+pub fn timezone_name() -> String {
+    school_timezone()
+        .map(|timezone| timezone.name().to_string())
+        .unwrap_or_else(|| "local time".to_string())
+}
+
+/// Calendar day an instant falls on at school
+pub fn school_date(time: DateTime<Utc>) -> NaiveDate {
+    date_in(time, school_timezone())
+}
+
+/// Today's date at school
+pub fn today() -> NaiveDate {
+    school_date(Utc::now())
+}
+
+/// Format an instant as wall-clock time at school
+pub fn format(time: DateTime<Utc>, format: &str) -> String {
+    match school_timezone() {
+        Some(timezone) => time.with_timezone(&timezone).format(format).to_string(),
+        None => time.with_timezone(&Local).format(format).to_string(),
+    }
+}
+
+/// The instant a wall-clock time at school refers to; the earlier one when clocks fall back
+pub fn from_school_time(naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    from_time_in(naive, school_timezone())
+}
+
+fn date_in(time: DateTime<Utc>, timezone: Option<Tz>) -> NaiveDate {
+    match timezone {
+        Some(timezone) => time.with_timezone(&timezone).date_naive(),
+        None => time.with_timezone(&Local).date_naive(),
+    }
+}
+
+fn from_time_in(naive: NaiveDateTime, timezone: Option<Tz>) -> Option<DateTime<Utc>> {
+    match timezone {
+        Some(timezone) => timezone.from_local_datetime(&naive).earliest().map(|time| time.with_timezone(&Utc)),
+        None => Local.from_local_datetime(&naive).earliest().map(|time| time.with_timezone(&Utc)),
+    }
+}
+
+/// Relative description such as "3 hours ago"; whole days are counted in school calendar days
+pub fn format_time_ago(datetime: DateTime<Utc>) -> String {
+    let now = Utc::now();
+    let duration = now.signed_duration_since(datetime);
+
+    let seconds = duration.num_seconds();
+    let minutes = duration.num_minutes();
+    let hours = duration.num_hours();
+    let days = (school_date(now) - school_date(datetime)).num_days();
+
+    if seconds < 60 {
+        if seconds <= 1 {
+            "just now".to_string()
+        } else {
+            format!("{} seconds ago", seconds)
+        }
+    } else if minutes < 60 {
+        if minutes == 1 {
+            "1 minute ago".to_string()
+        } else {
+            format!("{} minutes ago", minutes)
+        }
+    } else if hours < 24 && days == 0 {
+        if hours == 1 {
+            "1 hour ago".to_string()
+        } else {
+            format!("{} hours ago", hours)
+        }
+    } else if days < 7 {
+        if days <= 1 {
+            format!("yesterday at {}", format(datetime, "%H:%M"))
+        } else {
+            format!("{} days ago", days)
+        }
+    } else if days < 30 {
+        let weeks = days / 7;
+        if weeks == 1 {
+            "1 week ago".to_string()
+        } else {
+            format!("{} weeks ago", weeks)
+        }
+    } else if days < 365 {
+        let months = days / 30;
+        if months == 1 {
+            "1 month ago".to_string()
+        } else {
+            format!("{} months ago", months)
+        }
+    } else {
+        let years = days / 365;
+        if years == 1 {
+            "1 year ago".to_string()
+        } else {
+            format!("{} years ago", years)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_school_timezone_day_boundaries() {
+        let eastern = parse_timezone("America/New_York").unwrap();
+        assert!(parse_timezone("Mars/Olympus_Mons").is_err());
+
+        // 9pm Eastern on a Tuesday is already Wednesday in UTC
+        let evening = Utc.with_ymd_and_hms(2024, 3, 13, 1, 0, 0).unwrap();
+        assert_eq!(date_in(evening, Some(eastern)), NaiveDate::from_ymd_opt(2024, 3, 12).unwrap());
+
+        let midnight = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(
+            from_time_in(midnight, Some(eastern)),
+            Some(Utc.with_ymd_and_hms(2024, 3, 12, 4, 0, 0).unwrap())
+        );
+    }
+}